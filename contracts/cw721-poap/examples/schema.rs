@@ -1,6 +1,6 @@
 use cosmwasm_schema::{export_schema_with_title, remove_schemas, schema_for};
 use cw721::{AllNftInfoResponse, NftInfoResponse};
-use cw721_poap::{ExecuteMsg, Metadata};
+use cw721_poap::{ExecuteMsg, InstantiateMsg, Metadata};
 use std::env::current_dir;
 use std::fs::create_dir_all;
 
@@ -10,6 +10,7 @@ fn main() {
     create_dir_all(&out_dir).unwrap();
     remove_schemas(&out_dir).unwrap();
 
+    export_schema_with_title(&schema_for!(InstantiateMsg), &out_dir, "InstantiateMsg");
     export_schema_with_title(&schema_for!(ExecuteMsg), &out_dir, "ExecuteMsg");
     export_schema_with_title(
         &schema_for!(AllNftInfoResponse<Metadata>),
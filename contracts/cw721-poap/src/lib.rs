@@ -1,21 +1,60 @@
 use cosmwasm_schema::cw_serde;
-use cosmwasm_std::{Addr, Empty};
-pub use cw721_base::{ContractError, InstantiateMsg, MintMsg, MinterResponse};
+use cosmwasm_std::{Addr, Empty, StdError, Timestamp};
+pub use cw721_base::{MintMsg, MinterResponse};
+use cw_storage_plus::Item;
 use desmos_bindings::{msg::DesmosMsg, query::DesmosQuery};
+use thiserror::Error;
 
 #[cw_serde]
 pub struct Metadata {
+    /// Address that received the POAP.
     pub claimer: Addr,
+    /// Id of the event the POAP belongs to.
+    pub event_id: u64,
+    /// Time at which the POAP has been minted.
+    pub mint_time: Timestamp,
+    /// Address that issued the POAP, it's equal to the claimer if the POAP has been self minted.
+    pub minter: Addr,
+    /// Optional tier of the POAP, e.g. speaker, attendee or organizer.
+    pub tier: Option<String>,
 }
 
+#[cw_serde]
+pub struct InstantiateMsg {
+    /// Name of the NFT contract.
+    pub name: String,
+    /// Symbol of the NFT contract.
+    pub symbol: String,
+    /// Address who is the only one able to mint new NFTs.
+    pub minter: String,
+    /// If true the tokens can't be transferred nor approved, they can only be burned by their owner
+    /// or by the minter.
+    pub soulbound: bool,
+}
+
+#[derive(Error, Debug, PartialEq)]
+pub enum ContractError {
+    #[error("{0}")]
+    Std(#[from] StdError),
+
+    #[error("{0}")]
+    Base(#[from] cw721_base::ContractError),
+
+    #[error("Soulbound tokens can't be transferred or approved")]
+    Soulbound {},
+}
+
+/// Tells if the tokens of the contract are non-transferable.
+pub const SOULBOUND: Item<bool> = Item::new("soulbound");
+
 pub type Cw721MetadataContract<'a> =
     cw721_base::Cw721Contract<'a, Metadata, Empty, Empty, DesmosMsg, DesmosQuery>;
 pub type ExecuteMsg = cw721_base::ExecuteMsg<Metadata, Empty>;
 pub type QueryMsg = cw721_base::QueryMsg<Empty>;
 
-#[cfg(not(feature = "library"))]
 pub mod entry {
     use super::*;
+    #[cfg(not(feature = "library"))]
     use cosmwasm_std::entry_point;
     use cosmwasm_std::{Binary, Deps, DepsMut, Env, MessageInfo, Response, StdResult};
     use cw2::set_contract_version;
@@ -25,31 +64,76 @@ pub mod entry {
     const CONTRACT_VERSION: &str = env!("CARGO_PKG_VERSION");
 
     // This makes a conscious choice on the various generics used by the contract
-    #[entry_point]
+    #[cfg_attr(not(feature = "library"), entry_point)]
     pub fn instantiate(
         mut deps: DepsMut<DesmosQuery>,
         env: Env,
         info: MessageInfo,
         msg: InstantiateMsg,
     ) -> Result<Response<DesmosMsg>, ContractError> {
-        let res = Cw721MetadataContract::default().instantiate(deps.branch(), env, info, msg)?;
+        let res = Cw721MetadataContract::default().instantiate(
+            deps.branch(),
+            env,
+            info,
+            cw721_base::InstantiateMsg {
+                name: msg.name,
+                symbol: msg.symbol,
+                minter: msg.minter,
+            },
+        )?;
+        SOULBOUND.save(deps.storage, &msg.soulbound)?;
         // Explicitly set contract name and version, otherwise set to cw721-base info
-        set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)
-            .map_err(ContractError::Std)?;
-        Ok(res)
+        set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
+        Ok(res.add_attribute("soulbound", msg.soulbound.to_string()))
     }
 
-    #[entry_point]
+    #[cfg_attr(not(feature = "library"), entry_point)]
     pub fn execute(
         deps: DepsMut<DesmosQuery>,
         env: Env,
         info: MessageInfo,
         msg: ExecuteMsg,
     ) -> Result<Response<DesmosMsg>, ContractError> {
-        Cw721MetadataContract::default().execute(deps, env, info, msg)
+        // Soulbound tokens can only be minted and burned
+        if SOULBOUND.may_load(deps.storage)?.unwrap_or_default()
+            && matches!(
+                msg,
+                ExecuteMsg::TransferNft { .. }
+                    | ExecuteMsg::SendNft { .. }
+                    | ExecuteMsg::Approve { .. }
+                    | ExecuteMsg::ApproveAll { .. }
+            )
+        {
+            return Err(ContractError::Soulbound {});
+        }
+        // The minter can burn any token so that the POAPs can be revoked
+        if let ExecuteMsg::Burn { token_id } = &msg {
+            let contract = Cw721MetadataContract::default();
+            if contract.minter.load(deps.storage)? == info.sender {
+                return execute_minter_burn(deps, info, token_id);
+            }
+        }
+        Ok(Cw721MetadataContract::default().execute(deps, env, info, msg)?)
+    }
+
+    fn execute_minter_burn(
+        deps: DepsMut<DesmosQuery>,
+        info: MessageInfo,
+        token_id: &str,
+    ) -> Result<Response<DesmosMsg>, ContractError> {
+        let contract = Cw721MetadataContract::default();
+        // Ensure the token exists before removing it
+        contract.tokens.load(deps.storage, token_id)?;
+        contract.tokens.remove(deps.storage, token_id)?;
+        contract.decrement_tokens(deps.storage)?;
+
+        Ok(Response::new()
+            .add_attribute("action", "burn")
+            .add_attribute("sender", info.sender)
+            .add_attribute("token_id", token_id))
     }
 
-    #[entry_point]
+    #[cfg_attr(not(feature = "library"), entry_point)]
     pub fn query(deps: Deps<DesmosQuery>, env: Env, msg: QueryMsg) -> StdResult<Binary> {
         Cw721MetadataContract::default().query(deps, env, msg)
     }
@@ -59,10 +143,12 @@ pub mod entry {
 mod tests {
     use super::*;
     use cosmwasm_std::testing::{mock_env, mock_info};
+    use cosmwasm_std::{Binary, DepsMut};
     use cw721::Cw721Query;
     use desmos_bindings::mocks::mock_queriers::mock_desmos_dependencies;
 
     const CREATOR: &str = "creator";
+    const OWNER: &str = "owner";
 
     #[test]
     fn use_metadata_extension() {
@@ -70,7 +156,7 @@ mod tests {
         let contract = Cw721MetadataContract::default();
 
         let info = mock_info(CREATOR, &[]);
-        let init_msg = InstantiateMsg {
+        let init_msg = cw721_base::InstantiateMsg {
             name: "SpaceShips".to_string(),
             symbol: "SPACE".to_string(),
             minter: CREATOR.to_string(),
@@ -86,6 +172,10 @@ mod tests {
             token_uri: Some("https://starships.example.com/Starship/Enterprise.json".into()),
             extension: Metadata {
                 claimer: Addr::unchecked("claimer"),
+                event_id: 1,
+                mint_time: Timestamp::from_seconds(1),
+                minter: Addr::unchecked(CREATOR),
+                tier: Some("speaker".to_string()),
             },
         };
         let exec_msg = ExecuteMsg::Mint(mint_msg.clone());
@@ -97,4 +187,152 @@ mod tests {
         assert_eq!(res.token_uri, mint_msg.token_uri);
         assert_eq!(res.extension, mint_msg.extension);
     }
+    fn do_instantiate(deps: DepsMut<DesmosQuery>, soulbound: bool) {
+        entry::instantiate(
+            deps,
+            mock_env(),
+            mock_info(CREATOR, &[]),
+            InstantiateMsg {
+                name: "POAP".to_string(),
+                symbol: "POAP".to_string(),
+                minter: CREATOR.to_string(),
+                soulbound,
+            },
+        )
+        .unwrap();
+    }
+
+    fn mint(deps: DepsMut<DesmosQuery>, token_id: &str, owner: &str) {
+        entry::execute(
+            deps,
+            mock_env(),
+            mock_info(CREATOR, &[]),
+            ExecuteMsg::Mint(MintMsg {
+                token_id: token_id.to_string(),
+                owner: owner.to_string(),
+                token_uri: None,
+                extension: Metadata {
+                    claimer: Addr::unchecked(owner),
+                    event_id: 1,
+                    mint_time: Timestamp::from_seconds(1),
+                    minter: Addr::unchecked(owner),
+                    tier: None,
+                },
+            }),
+        )
+        .unwrap();
+    }
+
+    #[test]
+    fn transfer_not_soulbound_properly() {
+        let mut deps = mock_desmos_dependencies();
+        do_instantiate(deps.as_mut(), false);
+        mint(deps.as_mut(), "1", OWNER);
+
+        entry::execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info(OWNER, &[]),
+            ExecuteMsg::TransferNft {
+                recipient: "recipient".to_string(),
+                token_id: "1".to_string(),
+            },
+        )
+        .unwrap();
+
+        let contract = Cw721MetadataContract::default();
+        let res = contract
+            .owner_of(deps.as_ref(), mock_env(), "1".to_string(), false)
+            .unwrap();
+        assert_eq!("recipient", res.owner);
+    }
+
+    #[test]
+    fn transfer_and_approve_soulbound_error() {
+        let mut deps = mock_desmos_dependencies();
+        do_instantiate(deps.as_mut(), true);
+        mint(deps.as_mut(), "1", OWNER);
+
+        let msgs = vec![
+            ExecuteMsg::TransferNft {
+                recipient: "recipient".to_string(),
+                token_id: "1".to_string(),
+            },
+            ExecuteMsg::SendNft {
+                contract: "contract".to_string(),
+                token_id: "1".to_string(),
+                msg: Binary::default(),
+            },
+            ExecuteMsg::Approve {
+                spender: "spender".to_string(),
+                token_id: "1".to_string(),
+                expires: None,
+            },
+            ExecuteMsg::ApproveAll {
+                operator: "operator".to_string(),
+                expires: None,
+            },
+        ];
+        for msg in msgs {
+            let result = entry::execute(deps.as_mut(), mock_env(), mock_info(OWNER, &[]), msg);
+            assert_eq!(ContractError::Soulbound {}, result.unwrap_err());
+        }
+    }
+
+    #[test]
+    fn burn_soulbound_from_owner_properly() {
+        let mut deps = mock_desmos_dependencies();
+        do_instantiate(deps.as_mut(), true);
+        mint(deps.as_mut(), "1", OWNER);
+
+        entry::execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info(OWNER, &[]),
+            ExecuteMsg::Burn {
+                token_id: "1".to_string(),
+            },
+        )
+        .unwrap();
+
+        let contract = Cw721MetadataContract::default();
+        assert_eq!(0, contract.num_tokens(deps.as_ref()).unwrap().count);
+    }
+
+    #[test]
+    fn burn_soulbound_from_minter_properly() {
+        let mut deps = mock_desmos_dependencies();
+        do_instantiate(deps.as_mut(), true);
+        mint(deps.as_mut(), "1", OWNER);
+
+        entry::execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info(CREATOR, &[]),
+            ExecuteMsg::Burn {
+                token_id: "1".to_string(),
+            },
+        )
+        .unwrap();
+
+        let contract = Cw721MetadataContract::default();
+        assert_eq!(0, contract.num_tokens(deps.as_ref()).unwrap().count);
+    }
+
+    #[test]
+    fn burn_from_non_owner_error() {
+        let mut deps = mock_desmos_dependencies();
+        do_instantiate(deps.as_mut(), false);
+        mint(deps.as_mut(), "1", OWNER);
+
+        let result = entry::execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("other", &[]),
+            ExecuteMsg::Burn {
+                token_id: "1".to_string(),
+            },
+        );
+        assert!(result.is_err());
+    }
 }
@@ -1,5 +1,5 @@
 use cosmwasm_schema::cw_serde;
-use cosmwasm_std::Empty;
+use cosmwasm_std::{Addr, Empty};
 pub use cw721_base::{ContractError, InstantiateMsg, MintMsg, MinterResponse};
 use desmos_bindings::{msg::DesmosMsg, query::DesmosQuery};
 
@@ -8,6 +8,8 @@ pub struct Metadata {
     pub rarity_level: u32,
     pub subspace_id: u64,
     pub post_id: u64,
+    /// Author of the post, not set for the tokens minted before it was recorded.
+    pub author: Option<Addr>,
 }
 
 pub type Cw721MetadataContract<'a> =
@@ -90,6 +92,7 @@ mod tests {
                 rarity_level: 0,
                 subspace_id: 1u64,
                 post_id: 1u64,
+                author: Some(Addr::unchecked("john")),
             },
         };
         let exec_msg = ExecuteMsg::Mint(mint_msg.clone());
@@ -1,18 +1,39 @@
 #[cfg(not(feature = "library"))]
 use cosmwasm_std::entry_point;
 use cosmwasm_std::{
-    to_binary, wasm_execute, wasm_instantiate, Addr, Deps, DepsMut, Env, MessageInfo,
-    QueryResponse, Reply, Response, StdResult, Storage, SubMsg,
+    to_binary, wasm_execute, wasm_instantiate, Addr, Api, Deps, DepsMut, Env, MessageInfo, Order,
+    QueryResponse, Reply, Response, StdResult, Storage, SubMsg, Timestamp, Uint64,
 };
 use cw2::set_contract_version;
+use cw_storage_plus::Bound;
 use cw_utils::parse_reply_instantiate_data;
 
-use desmos_bindings::{msg::DesmosMsg, profiles::querier::ProfilesQuerier, query::DesmosQuery};
-use poap::msg::ExecuteMsg as POAPExecuteMsg;
+use cw721::{AllNftInfoResponse, TokensResponse};
+use cw721_poap::Metadata;
+use desmos_bindings::{
+    msg::DesmosMsg, posts::querier::PostsQuerier, profiles::models_app_links::ApplicationLinkState,
+    profiles::querier::ProfilesQuerier, query::DesmosQuery, subspaces::querier::SubspacesQuerier,
+};
+use poap::msg::{
+    ExecuteMsg as POAPExecuteMsg, InstantiateMsg as POAPInstantiateMsg,
+    QueryEventInfoResponse as POAPQueryEventInfoResponse,
+    QueryHasAttendedResponse as POAPQueryHasAttendedResponse,
+    QueryMintedAmountResponse as POAPQueryMintedAmountResponse, QueryMsg as POAPQueryMsg,
+    QueryStatsResponse as POAPQueryStatsResponse, QueryStatusResponse as POAPQueryStatusResponse,
+};
+use serde::de::DeserializeOwned;
 
 use crate::error::ContractError;
-use crate::msg::{ExecuteMsg, InstantiateMsg, QueryConfigResponse, QueryMsg};
-use crate::state::{Config, CONFIG, POAP_CONTRACT_ADDRESS};
+use crate::msg::{
+    ClaimLimits, EligibilityRule, ExecuteMsg, InstantiateMsg, QueryActiveEventsResponse,
+    QueryCheckEligibilityResponse, QueryClaimStatusResponse, QueryConfigResponse,
+    QueryEligibilityRulesResponse, QueryEventResponse, QueryEventsResponse, QueryMsg,
+    QueryOverviewResponse, MAX_SCANNED_SUBSPACE_POSTS,
+};
+use crate::state::{
+    events, BlockClaims, Config, EventRecord, PendingEvent, BLOCK_CLAIMS, CLAIMED_DTAGS,
+    CLAIM_LIMITS, CONFIG, ELIGIBILITY_RULES, LAST_CLAIM_TIMES, NEXT_EVENT_ID, PENDING_EVENTS,
+};
 
 use std::ops::Deref;
 
@@ -22,10 +43,20 @@ const CONTRACT_VERSION: &str = env!("CARGO_PKG_VERSION");
 
 // actions for executing messages
 const ACTION_INSTANTIATE: &str = "instantiate";
+const ACTION_CREATE_EVENT: &str = "create_event";
 const ACTION_INSTANTIATE_POAP_REPLY: &str = "instantiate_poap_reply";
 const ACTION_CLAIM: &str = "claim";
 const ACTION_MINT_TO: &str = "mint_to";
 const ACTION_UPDATE_ADMIN: &str = "update_admin";
+const ACTION_UPDATE_ELIGIBILITY_RULES: &str = "update_eligibility_rules";
+const ACTION_ENABLE_MINT: &str = "enable_mint";
+const ACTION_DISABLE_MINT: &str = "disable_mint";
+const ACTION_UPDATE_EVENT_INFO: &str = "update_event_info";
+const ACTION_UPDATE_MINTER: &str = "update_minter";
+const ACTION_UPDATE_CLAIM_LIMITS: &str = "update_claim_limits";
+const ACTION_CLAIM_FUNDS: &str = "claim_funds";
+const ACTION_CLOSE_EVENT: &str = "close_event";
+const ACTION_REVOKE: &str = "revoke";
 
 // attributes for executing messages
 const ATTRIBUTE_ACTION: &str = "action";
@@ -33,15 +64,24 @@ const ATTRIBUTE_ADMIN: &str = "admin";
 const ATTRIBUTE_POAP_CODE_ID: &str = "poap_code_id";
 const ATTRIBUTE_SENDER: &str = "sender";
 const ATTRIBUTE_NEW_ADMIN: &str = "new_admin";
+const ATTRIBUTE_EVENT_ID: &str = "event_id";
+const ATTRIBUTE_POAP_CONTRACT_ADDRESS: &str = "poap_contract_address";
 
-// reply ids for handling reply tasks
-const INSTANTIATE_POAP_REPLY_ID: u64 = 1;
+// id of the event created together with each POAP contract
+const POAP_EVENT_ID: u64 = 1;
+
+const DEFAULT_QUERY_LIMIT: u32 = 10;
+const MAX_QUERY_LIMIT: u32 = 30;
+const MAX_SCANNED_ACTIVE_EVENTS: usize = 100;
+
+const APPLICATION_LINKS_PAGE_SIZE: u64 = 10;
+const SUBSPACE_POSTS_PAGE_SIZE: u64 = 50;
 
 #[cfg_attr(not(feature = "library"), entry_point)]
 pub fn instantiate(
     deps: DepsMut<DesmosQuery>,
-    env: Env,
-    info: MessageInfo,
+    _env: Env,
+    _info: MessageInfo,
     msg: InstantiateMsg,
 ) -> Result<Response<DesmosMsg>, ContractError> {
     msg.validate()?;
@@ -53,23 +93,18 @@ pub fn instantiate(
     };
     CONFIG.save(deps.storage, &config)?;
 
-    // assign the minter of poap contract to the manager's contract address
-    let mut poap_instantiate_msg = msg.poap_instantiate_msg;
-    poap_instantiate_msg.minter = env.contract.address.into();
+    // Only a Desmos profile is required if no rules are provided
+    let eligibility_rules = msg
+        .eligibility_rules
+        .unwrap_or_else(|| vec![EligibilityRule::ProfileRequired {}]);
+    validate_rules_addresses(deps.api, &eligibility_rules)?;
+    ELIGIBILITY_RULES.save(deps.storage, &eligibility_rules)?;
+    CLAIM_LIMITS.save(deps.storage, &msg.claim_limits.unwrap_or_default())?;
 
     Ok(Response::new()
         .add_attribute("action", ACTION_INSTANTIATE)
         .add_attribute(ATTRIBUTE_ADMIN, admin)
-        .add_attribute(ATTRIBUTE_POAP_CODE_ID, msg.poap_code_id)
-        .add_submessage(SubMsg::reply_on_success(
-            wasm_instantiate(
-                msg.poap_code_id.u64(),
-                &poap_instantiate_msg,
-                info.funds,
-                "poap".into(),
-            )?,
-            INSTANTIATE_POAP_REPLY_ID,
-        )))
+        .add_attribute(ATTRIBUTE_POAP_CODE_ID, msg.poap_code_id))
 }
 
 #[cfg_attr(not(feature = "library"), entry_point)]
@@ -78,76 +113,376 @@ pub fn reply(
     _env: Env,
     msg: Reply,
 ) -> Result<Response<DesmosMsg>, ContractError> {
-    match msg.id {
-        INSTANTIATE_POAP_REPLY_ID => resolve_instantiate_poap_reply(deps, msg),
-        _ => Err(ContractError::InvalidReplyID {}),
+    // The reply id is the id of the event whose POAP contract has been instantiated
+    match PENDING_EVENTS.may_load(deps.storage, msg.id)? {
+        Some(pending_event) => resolve_instantiate_poap_reply(deps, msg, pending_event),
+        None => Err(ContractError::InvalidReplyID {}),
     }
 }
 
 fn resolve_instantiate_poap_reply(
     deps: DepsMut<DesmosQuery>,
     msg: Reply,
+    pending_event: PendingEvent,
 ) -> Result<Response<DesmosMsg>, ContractError> {
+    let event_id = msg.id;
     let res = parse_reply_instantiate_data(msg)?;
     let address = deps.api.addr_validate(&res.contract_address)?;
-    POAP_CONTRACT_ADDRESS.save(deps.storage, &address)?;
-    Ok(Response::new().add_attribute(ATTRIBUTE_ACTION, ACTION_INSTANTIATE_POAP_REPLY))
+    PENDING_EVENTS.remove(deps.storage, event_id);
+    events().save(
+        deps.storage,
+        event_id,
+        &EventRecord {
+            creator: pending_event.creator,
+            poap_contract_address: address.clone(),
+            start_time: pending_event.start_time,
+            end_time: pending_event.end_time,
+            // The POAP contract starts with the mint disabled
+            mint_enabled: false,
+        },
+    )?;
+    Ok(Response::new()
+        .add_attribute(ATTRIBUTE_ACTION, ACTION_INSTANTIATE_POAP_REPLY)
+        .add_attribute(ATTRIBUTE_EVENT_ID, event_id.to_string())
+        .add_attribute(ATTRIBUTE_POAP_CONTRACT_ADDRESS, address))
 }
 
 #[cfg_attr(not(feature = "library"), entry_point)]
 pub fn execute(
     deps: DepsMut<DesmosQuery>,
-    _env: Env,
+    env: Env,
     info: MessageInfo,
     msg: ExecuteMsg,
 ) -> Result<Response<DesmosMsg>, ContractError> {
+    msg.validate()?;
+
     match msg {
-        ExecuteMsg::Claim {} => execute_claim(deps, info),
-        ExecuteMsg::MintTo { recipient } => execute_mint_to(deps, info, recipient),
+        ExecuteMsg::CreateEvent {
+            poap_instantiate_msg,
+        } => execute_create_event(deps, env, info, poap_instantiate_msg),
+        ExecuteMsg::Claim { event_id } => execute_claim(deps, env, info, event_id.u64()),
+        ExecuteMsg::MintTo {
+            event_id,
+            recipient,
+        } => execute_mint_to(deps, info, event_id.u64(), recipient),
         ExecuteMsg::UpdateAdmin { new_admin } => execute_update_admin(deps, info, new_admin),
+        ExecuteMsg::UpdateEligibilityRules { rules } => {
+            execute_update_eligibility_rules(deps, info, rules)
+        }
+        ExecuteMsg::EnableMint { event_id } => {
+            execute_set_mint_enabled(deps, info, event_id.u64(), true)
+        }
+        ExecuteMsg::DisableMint { event_id } => {
+            execute_set_mint_enabled(deps, info, event_id.u64(), false)
+        }
+        ExecuteMsg::UpdateEventInfo {
+            event_id,
+            start_time,
+            end_time,
+        } => execute_update_event_info(deps, info, event_id.u64(), start_time, end_time),
+        ExecuteMsg::UpdateMinter {
+            event_id,
+            new_minter,
+        } => execute_update_minter(deps, info, event_id.u64(), new_minter),
+        ExecuteMsg::UpdateClaimLimits { claim_limits } => {
+            execute_update_claim_limits(deps, info, claim_limits)
+        }
+        ExecuteMsg::ClaimFunds { event_id, receiver } => {
+            execute_claim_funds(deps, info, event_id.u64(), receiver)
+        }
+        ExecuteMsg::CloseEvent { event_id } => execute_close_event(deps, info, event_id.u64()),
+        ExecuteMsg::Revoke {
+            event_id,
+            token_id,
+            reason,
+        } => execute_revoke(deps, info, event_id.u64(), token_id, reason),
     }
 }
 
+fn execute_create_event(
+    deps: DepsMut<DesmosQuery>,
+    env: Env,
+    info: MessageInfo,
+    mut poap_instantiate_msg: POAPInstantiateMsg,
+) -> Result<Response<DesmosMsg>, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+    // The sender is the creator of the event, regardless of the provided one
+    let creator = info.sender.clone();
+
+    // assign the admin, the minter and the event creator of the poap contract to the
+    // manager's contract address so that the manager can operate the poap contract
+    let manager_address = env.contract.address.to_string();
+    poap_instantiate_msg.admin = manager_address.clone();
+    poap_instantiate_msg.minter = manager_address.clone();
+    poap_instantiate_msg.event_info.creator = manager_address;
+
+    // Save the event as pending until the POAP contract address is received
+    let event_id = NEXT_EVENT_ID.may_load(deps.storage)?.unwrap_or(1);
+    NEXT_EVENT_ID.save(deps.storage, &(event_id + 1))?;
+    PENDING_EVENTS.save(
+        deps.storage,
+        event_id,
+        &PendingEvent {
+            creator: creator.clone(),
+            start_time: poap_instantiate_msg.event_info.start_time,
+            end_time: poap_instantiate_msg.event_info.end_time,
+        },
+    )?;
+
+    Ok(Response::new()
+        .add_attribute(ATTRIBUTE_ACTION, ACTION_CREATE_EVENT)
+        .add_attribute(ATTRIBUTE_SENDER, &info.sender)
+        .add_attribute(ATTRIBUTE_EVENT_ID, event_id.to_string())
+        .add_attribute("creator", creator)
+        .add_submessage(SubMsg::reply_on_success(
+            wasm_instantiate(
+                config.poap_code_id,
+                &poap_instantiate_msg,
+                info.funds,
+                format!("poap_{}", event_id),
+            )?,
+            event_id,
+        )))
+}
+
+fn load_event(storage: &dyn Storage, event_id: u64) -> Result<EventRecord, ContractError> {
+    events()
+        .may_load(storage, event_id)?
+        .ok_or(ContractError::EventNotFound { event_id })
+}
+
 fn execute_claim(
     deps: DepsMut<DesmosQuery>,
+    env: Env,
     info: MessageInfo,
+    event_id: u64,
 ) -> Result<Response<DesmosMsg>, ContractError> {
-    let poap_contract_address = POAP_CONTRACT_ADDRESS.load(deps.storage)?;
-    if !check_eligibility(deps, info.sender.clone()) {
+    let event = load_event(deps.storage, event_id)?;
+    if !event.mint_enabled {
+        return Err(ContractError::MintDisabled { event_id });
+    }
+    if !failed_eligibility_rules(deps.as_ref(), &env, &info.sender)?.is_empty() {
         return Err(ContractError::NoEligibilityError {});
     }
+    record_claim(deps, &env, &info.sender, event_id)?;
     Ok(Response::new()
         .add_attribute(ATTRIBUTE_ACTION, ACTION_CLAIM)
         .add_attribute(ATTRIBUTE_SENDER, &info.sender)
+        .add_attribute(ATTRIBUTE_EVENT_ID, event_id.to_string())
         .add_message(wasm_execute(
-            poap_contract_address,
+            event.poap_contract_address,
             &POAPExecuteMsg::MintTo {
+                event_id: POAP_EVENT_ID.into(),
                 recipient: info.sender.into(),
+                tier: None,
             },
             info.funds,
         )?))
 }
 
-fn check_eligibility(deps: DepsMut<DesmosQuery>, user: Addr) -> bool {
+/// Checks that the claim respects the claim limits and records it.
+fn record_claim(
+    deps: DepsMut<DesmosQuery>,
+    env: &Env,
+    user: &Addr,
+    event_id: u64,
+) -> Result<(), ContractError> {
+    let claim_limits = CLAIM_LIMITS.load(deps.storage)?;
+    if claim_limits.one_claim_per_dtag {
+        let dtag = query_dtag(deps.as_ref(), user)
+            .ok_or(ContractError::ProfileNotFound { user: user.clone() })?;
+        if CLAIMED_DTAGS.has(deps.storage, (event_id, &dtag)) {
+            return Err(ContractError::DTagAlreadyClaimed { dtag, event_id });
+        }
+        CLAIMED_DTAGS.save(deps.storage, (event_id, &dtag), user)?;
+    }
+
+    if let Some(cooldown_seconds) = claim_limits.cooldown_seconds {
+        if let Some(next_claim_time) = next_claim_time(deps.storage, env, user, cooldown_seconds)? {
+            return Err(ContractError::ClaimCooldown { next_claim_time });
+        }
+    }
+    LAST_CLAIM_TIMES.save(deps.storage, user, &env.block.time)?;
+
+    if let Some(max_claims_per_block) = claim_limits.max_claims_per_block {
+        // The counter is reset on the first claim of each block
+        let count = match BLOCK_CLAIMS.may_load(deps.storage)? {
+            Some(block_claims) if block_claims.height == env.block.height => block_claims.count,
+            _ => 0,
+        };
+        if count >= max_claims_per_block {
+            return Err(ContractError::MaxClaimsPerBlockReached {
+                max_claims_per_block,
+            });
+        }
+        BLOCK_CLAIMS.save(
+            deps.storage,
+            &BlockClaims {
+                height: env.block.height,
+                count: count + 1,
+            },
+        )?;
+    }
+    Ok(())
+}
+
+/// Returns the lowercase DTag of the user's profile, if any.
+fn query_dtag(deps: Deps<DesmosQuery>, user: &Addr) -> Option<String> {
     ProfilesQuerier::new(deps.querier.deref())
-        .query_profile(user)
-        .is_ok()
+        .query_profile(user.clone())
+        .ok()
+        .map(|response| response.profile.dtag.to_lowercase())
+}
+
+/// Returns the time from which the user can claim again if the cooldown is still running.
+fn next_claim_time(
+    storage: &dyn Storage,
+    env: &Env,
+    user: &Addr,
+    cooldown_seconds: u64,
+) -> StdResult<Option<Timestamp>> {
+    Ok(LAST_CLAIM_TIMES
+        .may_load(storage, user)?
+        .map(|last_claim_time| last_claim_time.plus_seconds(cooldown_seconds))
+        .filter(|next_claim_time| *next_claim_time > env.block.time))
+}
+
+/// Returns the eligibility rules that the user doesn't satisfy.
+fn failed_eligibility_rules(
+    deps: Deps<DesmosQuery>,
+    env: &Env,
+    user: &Addr,
+) -> StdResult<Vec<EligibilityRule>> {
+    let mut failed_rules = vec![];
+    for rule in ELIGIBILITY_RULES.load(deps.storage)? {
+        if !is_rule_satisfied(deps, env, user, &rule)? {
+            failed_rules.push(rule);
+        }
+    }
+    Ok(failed_rules)
+}
+
+/// Checks if the user satisfies the given eligibility rule.
+fn is_rule_satisfied(
+    deps: Deps<DesmosQuery>,
+    env: &Env,
+    user: &Addr,
+    rule: &EligibilityRule,
+) -> StdResult<bool> {
+    let querier = deps.querier.deref();
+    let satisfied = match rule {
+        EligibilityRule::ProfileRequired {} => ProfilesQuerier::new(querier)
+            .query_profile(user.clone())
+            .is_ok(),
+        EligibilityRule::MinProfileAge { min_age_seconds } => ProfilesQuerier::new(querier)
+            .query_profile(user.clone())
+            .map(|response| {
+                env.block
+                    .time
+                    .seconds()
+                    .saturating_sub(response.profile.creation_date.seconds())
+                    >= *min_age_seconds
+            })
+            .unwrap_or(false),
+        EligibilityRule::ApplicationLink { application } => {
+            let mut verified = false;
+            for app_link in ProfilesQuerier::new(querier).iterate_application_links(
+                Some(user.clone()),
+                Some(application.clone()),
+                None,
+                APPLICATION_LINKS_PAGE_SIZE,
+            ) {
+                if app_link?.state == ApplicationLinkState::VerificationSuccess {
+                    verified = true;
+                    break;
+                }
+            }
+            verified
+        }
+        EligibilityRule::SubspaceMember { subspace_id } => SubspacesQuerier::new(querier)
+            .query_user_permissions(subspace_id.u64(), None, user.clone())
+            .map(|response| !response.permissions.is_empty())
+            .unwrap_or(false),
+        EligibilityRule::SubspacePermission {
+            subspace_id,
+            permission,
+        } => SubspacesQuerier::new(querier)
+            .query_user_permissions(subspace_id.u64(), None, user.clone())
+            .map(|response| response.permissions.iter().any(|p| p == permission))
+            .unwrap_or(false),
+        EligibilityRule::MinPosts {
+            subspace_id,
+            min_posts,
+        } => {
+            // Scan a bounded amount of posts, failing if the user posts are not found within them
+            let mut posts_count = 0;
+            for post in PostsQuerier::new(querier)
+                .iterate_subspace_posts(subspace_id.u64(), SUBSPACE_POSTS_PAGE_SIZE)
+                .take(MAX_SCANNED_SUBSPACE_POSTS as usize)
+            {
+                if &post?.author == user {
+                    posts_count += 1;
+                    if posts_count >= *min_posts {
+                        break;
+                    }
+                }
+            }
+            posts_count >= *min_posts
+        }
+        EligibilityRule::PoapHolder { event_id } => {
+            match events().may_load(deps.storage, event_id.u64())? {
+                Some(event) => {
+                    deps.querier
+                        .query_wasm_smart::<POAPQueryHasAttendedResponse>(
+                            event.poap_contract_address,
+                            &POAPQueryMsg::HasAttended {
+                                event_id: POAP_EVENT_ID.into(),
+                                user: user.to_string(),
+                            },
+                        )?
+                        .attended
+                }
+                None => false,
+            }
+        }
+        EligibilityRule::Allowlist { addresses } => {
+            addresses.iter().any(|address| address == user.as_str())
+        }
+    };
+    Ok(satisfied)
+}
+
+/// Checks that the addresses inside the eligibility rules are valid.
+fn validate_rules_addresses(api: &dyn Api, rules: &[EligibilityRule]) -> StdResult<()> {
+    for rule in rules {
+        if let EligibilityRule::Allowlist { addresses } = rule {
+            for address in addresses {
+                api.addr_validate(address)?;
+            }
+        }
+    }
+    Ok(())
 }
 
 fn execute_mint_to(
     deps: DepsMut<DesmosQuery>,
     info: MessageInfo,
+    event_id: u64,
     recipient: String,
 ) -> Result<Response<DesmosMsg>, ContractError> {
-    check_admin(deps.storage, &info)?;
-    let poap_contract_address = POAP_CONTRACT_ADDRESS.load(deps.storage)?;
+    let event = load_event_as_creator(deps.storage, &info, event_id)?;
     deps.api.addr_validate(&recipient)?;
     Ok(Response::new()
         .add_attribute(ATTRIBUTE_ACTION, ACTION_MINT_TO)
         .add_attribute(ATTRIBUTE_SENDER, &info.sender)
+        .add_attribute(ATTRIBUTE_EVENT_ID, event_id.to_string())
         .add_message(wasm_execute(
-            poap_contract_address,
-            &POAPExecuteMsg::MintTo { recipient },
+            event.poap_contract_address,
+            &POAPExecuteMsg::MintTo {
+                event_id: POAP_EVENT_ID.into(),
+                recipient,
+                tier: None,
+            },
             info.funds,
         )?))
 }
@@ -169,6 +504,200 @@ fn execute_update_admin(
         .add_attribute(ATTRIBUTE_SENDER, info.sender))
 }
 
+fn execute_update_eligibility_rules(
+    deps: DepsMut<DesmosQuery>,
+    info: MessageInfo,
+    rules: Vec<EligibilityRule>,
+) -> Result<Response<DesmosMsg>, ContractError> {
+    check_admin(deps.storage, &info)?;
+    validate_rules_addresses(deps.api, &rules)?;
+    ELIGIBILITY_RULES.save(deps.storage, &rules)?;
+    Ok(Response::new()
+        .add_attribute(ATTRIBUTE_ACTION, ACTION_UPDATE_ELIGIBILITY_RULES)
+        .add_attribute(ATTRIBUTE_SENDER, info.sender)
+        .add_attribute("rules_count", rules.len().to_string()))
+}
+
+fn execute_update_claim_limits(
+    deps: DepsMut<DesmosQuery>,
+    info: MessageInfo,
+    claim_limits: ClaimLimits,
+) -> Result<Response<DesmosMsg>, ContractError> {
+    check_admin(deps.storage, &info)?;
+    CLAIM_LIMITS.save(deps.storage, &claim_limits)?;
+    Ok(Response::new()
+        .add_attribute(ATTRIBUTE_ACTION, ACTION_UPDATE_CLAIM_LIMITS)
+        .add_attribute(ATTRIBUTE_SENDER, info.sender))
+}
+
+fn execute_set_mint_enabled(
+    deps: DepsMut<DesmosQuery>,
+    info: MessageInfo,
+    event_id: u64,
+    mint_enabled: bool,
+) -> Result<Response<DesmosMsg>, ContractError> {
+    let mut event = load_event_as_creator(deps.storage, &info, event_id)?;
+    // Keep track of the mint status to gate the claims, since the manager
+    // mints through MintTo that ignores the mint status of the POAP contract
+    event.mint_enabled = mint_enabled;
+    events().save(deps.storage, event_id, &event)?;
+    let (action, poap_msg) = if mint_enabled {
+        (
+            ACTION_ENABLE_MINT,
+            POAPExecuteMsg::EnableMint {
+                event_id: POAP_EVENT_ID.into(),
+            },
+        )
+    } else {
+        (
+            ACTION_DISABLE_MINT,
+            POAPExecuteMsg::DisableMint {
+                event_id: POAP_EVENT_ID.into(),
+            },
+        )
+    };
+    Ok(Response::new()
+        .add_attribute(ATTRIBUTE_ACTION, action)
+        .add_attribute(ATTRIBUTE_SENDER, info.sender)
+        .add_attribute(ATTRIBUTE_EVENT_ID, event_id.to_string())
+        .add_message(wasm_execute(
+            event.poap_contract_address,
+            &poap_msg,
+            vec![],
+        )?))
+}
+
+fn execute_update_event_info(
+    deps: DepsMut<DesmosQuery>,
+    info: MessageInfo,
+    event_id: u64,
+    start_time: Timestamp,
+    end_time: Timestamp,
+) -> Result<Response<DesmosMsg>, ContractError> {
+    let mut event = load_event_as_creator(deps.storage, &info, event_id)?;
+
+    // Keep the registry in sync, the poap contract validates the new times
+    event.start_time = start_time;
+    event.end_time = end_time;
+    events().save(deps.storage, event_id, &event)?;
+
+    Ok(Response::new()
+        .add_attribute(ATTRIBUTE_ACTION, ACTION_UPDATE_EVENT_INFO)
+        .add_attribute(ATTRIBUTE_SENDER, info.sender)
+        .add_attribute(ATTRIBUTE_EVENT_ID, event_id.to_string())
+        .add_message(wasm_execute(
+            event.poap_contract_address,
+            &POAPExecuteMsg::UpdateEventInfo {
+                event_id: POAP_EVENT_ID.into(),
+                start_time,
+                end_time,
+            },
+            vec![],
+        )?))
+}
+
+fn execute_update_minter(
+    deps: DepsMut<DesmosQuery>,
+    info: MessageInfo,
+    event_id: u64,
+    new_minter: String,
+) -> Result<Response<DesmosMsg>, ContractError> {
+    let event = load_event_as_creator(deps.storage, &info, event_id)?;
+    let new_minter = deps.api.addr_validate(&new_minter)?;
+    Ok(Response::new()
+        .add_attribute(ATTRIBUTE_ACTION, ACTION_UPDATE_MINTER)
+        .add_attribute(ATTRIBUTE_SENDER, info.sender)
+        .add_attribute(ATTRIBUTE_EVENT_ID, event_id.to_string())
+        .add_attribute("new_minter", &new_minter)
+        .add_message(wasm_execute(
+            event.poap_contract_address,
+            &POAPExecuteMsg::UpdateMinter {
+                new_minter: new_minter.into(),
+            },
+            vec![],
+        )?))
+}
+
+fn execute_claim_funds(
+    deps: DepsMut<DesmosQuery>,
+    info: MessageInfo,
+    event_id: u64,
+    receiver: String,
+) -> Result<Response<DesmosMsg>, ContractError> {
+    let event = load_event_as_creator(deps.storage, &info, event_id)?;
+    let receiver = deps.api.addr_validate(&receiver)?;
+    Ok(Response::new()
+        .add_attribute(ATTRIBUTE_ACTION, ACTION_CLAIM_FUNDS)
+        .add_attribute(ATTRIBUTE_SENDER, info.sender)
+        .add_attribute(ATTRIBUTE_EVENT_ID, event_id.to_string())
+        .add_attribute("receiver", &receiver)
+        .add_message(wasm_execute(
+            event.poap_contract_address,
+            &POAPExecuteMsg::ClaimFunds {
+                event_id: POAP_EVENT_ID.into(),
+                receiver: receiver.into(),
+            },
+            vec![],
+        )?))
+}
+
+fn execute_close_event(
+    deps: DepsMut<DesmosQuery>,
+    info: MessageInfo,
+    event_id: u64,
+) -> Result<Response<DesmosMsg>, ContractError> {
+    let event = load_event_as_creator(deps.storage, &info, event_id)?;
+    Ok(Response::new()
+        .add_attribute(ATTRIBUTE_ACTION, ACTION_CLOSE_EVENT)
+        .add_attribute(ATTRIBUTE_SENDER, info.sender)
+        .add_attribute(ATTRIBUTE_EVENT_ID, event_id.to_string())
+        .add_message(wasm_execute(
+            event.poap_contract_address,
+            &POAPExecuteMsg::CloseEvent {
+                event_id: POAP_EVENT_ID.into(),
+            },
+            vec![],
+        )?))
+}
+
+fn execute_revoke(
+    deps: DepsMut<DesmosQuery>,
+    info: MessageInfo,
+    event_id: u64,
+    token_id: String,
+    reason: String,
+) -> Result<Response<DesmosMsg>, ContractError> {
+    let event = load_event_as_creator(deps.storage, &info, event_id)?;
+    Ok(Response::new()
+        .add_attribute(ATTRIBUTE_ACTION, ACTION_REVOKE)
+        .add_attribute(ATTRIBUTE_SENDER, info.sender)
+        .add_attribute(ATTRIBUTE_EVENT_ID, event_id.to_string())
+        .add_attribute("token_id", &token_id)
+        .add_message(wasm_execute(
+            event.poap_contract_address,
+            &POAPExecuteMsg::Revoke { token_id, reason },
+            vec![],
+        )?))
+}
+
+/// Loads an event ensuring that the sender is the user that created it or the contract's admin.
+/// This is required since the manager is the creator of the event inside its POAP contract.
+fn load_event_as_creator(
+    storage: &dyn Storage,
+    info: &MessageInfo,
+    event_id: u64,
+) -> Result<EventRecord, ContractError> {
+    let event = load_event(storage, event_id)?;
+    let config = CONFIG.load(storage)?;
+    if info.sender != event.creator && info.sender != config.admin {
+        return Err(ContractError::NotEventCreator {
+            caller: info.sender.clone(),
+            event_id,
+        });
+    }
+    Ok(event)
+}
+
 fn check_admin(storage: &dyn Storage, info: &MessageInfo) -> Result<(), ContractError> {
     let config = CONFIG.load(storage)?;
     if config.admin != info.sender {
@@ -180,9 +709,60 @@ fn check_admin(storage: &dyn Storage, info: &MessageInfo) -> Result<(), Contract
 }
 
 #[cfg_attr(not(feature = "library"), entry_point)]
-pub fn query(deps: Deps<DesmosQuery>, _env: Env, msg: QueryMsg) -> StdResult<QueryResponse> {
+pub fn query(deps: Deps<DesmosQuery>, env: Env, msg: QueryMsg) -> StdResult<QueryResponse> {
     match msg {
         QueryMsg::Config {} => to_binary(&query_config(deps)?),
+        QueryMsg::Event { event_id } => to_binary(&query_event(deps, event_id.u64())?),
+        QueryMsg::Events {
+            creator,
+            start_after,
+            limit,
+        } => to_binary(&query_events(
+            deps,
+            creator,
+            start_after.map(|id| id.u64()),
+            limit,
+        )?),
+        QueryMsg::ActiveEvents { start_after, limit } => to_binary(&query_active_events(
+            deps,
+            env,
+            start_after.map(|id| id.u64()),
+            limit,
+        )?),
+        QueryMsg::EligibilityRules {} => to_binary(&query_eligibility_rules(deps)?),
+        QueryMsg::CheckEligibility { user } => {
+            to_binary(&query_check_eligibility(deps, env, user)?)
+        }
+        QueryMsg::ClaimStatus { event_id, user } => {
+            to_binary(&query_claim_status(deps, env, event_id.u64(), user)?)
+        }
+        QueryMsg::EventInfo { event_id } => to_binary(&query_event_info(deps, event_id.u64())?),
+        QueryMsg::MintedAmount { event_id, user } => {
+            to_binary(&query_minted_amount(deps, event_id.u64(), user)?)
+        }
+        QueryMsg::Tokens {
+            event_id,
+            owner,
+            start_after,
+            limit,
+        } => to_binary(&query_tokens(
+            deps,
+            event_id.u64(),
+            owner,
+            start_after,
+            limit,
+        )?),
+        QueryMsg::AllNftInfo {
+            event_id,
+            token_id,
+            include_expired,
+        } => to_binary(&query_all_nft_info(
+            deps,
+            event_id.u64(),
+            token_id,
+            include_expired,
+        )?),
+        QueryMsg::Overview { event_id } => to_binary(&query_overview(deps, event_id.u64())?),
     }
 }
 
@@ -191,7 +771,241 @@ fn query_config(deps: Deps<DesmosQuery>) -> StdResult<QueryConfigResponse> {
     Ok(QueryConfigResponse {
         admin: config.admin,
         poap_code_id: config.poap_code_id,
-        poap_contract_address: POAP_CONTRACT_ADDRESS.load(deps.storage)?,
+        claim_limits: CLAIM_LIMITS.load(deps.storage)?,
+    })
+}
+
+fn event_response(event_id: u64, event: EventRecord) -> QueryEventResponse {
+    QueryEventResponse {
+        event_id: event_id.into(),
+        creator: event.creator,
+        poap_contract_address: event.poap_contract_address,
+        start_time: event.start_time,
+        end_time: event.end_time,
+    }
+}
+
+fn query_event(deps: Deps<DesmosQuery>, event_id: u64) -> StdResult<QueryEventResponse> {
+    let event = events().load(deps.storage, event_id)?;
+    Ok(event_response(event_id, event))
+}
+
+fn query_events(
+    deps: Deps<DesmosQuery>,
+    creator: Option<String>,
+    start_after: Option<u64>,
+    limit: Option<u32>,
+) -> StdResult<QueryEventsResponse> {
+    let limit = limit.unwrap_or(DEFAULT_QUERY_LIMIT).min(MAX_QUERY_LIMIT) as usize;
+    let start = start_after.map(Bound::exclusive);
+    let events_map = events();
+    let iter = match creator {
+        Some(creator) => {
+            let creator = deps.api.addr_validate(&creator)?;
+            events_map.idx.creator.prefix(creator).range(
+                deps.storage,
+                start,
+                None,
+                Order::Ascending,
+            )
+        }
+        None => events_map.range(deps.storage, start, None, Order::Ascending),
+    };
+    let events = iter
+        .take(limit)
+        .map(|item| item.map(|(event_id, event)| event_response(event_id, event)))
+        .collect::<StdResult<Vec<_>>>()?;
+    Ok(QueryEventsResponse { events })
+}
+
+fn query_active_events(
+    deps: Deps<DesmosQuery>,
+    env: Env,
+    start_after: Option<u64>,
+    limit: Option<u32>,
+) -> StdResult<QueryActiveEventsResponse> {
+    let limit = limit.unwrap_or(DEFAULT_QUERY_LIMIT).min(MAX_QUERY_LIMIT) as usize;
+    let events_map = events();
+    // Iterate only the events that are not ended yet
+    let mut start = (env.block.time.seconds(), u64::MAX);
+    if let Some(event_id) = start_after {
+        let event = events_map.load(deps.storage, event_id)?;
+        start = start.max((event.end_time.seconds(), event_id));
+    }
+    let mut range = events_map.idx.end_time.range(
+        deps.storage,
+        Some(Bound::exclusive(start)),
+        None,
+        Order::Ascending,
+    );
+    // Bound the scanned events, since the not started ones are scanned but not returned
+    let mut events = Vec::with_capacity(limit);
+    let mut scanned = 0;
+    let mut last_scanned = None;
+    while events.len() < limit && scanned < MAX_SCANNED_ACTIVE_EVENTS {
+        match range.next() {
+            Some(item) => {
+                let (event_id, event) = item?;
+                scanned += 1;
+                last_scanned = Some(event_id);
+                if event.start_time <= env.block.time {
+                    events.push(event_response(event_id, event));
+                }
+            }
+            None => {
+                last_scanned = None;
+                break;
+            }
+        }
+    }
+    Ok(QueryActiveEventsResponse {
+        events,
+        next_start_after: last_scanned.map(Uint64::new),
+    })
+}
+
+fn query_eligibility_rules(deps: Deps<DesmosQuery>) -> StdResult<QueryEligibilityRulesResponse> {
+    Ok(QueryEligibilityRulesResponse {
+        rules: ELIGIBILITY_RULES.load(deps.storage)?,
+    })
+}
+
+fn query_check_eligibility(
+    deps: Deps<DesmosQuery>,
+    env: Env,
+    user: String,
+) -> StdResult<QueryCheckEligibilityResponse> {
+    let user = deps.api.addr_validate(&user)?;
+    let failed_rules = failed_eligibility_rules(deps, &env, &user)?;
+    Ok(QueryCheckEligibilityResponse {
+        eligible: failed_rules.is_empty(),
+        failed_rules,
+    })
+}
+
+fn query_claim_status(
+    deps: Deps<DesmosQuery>,
+    env: Env,
+    event_id: u64,
+    user: String,
+) -> StdResult<QueryClaimStatusResponse> {
+    let user = deps.api.addr_validate(&user)?;
+    let claim_limits = CLAIM_LIMITS.load(deps.storage)?;
+    let dtag = query_dtag(deps, &user);
+    let claimed_by = match &dtag {
+        Some(dtag) => CLAIMED_DTAGS.may_load(deps.storage, (event_id, dtag))?,
+        None => None,
+    };
+    let next_claim_time = match claim_limits.cooldown_seconds {
+        Some(cooldown_seconds) => next_claim_time(deps.storage, &env, &user, cooldown_seconds)?,
+        None => None,
+    };
+    Ok(QueryClaimStatusResponse {
+        last_claim_time: LAST_CLAIM_TIMES.may_load(deps.storage, &user)?,
+        user,
+        dtag,
+        claimed_by,
+        next_claim_time,
+    })
+}
+
+/// Sends the given query to the POAP contract of an event.
+fn query_poap_contract<T: DeserializeOwned>(
+    deps: Deps<DesmosQuery>,
+    event_id: u64,
+    msg: &POAPQueryMsg,
+) -> StdResult<T> {
+    let event = events().load(deps.storage, event_id)?;
+    deps.querier
+        .query_wasm_smart(event.poap_contract_address, msg)
+}
+
+fn query_event_info(
+    deps: Deps<DesmosQuery>,
+    event_id: u64,
+) -> StdResult<POAPQueryEventInfoResponse> {
+    let mut event_info: POAPQueryEventInfoResponse = query_poap_contract(
+        deps,
+        event_id,
+        &POAPQueryMsg::EventInfo {
+            event_id: POAP_EVENT_ID.into(),
+        },
+    )?;
+    // Expose the event with the id used inside the manager
+    event_info.event_id = event_id.into();
+    Ok(event_info)
+}
+
+fn query_minted_amount(
+    deps: Deps<DesmosQuery>,
+    event_id: u64,
+    user: String,
+) -> StdResult<POAPQueryMintedAmountResponse> {
+    query_poap_contract(
+        deps,
+        event_id,
+        &POAPQueryMsg::MintedAmount {
+            event_id: POAP_EVENT_ID.into(),
+            user,
+        },
+    )
+}
+
+fn query_tokens(
+    deps: Deps<DesmosQuery>,
+    event_id: u64,
+    owner: String,
+    start_after: Option<String>,
+    limit: Option<u32>,
+) -> StdResult<TokensResponse> {
+    query_poap_contract(
+        deps,
+        event_id,
+        &POAPQueryMsg::Tokens {
+            owner,
+            start_after,
+            limit,
+        },
+    )
+}
+
+fn query_all_nft_info(
+    deps: Deps<DesmosQuery>,
+    event_id: u64,
+    token_id: String,
+    include_expired: Option<bool>,
+) -> StdResult<AllNftInfoResponse<Metadata>> {
+    query_poap_contract(
+        deps,
+        event_id,
+        &POAPQueryMsg::AllNftInfo {
+            token_id,
+            include_expired,
+        },
+    )
+}
+
+fn query_overview(deps: Deps<DesmosQuery>, event_id: u64) -> StdResult<QueryOverviewResponse> {
+    let status: POAPQueryStatusResponse = query_poap_contract(
+        deps,
+        event_id,
+        &POAPQueryMsg::Status {
+            event_id: POAP_EVENT_ID.into(),
+        },
+    )?;
+    let stats: POAPQueryStatsResponse = query_poap_contract(
+        deps,
+        event_id,
+        &POAPQueryMsg::Stats {
+            event_id: POAP_EVENT_ID.into(),
+        },
+    )?;
+    Ok(QueryOverviewResponse {
+        config: query_config(deps)?,
+        event_info: query_event_info(deps, event_id)?,
+        mint_enabled: events().load(deps.storage, event_id)?.mint_enabled,
+        status: status.status,
+        claimed: stats.minted,
     })
 }
 
@@ -199,35 +1013,58 @@ fn query_config(deps: Deps<DesmosQuery>) -> StdResult<QueryConfigResponse> {
 mod tests {
     use super::*;
     use cosmwasm_std::testing::{mock_env, mock_info};
-    use cosmwasm_std::{StdError, SubMsgResponse, SubMsgResult, Timestamp};
+    use cosmwasm_std::{
+        to_binary, Binary, ContractResult, StdError, SubMsgResponse, SubMsgResult, Timestamp,
+        Uint64,
+    };
     use cw721_base::InstantiateMsg as Cw721InstantiateMsg;
     use cw_utils::ParseReplyError;
-    use desmos_bindings::mocks::mock_queriers::mock_desmos_dependencies;
-    use poap::msg::{EventInfo, InstantiateMsg as POAPInstantiateMsg};
+    use desmos_bindings::mocks::mock_queriers::{
+        mock_desmos_dependencies, mock_desmos_dependencies_with_custom_querier, MockDesmosQuerier,
+    };
+    use desmos_bindings::posts::mocks::MockPostsQueries;
+    use desmos_bindings::posts::models_query::QuerySubspacePostsResponse;
+    use desmos_bindings::posts::query::PostsQuery;
+    use desmos_bindings::types::PageResponse;
+    use poap::msg::EventInfo;
 
     const CREATOR: &str = "desmos1nwp8gxrnmrsrzjdhvk47vvmthzxjtphgxp5ftc";
     const NEW_ADMIN: &str = "desmos1fcrca0eyvj32yeqwyqgs245gjmq4ee9vjjdlnz";
+    const POAP_CONTRACT: &str = "poap_contract";
+    const RECEIVER: &str = "desmos1jnpfa06xhflyjh6klwlrq8mk55s53czh6ncdm3";
 
     fn get_valid_instantiate() -> InstantiateMsg {
         InstantiateMsg {
             admin: "desmos1nwp8gxrnmrsrzjdhvk47vvmthzxjtphgxp5ftc".into(),
             poap_code_id: 1u64.into(),
-            poap_instantiate_msg: POAPInstantiateMsg {
-                admin: CREATOR.into(),
+            eligibility_rules: None,
+            claim_limits: None,
+        }
+    }
+
+    fn get_valid_poap_instantiate_msg() -> POAPInstantiateMsg {
+        POAPInstantiateMsg {
+            admin: CREATOR.into(),
+            minter: CREATOR.into(),
+            attestor_pubkey: None,
+            cw721_code_id: 2u64.into(),
+            cw721_instantiate_msg: Cw721InstantiateMsg {
                 minter: CREATOR.into(),
-                cw721_code_id: 2u64.into(),
-                cw721_instantiate_msg: Cw721InstantiateMsg {
-                    minter: CREATOR.into(),
-                    name: CREATOR.into(),
-                    symbol: CREATOR.into(),
-                },
-                event_info: EventInfo {
-                    creator: CREATOR.into(),
-                    start_time: Timestamp::from_seconds(10),
-                    end_time: Timestamp::from_seconds(20),
-                    per_address_limit: 2,
-                    poap_uri: "ipfs://popap-uri".to_string(),
-                },
+                name: CREATOR.into(),
+                symbol: CREATOR.into(),
+            },
+            soulbound: false,
+            event_info: EventInfo {
+                creator: CREATOR.into(),
+                start_time: Timestamp::from_seconds(10),
+                end_time: Timestamp::from_seconds(20),
+                per_address_limit: 2,
+                poap_uri: "ipfs://popap-uri".to_string(),
+                merkle_root: None,
+                max_supply: None,
+                mint_price: vec![],
+                tier_uris: vec![],
+                phases: vec![],
             },
         }
     }
@@ -239,6 +1076,23 @@ mod tests {
         instantiate(deps, env, info, valid_msg).unwrap();
     }
 
+    /// Registers an event as if its POAP contract has been instantiated.
+    fn save_event(storage: &mut dyn Storage, event_id: u64, creator: &str, start: u64, end: u64) {
+        events()
+            .save(
+                storage,
+                event_id,
+                &EventRecord {
+                    creator: Addr::unchecked(creator),
+                    poap_contract_address: Addr::unchecked(POAP_CONTRACT),
+                    start_time: Timestamp::from_seconds(start),
+                    end_time: Timestamp::from_seconds(end),
+                    mint_enabled: true,
+                },
+            )
+            .unwrap();
+    }
+
     #[test]
     fn instatiate_with_invalid_msg_error() {
         let mut deps = mock_desmos_dependencies();
@@ -247,23 +1101,8 @@ mod tests {
         let invalid_msg = InstantiateMsg {
             admin: "".into(),
             poap_code_id: 0u64.into(),
-            poap_instantiate_msg: POAPInstantiateMsg {
-                admin: CREATOR.into(),
-                minter: CREATOR.into(),
-                cw721_code_id: 2u64.into(),
-                cw721_instantiate_msg: Cw721InstantiateMsg {
-                    minter: CREATOR.into(),
-                    name: CREATOR.into(),
-                    symbol: CREATOR.into(),
-                },
-                event_info: EventInfo {
-                    creator: CREATOR.into(),
-                    start_time: Timestamp::from_seconds(10),
-                    end_time: Timestamp::from_seconds(20),
-                    per_address_limit: 2,
-                    poap_uri: "ipfs://popap-uri".to_string(),
-                },
-            },
+            eligibility_rules: None,
+            claim_limits: None,
         };
         assert_eq!(
             instantiate(deps.as_mut(), env, info, invalid_msg).unwrap_err(),
@@ -279,23 +1118,8 @@ mod tests {
         let invalid_msg = InstantiateMsg {
             admin: "a".into(),
             poap_code_id: 1u64.into(),
-            poap_instantiate_msg: POAPInstantiateMsg {
-                admin: CREATOR.into(),
-                minter: CREATOR.into(),
-                cw721_code_id: 2u64.into(),
-                cw721_instantiate_msg: Cw721InstantiateMsg {
-                    minter: CREATOR.into(),
-                    name: CREATOR.into(),
-                    symbol: CREATOR.into(),
-                },
-                event_info: EventInfo {
-                    creator: CREATOR.into(),
-                    start_time: Timestamp::from_seconds(10),
-                    end_time: Timestamp::from_seconds(20),
-                    per_address_limit: 2,
-                    poap_uri: "ipfs://popap-uri".to_string(),
-                },
-            },
+            eligibility_rules: None,
+            claim_limits: None,
         };
         assert_eq!(
             instantiate(deps.as_mut(), env, info, invalid_msg).unwrap_err(),
@@ -315,17 +1139,80 @@ mod tests {
             admin: Addr::unchecked("desmos1nwp8gxrnmrsrzjdhvk47vvmthzxjtphgxp5ftc"),
             poap_code_id: 1u64,
         };
-        assert_eq!(config, expected)
+        assert_eq!(config, expected);
+
+        // Only a profile is required by default
+        assert_eq!(
+            vec![EligibilityRule::ProfileRequired {}],
+            ELIGIBILITY_RULES.load(&deps.storage).unwrap()
+        );
+
+        // Only one claim per DTag is allowed by default
+        assert_eq!(
+            ClaimLimits::default(),
+            CLAIM_LIMITS.load(&deps.storage).unwrap()
+        );
     }
 
     #[test]
-    fn poap_instantiate_with_invalid_reply_id_error() {
+    fn create_event_with_other_creator_registers_sender_properly() {
+        let mut deps = mock_desmos_dependencies();
+        do_instantiate(deps.as_mut());
+        let mut poap_instantiate_msg = get_valid_poap_instantiate_msg();
+        poap_instantiate_msg.event_info.creator = NEW_ADMIN.into();
+        let msg = ExecuteMsg::CreateEvent {
+            poap_instantiate_msg,
+        };
+        execute(deps.as_mut(), mock_env(), mock_info(CREATOR, &vec![]), msg).unwrap();
+        assert_eq!(
+            Addr::unchecked(CREATOR),
+            PENDING_EVENTS.load(&deps.storage, 1).unwrap().creator
+        );
+    }
+
+    #[test]
+    fn create_event_properly() {
         let mut deps = mock_desmos_dependencies();
         do_instantiate(deps.as_mut());
         let env = mock_env();
-        let result = reply(
-            deps.as_mut(),
-            env,
+        let msg = ExecuteMsg::CreateEvent {
+            poap_instantiate_msg: get_valid_poap_instantiate_msg(),
+        };
+        let response =
+            execute(deps.as_mut(), env.clone(), mock_info(CREATOR, &vec![]), msg).unwrap();
+
+        // The POAP contract is instantiated with the manager as admin, minter and event creator
+        // and the event id as reply id
+        let mut poap_instantiate_msg = get_valid_poap_instantiate_msg();
+        poap_instantiate_msg.admin = env.contract.address.to_string();
+        poap_instantiate_msg.minter = env.contract.address.to_string();
+        poap_instantiate_msg.event_info.creator = env.contract.address.to_string();
+        assert_eq!(
+            vec![SubMsg::reply_on_success(
+                wasm_instantiate(1, &poap_instantiate_msg, vec![], "poap_1".into()).unwrap(),
+                1,
+            )],
+            response.messages
+        );
+        assert_eq!(
+            PendingEvent {
+                creator: Addr::unchecked(CREATOR),
+                start_time: Timestamp::from_seconds(10),
+                end_time: Timestamp::from_seconds(20),
+            },
+            PENDING_EVENTS.load(&deps.storage, 1).unwrap()
+        );
+        assert_eq!(2, NEXT_EVENT_ID.load(&deps.storage).unwrap());
+    }
+
+    #[test]
+    fn poap_instantiate_with_invalid_reply_id_error() {
+        let mut deps = mock_desmos_dependencies();
+        do_instantiate(deps.as_mut());
+        let env = mock_env();
+        let result = reply(
+            deps.as_mut(),
+            env,
             Reply {
                 id: 2,
                 result: SubMsgResult::Ok(SubMsgResponse {
@@ -341,6 +1228,11 @@ mod tests {
     fn poap_instantiate_with_invalid_instantiate_msg_error() {
         let mut deps = mock_desmos_dependencies();
         do_instantiate(deps.as_mut());
+        let msg = ExecuteMsg::CreateEvent {
+            poap_instantiate_msg: get_valid_poap_instantiate_msg(),
+        };
+        execute(deps.as_mut(), mock_env(), mock_info(CREATOR, &vec![]), msg).unwrap();
+
         let env = mock_env();
         let result = reply(
             deps.as_mut(),
@@ -362,15 +1254,17 @@ mod tests {
     }
 
     #[test]
-    fn claim_with_unsupported_desmos_deps_error() {
+    fn claim_with_nonexistent_event_error() {
         let mut deps = mock_desmos_dependencies();
         do_instantiate(deps.as_mut());
         let env = mock_env();
         let info = mock_info(CREATOR, &vec![]);
-        let msg = ExecuteMsg::Claim {};
+        let msg = ExecuteMsg::Claim {
+            event_id: 1u64.into(),
+        };
         assert_eq!(
             execute(deps.as_mut(), env, info, msg).unwrap_err(),
-            ContractError::Std(StdError::not_found("cosmwasm_std::addresses::Addr"))
+            ContractError::EventNotFound { event_id: 1 }
         )
     }
 
@@ -378,12 +1272,12 @@ mod tests {
     fn claim_properly() {
         let mut deps = mock_desmos_dependencies();
         do_instantiate(deps.as_mut());
-        POAP_CONTRACT_ADDRESS
-            .save(deps.as_mut().storage, &Addr::unchecked(""))
-            .unwrap();
+        save_event(deps.as_mut().storage, 1, CREATOR, 10, 20);
         let env = mock_env();
         let info = mock_info(CREATOR, &vec![]);
-        let msg = ExecuteMsg::Claim {};
+        let msg = ExecuteMsg::Claim {
+            event_id: 1u64.into(),
+        };
         execute(deps.as_mut(), env, info, msg).unwrap();
     }
 
@@ -391,17 +1285,17 @@ mod tests {
     fn mint_to_without_permissions_error() {
         let mut deps = mock_desmos_dependencies();
         do_instantiate(deps.as_mut());
-        POAP_CONTRACT_ADDRESS
-            .save(deps.as_mut().storage, &Addr::unchecked(""))
-            .unwrap();
+        save_event(deps.as_mut().storage, 1, CREATOR, 10, 20);
         let env = mock_env();
         let info = mock_info(NEW_ADMIN, &vec![]);
         let msg = ExecuteMsg::MintTo {
+            event_id: 1u64.into(),
             recipient: CREATOR.into(),
         };
         assert_eq!(
-            ContractError::NotAdmin {
-                caller: Addr::unchecked(NEW_ADMIN)
+            ContractError::NotEventCreator {
+                caller: Addr::unchecked(NEW_ADMIN),
+                event_id: 1,
             },
             execute(deps.as_mut(), env, info, msg).unwrap_err(),
         )
@@ -411,12 +1305,11 @@ mod tests {
     fn mint_to_with_invalid_recipient_error() {
         let mut deps = mock_desmos_dependencies();
         do_instantiate(deps.as_mut());
-        POAP_CONTRACT_ADDRESS
-            .save(deps.as_mut().storage, &Addr::unchecked(""))
-            .unwrap();
+        save_event(deps.as_mut().storage, 1, CREATOR, 10, 20);
         let env = mock_env();
         let info = mock_info(CREATOR, &vec![]);
         let msg = ExecuteMsg::MintTo {
+            event_id: 1u64.into(),
             recipient: "a".into(),
         };
         assert_eq!(
@@ -431,15 +1324,151 @@ mod tests {
     fn mint_to_properly() {
         let mut deps = mock_desmos_dependencies();
         do_instantiate(deps.as_mut());
-        POAP_CONTRACT_ADDRESS
-            .save(deps.as_mut().storage, &Addr::unchecked(""))
-            .unwrap();
+        save_event(deps.as_mut().storage, 1, CREATOR, 10, 20);
         let env = mock_env();
         let info = mock_info(CREATOR, &vec![]);
         let msg = ExecuteMsg::MintTo {
+            event_id: 1u64.into(),
             recipient: CREATOR.into(),
         };
-        execute(deps.as_mut(), env, info, msg).unwrap();
+        let response = execute(deps.as_mut(), env, info, msg).unwrap();
+        assert_eq!(
+            vec![SubMsg::new(
+                wasm_execute(
+                    POAP_CONTRACT,
+                    &POAPExecuteMsg::MintTo {
+                        event_id: POAP_EVENT_ID.into(),
+                        recipient: CREATOR.into(),
+                        tier: None,
+                    },
+                    vec![],
+                )
+                .unwrap()
+            )],
+            response.messages
+        );
+    }
+
+    #[test]
+    fn query_events_properly() {
+        let mut deps = mock_desmos_dependencies();
+        do_instantiate(deps.as_mut());
+        save_event(deps.as_mut().storage, 1, CREATOR, 10, 20);
+        save_event(deps.as_mut().storage, 2, NEW_ADMIN, 10, 20);
+        save_event(deps.as_mut().storage, 3, CREATOR, 30, 40);
+
+        let response = query_events(deps.as_ref(), None, None, None).unwrap();
+        assert_eq!(
+            vec![1u64.into(), 2u64.into(), 3u64.into()],
+            response
+                .events
+                .iter()
+                .map(|event| event.event_id)
+                .collect::<Vec<Uint64>>()
+        );
+
+        let response = query_events(deps.as_ref(), Some(CREATOR.into()), Some(1), Some(1)).unwrap();
+        assert_eq!(
+            vec![QueryEventResponse {
+                event_id: 3u64.into(),
+                creator: Addr::unchecked(CREATOR),
+                poap_contract_address: Addr::unchecked(POAP_CONTRACT),
+                start_time: Timestamp::from_seconds(30),
+                end_time: Timestamp::from_seconds(40),
+            }],
+            response.events
+        );
+    }
+
+    #[test]
+    fn query_active_events_properly() {
+        let mut deps = mock_desmos_dependencies();
+        do_instantiate(deps.as_mut());
+        save_event(deps.as_mut().storage, 1, CREATOR, 10, 20);
+        save_event(deps.as_mut().storage, 2, CREATOR, 10, 40);
+        save_event(deps.as_mut().storage, 3, CREATOR, 30, 40);
+
+        let mut env = mock_env();
+        env.block.time = Timestamp::from_seconds(20);
+        let response = query_active_events(deps.as_ref(), env, None, None).unwrap();
+        assert_eq!(
+            vec![2u64.into()],
+            response
+                .events
+                .iter()
+                .map(|event| event.event_id)
+                .collect::<Vec<Uint64>>()
+        );
+    }
+
+    #[test]
+    fn query_active_events_paginated_properly() {
+        let mut deps = mock_desmos_dependencies();
+        do_instantiate(deps.as_mut());
+        save_event(deps.as_mut().storage, 1, CREATOR, 10, 50);
+        save_event(deps.as_mut().storage, 2, CREATOR, 10, 40);
+        save_event(deps.as_mut().storage, 3, CREATOR, 10, 40);
+        save_event(deps.as_mut().storage, 4, CREATOR, 10, 20);
+
+        let mut env = mock_env();
+        env.block.time = Timestamp::from_seconds(20);
+        let response = query_active_events(deps.as_ref(), env.clone(), None, Some(2)).unwrap();
+        assert_eq!(
+            vec![2u64.into(), 3u64.into()],
+            response
+                .events
+                .iter()
+                .map(|event| event.event_id)
+                .collect::<Vec<Uint64>>()
+        );
+
+        let response = query_active_events(deps.as_ref(), env, Some(3), Some(2)).unwrap();
+        assert_eq!(
+            vec![1u64.into()],
+            response
+                .events
+                .iter()
+                .map(|event| event.event_id)
+                .collect::<Vec<Uint64>>()
+        );
+    }
+
+    #[test]
+    fn query_active_events_scans_bounded_events_properly() {
+        let mut deps = mock_desmos_dependencies();
+        do_instantiate(deps.as_mut());
+        // Events not started yet ending before the active one
+        for event_id in 1..=MAX_SCANNED_ACTIVE_EVENTS as u64 {
+            save_event(deps.as_mut().storage, event_id, CREATOR, 30, 35);
+        }
+        let active_event_id = MAX_SCANNED_ACTIVE_EVENTS as u64 + 1;
+        save_event(deps.as_mut().storage, active_event_id, CREATOR, 10, 40);
+
+        let mut env = mock_env();
+        env.block.time = Timestamp::from_seconds(20);
+        let response = query_active_events(deps.as_ref(), env.clone(), None, None).unwrap();
+        assert!(response.events.is_empty());
+        assert_eq!(
+            Some(Uint64::new(MAX_SCANNED_ACTIVE_EVENTS as u64)),
+            response.next_start_after
+        );
+
+        let response = query_active_events(
+            deps.as_ref(),
+            env,
+            response.next_start_after.map(|id| id.u64()),
+            None,
+        )
+        .unwrap();
+        assert_eq!(
+            vec![Uint64::new(active_event_id)],
+            response
+                .events
+                .iter()
+                .map(|event| event.event_id)
+                .collect::<Vec<Uint64>>()
+        );
+        assert_eq!(None, response.next_start_after);
     }
 
     #[test]
@@ -511,4 +1540,719 @@ mod tests {
         };
         assert_eq!(config, expected)
     }
+
+    #[test]
+    fn update_eligibility_rules_without_permission_error() {
+        let mut deps = mock_desmos_dependencies();
+        do_instantiate(deps.as_mut());
+        let msg = ExecuteMsg::UpdateEligibilityRules { rules: vec![] };
+        assert_eq!(
+            execute(
+                deps.as_mut(),
+                mock_env(),
+                mock_info(NEW_ADMIN, &vec![]),
+                msg
+            )
+            .unwrap_err(),
+            ContractError::NotAdmin {
+                caller: Addr::unchecked(NEW_ADMIN)
+            }
+        )
+    }
+
+    #[test]
+    fn update_eligibility_rules_with_invalid_allowlist_address_error() {
+        let mut deps = mock_desmos_dependencies();
+        do_instantiate(deps.as_mut());
+        let msg = ExecuteMsg::UpdateEligibilityRules {
+            rules: vec![EligibilityRule::Allowlist {
+                addresses: vec!["a".into()],
+            }],
+        };
+        assert_eq!(
+            execute(deps.as_mut(), mock_env(), mock_info(CREATOR, &vec![]), msg).unwrap_err(),
+            ContractError::Std(StdError::generic_err(
+                "Invalid input: human address too short"
+            ))
+        )
+    }
+
+    #[test]
+    fn update_eligibility_rules_properly() {
+        let mut deps = mock_desmos_dependencies();
+        do_instantiate(deps.as_mut());
+        let rules = vec![
+            EligibilityRule::ProfileRequired {},
+            EligibilityRule::Allowlist {
+                addresses: vec![NEW_ADMIN.into()],
+            },
+        ];
+        let msg = ExecuteMsg::UpdateEligibilityRules {
+            rules: rules.clone(),
+        };
+        execute(deps.as_mut(), mock_env(), mock_info(CREATOR, &vec![]), msg).unwrap();
+        assert_eq!(rules, query_eligibility_rules(deps.as_ref()).unwrap().rules);
+    }
+
+    #[test]
+    fn min_posts_rule_outside_scanned_posts_not_satisfied() {
+        // Endless subspace whose posts are all created from CREATOR
+        let querier = MockDesmosQuerier::default().with_custom_posts_handler(|query| match query {
+            PostsQuery::SubspacePosts { subspace_id, .. } => {
+                to_binary(&QuerySubspacePostsResponse {
+                    posts: MockPostsQueries::get_mocked_subspace_posts(subspace_id),
+                    pagination: Some(PageResponse {
+                        next_key: Some(Binary::from(b"next")),
+                        total: None,
+                    }),
+                })
+                .into()
+            }
+            _ => ContractResult::Err("not supported".to_string()),
+        });
+        let deps = mock_desmos_dependencies_with_custom_querier(querier);
+        let rule = EligibilityRule::MinPosts {
+            subspace_id: 1u64.into(),
+            min_posts: 1,
+        };
+
+        assert!(
+            is_rule_satisfied(deps.as_ref(), &mock_env(), &Addr::unchecked(CREATOR), &rule)
+                .unwrap()
+        );
+        assert!(!is_rule_satisfied(
+            deps.as_ref(),
+            &mock_env(),
+            &Addr::unchecked(NEW_ADMIN),
+            &rule
+        )
+        .unwrap());
+    }
+
+    #[test]
+    fn check_eligibility_properly() {
+        let mut deps = mock_desmos_dependencies();
+        do_instantiate(deps.as_mut());
+        ELIGIBILITY_RULES
+            .save(
+                deps.as_mut().storage,
+                &vec![
+                    EligibilityRule::ProfileRequired {},
+                    EligibilityRule::Allowlist {
+                        addresses: vec![NEW_ADMIN.into()],
+                    },
+                    EligibilityRule::PoapHolder {
+                        event_id: 5u64.into(),
+                    },
+                ],
+            )
+            .unwrap();
+
+        let response = query_check_eligibility(deps.as_ref(), mock_env(), CREATOR.into()).unwrap();
+        assert_eq!(
+            QueryCheckEligibilityResponse {
+                eligible: false,
+                failed_rules: vec![
+                    EligibilityRule::Allowlist {
+                        addresses: vec![NEW_ADMIN.into()],
+                    },
+                    EligibilityRule::PoapHolder {
+                        event_id: 5u64.into(),
+                    },
+                ],
+            },
+            response
+        );
+    }
+
+    #[test]
+    fn claim_without_eligibility_error() {
+        let mut deps = mock_desmos_dependencies();
+        do_instantiate(deps.as_mut());
+        save_event(deps.as_mut().storage, 1, CREATOR, 10, 20);
+        ELIGIBILITY_RULES
+            .save(
+                deps.as_mut().storage,
+                &vec![EligibilityRule::Allowlist {
+                    addresses: vec![NEW_ADMIN.into()],
+                }],
+            )
+            .unwrap();
+        let msg = ExecuteMsg::Claim {
+            event_id: 1u64.into(),
+        };
+        assert_eq!(
+            execute(deps.as_mut(), mock_env(), mock_info(CREATOR, &vec![]), msg).unwrap_err(),
+            ContractError::NoEligibilityError {}
+        )
+    }
+
+    #[test]
+    fn enable_mint_without_permission_error() {
+        let mut deps = mock_desmos_dependencies();
+        do_instantiate(deps.as_mut());
+        save_event(deps.as_mut().storage, 1, CREATOR, 10, 20);
+        let msg = ExecuteMsg::EnableMint {
+            event_id: 1u64.into(),
+        };
+        assert_eq!(
+            execute(
+                deps.as_mut(),
+                mock_env(),
+                mock_info(NEW_ADMIN, &vec![]),
+                msg
+            )
+            .unwrap_err(),
+            ContractError::NotEventCreator {
+                caller: Addr::unchecked(NEW_ADMIN),
+                event_id: 1,
+            }
+        )
+    }
+
+    #[test]
+    fn disable_mint_with_nonexistent_event_error() {
+        let mut deps = mock_desmos_dependencies();
+        do_instantiate(deps.as_mut());
+        let msg = ExecuteMsg::DisableMint {
+            event_id: 1u64.into(),
+        };
+        assert_eq!(
+            execute(deps.as_mut(), mock_env(), mock_info(CREATOR, &vec![]), msg).unwrap_err(),
+            ContractError::EventNotFound { event_id: 1 }
+        )
+    }
+
+    #[test]
+    fn enable_mint_properly() {
+        let mut deps = mock_desmos_dependencies();
+        do_instantiate(deps.as_mut());
+        save_event(deps.as_mut().storage, 1, CREATOR, 10, 20);
+        let msg = ExecuteMsg::DisableMint {
+            event_id: 1u64.into(),
+        };
+        execute(deps.as_mut(), mock_env(), mock_info(CREATOR, &vec![]), msg).unwrap();
+        assert!(!events().load(&deps.storage, 1).unwrap().mint_enabled);
+
+        let msg = ExecuteMsg::EnableMint {
+            event_id: 1u64.into(),
+        };
+        let response =
+            execute(deps.as_mut(), mock_env(), mock_info(CREATOR, &vec![]), msg).unwrap();
+        assert_eq!(
+            vec![SubMsg::new(
+                wasm_execute(
+                    POAP_CONTRACT,
+                    &POAPExecuteMsg::EnableMint {
+                        event_id: POAP_EVENT_ID.into(),
+                    },
+                    vec![]
+                )
+                .unwrap()
+            )],
+            response.messages
+        );
+        assert!(events().load(&deps.storage, 1).unwrap().mint_enabled);
+    }
+
+    #[test]
+    fn claim_with_mint_disabled_error() {
+        let mut deps = mock_desmos_dependencies();
+        do_instantiate(deps.as_mut());
+        save_event(deps.as_mut().storage, 1, CREATOR, 10, 20);
+        let msg = ExecuteMsg::DisableMint {
+            event_id: 1u64.into(),
+        };
+        execute(deps.as_mut(), mock_env(), mock_info(CREATOR, &vec![]), msg).unwrap();
+
+        let msg = ExecuteMsg::Claim {
+            event_id: 1u64.into(),
+        };
+        assert_eq!(
+            execute(deps.as_mut(), mock_env(), mock_info(CREATOR, &vec![]), msg).unwrap_err(),
+            ContractError::MintDisabled { event_id: 1 }
+        )
+    }
+
+    #[test]
+    fn claim_funds_without_permission_error() {
+        let mut deps = mock_desmos_dependencies();
+        do_instantiate(deps.as_mut());
+        save_event(deps.as_mut().storage, 1, NEW_ADMIN, 10, 20);
+        let msg = ExecuteMsg::ClaimFunds {
+            event_id: 1u64.into(),
+            receiver: NEW_ADMIN.into(),
+        };
+        assert_eq!(
+            execute(deps.as_mut(), mock_env(), mock_info(RECEIVER, &vec![]), msg).unwrap_err(),
+            ContractError::NotEventCreator {
+                caller: Addr::unchecked(RECEIVER),
+                event_id: 1
+            }
+        )
+    }
+
+    #[test]
+    fn claim_funds_from_event_creator_properly() {
+        let mut deps = mock_desmos_dependencies();
+        do_instantiate(deps.as_mut());
+        save_event(deps.as_mut().storage, 1, NEW_ADMIN, 10, 20);
+        let msg = ExecuteMsg::ClaimFunds {
+            event_id: 1u64.into(),
+            receiver: RECEIVER.into(),
+        };
+        let response = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info(NEW_ADMIN, &vec![]),
+            msg,
+        )
+        .unwrap();
+        assert_eq!(
+            vec![SubMsg::new(
+                wasm_execute(
+                    POAP_CONTRACT,
+                    &POAPExecuteMsg::ClaimFunds {
+                        event_id: POAP_EVENT_ID.into(),
+                        receiver: RECEIVER.into(),
+                    },
+                    vec![]
+                )
+                .unwrap()
+            )],
+            response.messages
+        );
+    }
+
+    #[test]
+    fn close_event_without_permission_error() {
+        let mut deps = mock_desmos_dependencies();
+        do_instantiate(deps.as_mut());
+        save_event(deps.as_mut().storage, 1, NEW_ADMIN, 10, 20);
+        let msg = ExecuteMsg::CloseEvent {
+            event_id: 1u64.into(),
+        };
+        assert_eq!(
+            execute(deps.as_mut(), mock_env(), mock_info(RECEIVER, &vec![]), msg).unwrap_err(),
+            ContractError::NotEventCreator {
+                caller: Addr::unchecked(RECEIVER),
+                event_id: 1
+            }
+        )
+    }
+
+    #[test]
+    fn close_event_from_admin_properly() {
+        let mut deps = mock_desmos_dependencies();
+        do_instantiate(deps.as_mut());
+        save_event(deps.as_mut().storage, 1, NEW_ADMIN, 10, 20);
+        let msg = ExecuteMsg::CloseEvent {
+            event_id: 1u64.into(),
+        };
+        let response =
+            execute(deps.as_mut(), mock_env(), mock_info(CREATOR, &vec![]), msg).unwrap();
+        assert_eq!(
+            vec![SubMsg::new(
+                wasm_execute(
+                    POAP_CONTRACT,
+                    &POAPExecuteMsg::CloseEvent {
+                        event_id: POAP_EVENT_ID.into(),
+                    },
+                    vec![]
+                )
+                .unwrap()
+            )],
+            response.messages
+        );
+    }
+
+    #[test]
+    fn revoke_without_permission_error() {
+        let mut deps = mock_desmos_dependencies();
+        do_instantiate(deps.as_mut());
+        save_event(deps.as_mut().storage, 1, NEW_ADMIN, 10, 20);
+        let msg = ExecuteMsg::Revoke {
+            event_id: 1u64.into(),
+            token_id: "1".into(),
+            reason: "spam".into(),
+        };
+        assert_eq!(
+            execute(deps.as_mut(), mock_env(), mock_info(RECEIVER, &vec![]), msg).unwrap_err(),
+            ContractError::NotEventCreator {
+                caller: Addr::unchecked(RECEIVER),
+                event_id: 1
+            }
+        )
+    }
+
+    #[test]
+    fn revoke_from_event_creator_properly() {
+        let mut deps = mock_desmos_dependencies();
+        do_instantiate(deps.as_mut());
+        save_event(deps.as_mut().storage, 1, NEW_ADMIN, 10, 20);
+        let msg = ExecuteMsg::Revoke {
+            event_id: 1u64.into(),
+            token_id: "1".into(),
+            reason: "spam".into(),
+        };
+        let response = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info(NEW_ADMIN, &vec![]),
+            msg,
+        )
+        .unwrap();
+        assert_eq!(
+            vec![SubMsg::new(
+                wasm_execute(
+                    POAP_CONTRACT,
+                    &POAPExecuteMsg::Revoke {
+                        token_id: "1".into(),
+                        reason: "spam".into(),
+                    },
+                    vec![]
+                )
+                .unwrap()
+            )],
+            response.messages
+        );
+    }
+
+    #[test]
+    fn update_event_info_without_permission_error() {
+        let mut deps = mock_desmos_dependencies();
+        do_instantiate(deps.as_mut());
+        save_event(deps.as_mut().storage, 1, CREATOR, 10, 20);
+        let msg = ExecuteMsg::UpdateEventInfo {
+            event_id: 1u64.into(),
+            start_time: Timestamp::from_seconds(30),
+            end_time: Timestamp::from_seconds(40),
+        };
+        assert_eq!(
+            execute(
+                deps.as_mut(),
+                mock_env(),
+                mock_info(NEW_ADMIN, &vec![]),
+                msg
+            )
+            .unwrap_err(),
+            ContractError::NotEventCreator {
+                caller: Addr::unchecked(NEW_ADMIN),
+                event_id: 1,
+            }
+        )
+    }
+
+    #[test]
+    fn update_event_info_properly() {
+        let mut deps = mock_desmos_dependencies();
+        do_instantiate(deps.as_mut());
+        save_event(deps.as_mut().storage, 1, CREATOR, 10, 20);
+        let msg = ExecuteMsg::UpdateEventInfo {
+            event_id: 1u64.into(),
+            start_time: Timestamp::from_seconds(30),
+            end_time: Timestamp::from_seconds(40),
+        };
+        let response =
+            execute(deps.as_mut(), mock_env(), mock_info(CREATOR, &vec![]), msg).unwrap();
+        assert_eq!(
+            vec![SubMsg::new(
+                wasm_execute(
+                    POAP_CONTRACT,
+                    &POAPExecuteMsg::UpdateEventInfo {
+                        event_id: POAP_EVENT_ID.into(),
+                        start_time: Timestamp::from_seconds(30),
+                        end_time: Timestamp::from_seconds(40),
+                    },
+                    vec![],
+                )
+                .unwrap()
+            )],
+            response.messages
+        );
+
+        // the registry must follow the new time frame
+        let event = load_event(&deps.storage, 1).unwrap();
+        assert_eq!(Timestamp::from_seconds(30), event.start_time);
+        assert_eq!(Timestamp::from_seconds(40), event.end_time);
+    }
+
+    #[test]
+    fn update_event_info_from_event_creator_properly() {
+        let mut deps = mock_desmos_dependencies();
+        do_instantiate(deps.as_mut());
+        // The event is created by a user that is not the contract's admin
+        save_event(deps.as_mut().storage, 1, RECEIVER, 10, 20);
+        let msg = ExecuteMsg::UpdateEventInfo {
+            event_id: 1u64.into(),
+            start_time: Timestamp::from_seconds(30),
+            end_time: Timestamp::from_seconds(40),
+        };
+        execute(deps.as_mut(), mock_env(), mock_info(RECEIVER, &vec![]), msg).unwrap();
+        let event = load_event(&deps.storage, 1).unwrap();
+        assert_eq!(Timestamp::from_seconds(30), event.start_time);
+    }
+
+    #[test]
+    fn update_minter_with_invalid_address_error() {
+        let mut deps = mock_desmos_dependencies();
+        do_instantiate(deps.as_mut());
+        save_event(deps.as_mut().storage, 1, CREATOR, 10, 20);
+        let msg = ExecuteMsg::UpdateMinter {
+            event_id: 1u64.into(),
+            new_minter: "a".into(),
+        };
+        assert_eq!(
+            execute(deps.as_mut(), mock_env(), mock_info(CREATOR, &vec![]), msg).unwrap_err(),
+            ContractError::Std(StdError::generic_err(
+                "Invalid input: human address too short"
+            ))
+        )
+    }
+
+    #[test]
+    fn update_minter_properly() {
+        let mut deps = mock_desmos_dependencies();
+        do_instantiate(deps.as_mut());
+        save_event(deps.as_mut().storage, 1, CREATOR, 10, 20);
+        let msg = ExecuteMsg::UpdateMinter {
+            event_id: 1u64.into(),
+            new_minter: NEW_ADMIN.into(),
+        };
+        let response =
+            execute(deps.as_mut(), mock_env(), mock_info(CREATOR, &vec![]), msg).unwrap();
+        assert_eq!(
+            vec![SubMsg::new(
+                wasm_execute(
+                    POAP_CONTRACT,
+                    &POAPExecuteMsg::UpdateMinter {
+                        new_minter: NEW_ADMIN.into(),
+                    },
+                    vec![],
+                )
+                .unwrap()
+            )],
+            response.messages
+        );
+    }
+
+    #[test]
+    fn claim_with_already_claimed_dtag_error() {
+        let mut deps = mock_desmos_dependencies();
+        do_instantiate(deps.as_mut());
+        save_event(deps.as_mut().storage, 1, CREATOR, 10, 20);
+        let msg = ExecuteMsg::Claim {
+            event_id: 1u64.into(),
+        };
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info(CREATOR, &vec![]),
+            msg.clone(),
+        )
+        .unwrap();
+        let dtag = query_dtag(deps.as_ref(), &Addr::unchecked(CREATOR)).unwrap();
+        assert_eq!(
+            execute(deps.as_mut(), mock_env(), mock_info(CREATOR, &vec![]), msg).unwrap_err(),
+            ContractError::DTagAlreadyClaimed { dtag, event_id: 1 }
+        )
+    }
+
+    #[test]
+    fn claim_during_cooldown_error() {
+        let mut deps = mock_desmos_dependencies();
+        do_instantiate(deps.as_mut());
+        save_event(deps.as_mut().storage, 1, CREATOR, 10, 20);
+        save_event(deps.as_mut().storage, 2, CREATOR, 10, 20);
+        CLAIM_LIMITS
+            .save(
+                deps.as_mut().storage,
+                &ClaimLimits {
+                    one_claim_per_dtag: false,
+                    cooldown_seconds: Some(60),
+                    max_claims_per_block: None,
+                },
+            )
+            .unwrap();
+        let env = mock_env();
+        execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info(CREATOR, &vec![]),
+            ExecuteMsg::Claim {
+                event_id: 1u64.into(),
+            },
+        )
+        .unwrap();
+        assert_eq!(
+            execute(
+                deps.as_mut(),
+                env.clone(),
+                mock_info(CREATOR, &vec![]),
+                ExecuteMsg::Claim {
+                    event_id: 2u64.into(),
+                },
+            )
+            .unwrap_err(),
+            ContractError::ClaimCooldown {
+                next_claim_time: env.block.time.plus_seconds(60)
+            }
+        );
+
+        // the user can claim again once the cooldown is expired
+        let mut env = env;
+        env.block.time = env.block.time.plus_seconds(60);
+        execute(
+            deps.as_mut(),
+            env,
+            mock_info(CREATOR, &vec![]),
+            ExecuteMsg::Claim {
+                event_id: 2u64.into(),
+            },
+        )
+        .unwrap();
+    }
+
+    #[test]
+    fn claim_over_block_limit_error() {
+        let mut deps = mock_desmos_dependencies();
+        do_instantiate(deps.as_mut());
+        save_event(deps.as_mut().storage, 1, CREATOR, 10, 20);
+        CLAIM_LIMITS
+            .save(
+                deps.as_mut().storage,
+                &ClaimLimits {
+                    one_claim_per_dtag: false,
+                    cooldown_seconds: None,
+                    max_claims_per_block: Some(1),
+                },
+            )
+            .unwrap();
+        let msg = ExecuteMsg::Claim {
+            event_id: 1u64.into(),
+        };
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info(CREATOR, &vec![]),
+            msg.clone(),
+        )
+        .unwrap();
+        assert_eq!(
+            execute(
+                deps.as_mut(),
+                mock_env(),
+                mock_info(NEW_ADMIN, &vec![]),
+                msg.clone()
+            )
+            .unwrap_err(),
+            ContractError::MaxClaimsPerBlockReached {
+                max_claims_per_block: 1
+            }
+        );
+
+        // the counter is reset in the next block
+        let mut env = mock_env();
+        env.block.height += 1;
+        execute(deps.as_mut(), env, mock_info(NEW_ADMIN, &vec![]), msg).unwrap();
+    }
+
+    #[test]
+    fn update_claim_limits_without_permission_error() {
+        let mut deps = mock_desmos_dependencies();
+        do_instantiate(deps.as_mut());
+        let msg = ExecuteMsg::UpdateClaimLimits {
+            claim_limits: ClaimLimits::default(),
+        };
+        assert_eq!(
+            execute(
+                deps.as_mut(),
+                mock_env(),
+                mock_info(NEW_ADMIN, &vec![]),
+                msg
+            )
+            .unwrap_err(),
+            ContractError::NotAdmin {
+                caller: Addr::unchecked(NEW_ADMIN)
+            }
+        )
+    }
+
+    #[test]
+    fn update_claim_limits_properly() {
+        let mut deps = mock_desmos_dependencies();
+        do_instantiate(deps.as_mut());
+        let claim_limits = ClaimLimits {
+            one_claim_per_dtag: false,
+            cooldown_seconds: Some(60),
+            max_claims_per_block: Some(5),
+        };
+        let msg = ExecuteMsg::UpdateClaimLimits {
+            claim_limits: claim_limits.clone(),
+        };
+        execute(deps.as_mut(), mock_env(), mock_info(CREATOR, &vec![]), msg).unwrap();
+        assert_eq!(
+            claim_limits,
+            query_config(deps.as_ref()).unwrap().claim_limits
+        );
+    }
+
+    #[test]
+    fn query_claim_status_properly() {
+        let mut deps = mock_desmos_dependencies();
+        do_instantiate(deps.as_mut());
+        save_event(deps.as_mut().storage, 1, CREATOR, 10, 20);
+        CLAIM_LIMITS
+            .save(
+                deps.as_mut().storage,
+                &ClaimLimits {
+                    one_claim_per_dtag: true,
+                    cooldown_seconds: Some(60),
+                    max_claims_per_block: None,
+                },
+            )
+            .unwrap();
+        let env = mock_env();
+        let dtag = query_dtag(deps.as_ref(), &Addr::unchecked(CREATOR));
+
+        let status = query_claim_status(deps.as_ref(), env.clone(), 1, CREATOR.into()).unwrap();
+        assert_eq!(
+            QueryClaimStatusResponse {
+                user: Addr::unchecked(CREATOR),
+                dtag: dtag.clone(),
+                claimed_by: None,
+                last_claim_time: None,
+                next_claim_time: None,
+            },
+            status
+        );
+
+        execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info(CREATOR, &vec![]),
+            ExecuteMsg::Claim {
+                event_id: 1u64.into(),
+            },
+        )
+        .unwrap();
+        let status = query_claim_status(deps.as_ref(), env.clone(), 1, CREATOR.into()).unwrap();
+        assert_eq!(
+            QueryClaimStatusResponse {
+                user: Addr::unchecked(CREATOR),
+                dtag,
+                claimed_by: Some(Addr::unchecked(CREATOR)),
+                last_claim_time: Some(env.block.time),
+                next_claim_time: Some(env.block.time.plus_seconds(60)),
+            },
+            status
+        );
+    }
+
+    #[test]
+    fn query_overview_with_nonexistent_event_error() {
+        let mut deps = mock_desmos_dependencies();
+        do_instantiate(deps.as_mut());
+        assert!(query_overview(deps.as_ref(), 1).is_err());
+    }
 }
@@ -1,4 +1,4 @@
-use cosmwasm_std::{Addr, StdError};
+use cosmwasm_std::{Addr, StdError, Timestamp};
 use cw_utils::ParseReplyError;
 use thiserror::Error;
 
@@ -19,9 +19,36 @@ pub enum ContractError {
     #[error("Caller is not admin: {caller}")]
     NotAdmin { caller: Addr },
 
+    #[error("Caller is not the creator of event {event_id} nor admin: {caller}")]
+    NotEventCreator { caller: Addr, event_id: u64 },
+
     #[error("{0}")]
     ParseReplyError(#[from] ParseReplyError),
 
     #[error("No eligibility error")]
     NoEligibilityError {},
+
+    #[error("Event {event_id} not found")]
+    EventNotFound { event_id: u64 },
+
+    #[error("Mint of event {event_id} is disabled")]
+    MintDisabled { event_id: u64 },
+
+    #[error("Invalid eligibility rule: {rule}")]
+    InvalidEligibilityRule { rule: String },
+
+    #[error("Invalid claim limits")]
+    InvalidClaimLimits {},
+
+    #[error("User {user} doesn't have a Desmos profile")]
+    ProfileNotFound { user: Addr },
+
+    #[error("DTag {dtag} already claimed the POAP of event {event_id}")]
+    DTagAlreadyClaimed { dtag: String, event_id: u64 },
+
+    #[error("Claim cooldown not expired, next claim allowed at {next_claim_time}")]
+    ClaimCooldown { next_claim_time: Timestamp },
+
+    #[error("Max claims per block reached: {max_claims_per_block}")]
+    MaxClaimsPerBlockReached { max_claims_per_block: u32 },
 }
@@ -1,10 +1,15 @@
 #[cfg(test)]
 mod tests {
-    use crate::msg::{ExecuteMsg, InstantiateMsg, QueryConfigResponse, QueryMsg};
+    use crate::msg::{
+        ExecuteMsg, InstantiateMsg, QueryActiveEventsResponse, QueryConfigResponse,
+        QueryEventResponse, QueryEventsResponse, QueryMsg, QueryOverviewResponse,
+    };
     use crate::test_utils::*;
-    use cosmwasm_std::{wasm_execute, Addr, Timestamp};
+    use cosmwasm_std::{wasm_execute, Addr, Coin, Timestamp};
+    use cw721::{AllNftInfoResponse, TokensResponse};
     use cw721_base::InstantiateMsg as Cw721InstantiateMsg;
-    use cw_multi_test::{Contract, ContractWrapper, Executor};
+    use cw721_poap::Metadata;
+    use cw_multi_test::{AppResponse, Contract, ContractWrapper, Executor};
     use desmos_bindings::{
         mocks::mock_apps::{mock_desmos_app, mock_failing_desmos_app, DesmosApp, DesmosModule},
         msg::DesmosMsg,
@@ -13,10 +18,13 @@ mod tests {
     use poap::msg::{
         EventInfo, InstantiateMsg as POAPInstantiateMsg,
         QueryConfigResponse as POAPQueryConfigResponse,
+        QueryEventInfoResponse as POAPQueryEventInfoResponse,
         QueryMintedAmountResponse as POAPQueryMintedAmountResponse, QueryMsg as POAPQueryMsg,
+        Status as POAPStatus,
     };
 
     const ADMIN: &str = "admin";
+    const CREATOR: &str = "creator";
     const RECIPIENT: &str = "recipient";
 
     fn contract_poap_manager() -> Box<dyn Contract<DesmosMsg, DesmosQuery>> {
@@ -36,31 +44,59 @@ mod tests {
         (cw721_code_id, poap_code_id, poap_manager_code_id)
     }
 
-    fn get_valid_init_msg(cw721_code_id: u64, poap_code_id: u64) -> InstantiateMsg {
+    fn get_valid_init_msg(poap_code_id: u64) -> InstantiateMsg {
         InstantiateMsg {
             admin: ADMIN.into(),
             poap_code_id: poap_code_id.into(),
-            poap_instantiate_msg: POAPInstantiateMsg {
-                admin: ADMIN.into(),
+            eligibility_rules: None,
+            claim_limits: None,
+        }
+    }
+
+    fn get_valid_poap_instantiate_msg(cw721_code_id: u64) -> POAPInstantiateMsg {
+        POAPInstantiateMsg {
+            admin: ADMIN.into(),
+            minter: "".into(),
+            attestor_pubkey: None,
+            cw721_code_id: cw721_code_id.into(),
+            cw721_instantiate_msg: Cw721InstantiateMsg {
                 minter: "".into(),
-                cw721_code_id: cw721_code_id.into(),
-                cw721_instantiate_msg: Cw721InstantiateMsg {
-                    minter: "".into(),
-                    name: "test".into(),
-                    symbol: "test".into(),
-                },
-                event_info: EventInfo {
-                    creator: "creator".to_string(),
-                    start_time: Timestamp::from_seconds(10),
-                    end_time: Timestamp::from_seconds(20),
-                    per_address_limit: 2,
-                    poap_uri: "ipfs://popap-uri".to_string(),
-                },
+                name: "test".into(),
+                symbol: "test".into(),
+            },
+            soulbound: false,
+            event_info: EventInfo {
+                creator: CREATOR.to_string(),
+                start_time: Timestamp::from_seconds(10),
+                end_time: Timestamp::from_seconds(20),
+                per_address_limit: 2,
+                poap_uri: "ipfs://popap-uri".to_string(),
+                merkle_root: None,
+                max_supply: None,
+                mint_price: vec![],
+                tier_uris: vec![],
+                phases: vec![],
             },
         }
     }
 
-    fn proper_instantiate<M: DesmosModule>(app: &mut DesmosApp<M>) -> (Addr, (u64, u64, u64)) {
+    fn create_event<M: DesmosModule>(
+        app: &mut DesmosApp<M>,
+        manager_addr: &Addr,
+        poap_instantiate_msg: POAPInstantiateMsg,
+    ) -> Result<AppResponse, String> {
+        app.execute_contract(
+            Addr::unchecked(CREATOR),
+            manager_addr.clone(),
+            &ExecuteMsg::CreateEvent {
+                poap_instantiate_msg,
+            },
+            &[],
+        )
+        .map_err(|err| err.to_string())
+    }
+
+    fn instantiate_manager<M: DesmosModule>(app: &mut DesmosApp<M>) -> (Addr, (u64, u64, u64)) {
         app.update_block(|block| {
             // init the time to before the event
             block.time = Timestamp::from_seconds(0);
@@ -70,75 +106,115 @@ mod tests {
             .instantiate_contract(
                 poap_manager_code_id,
                 Addr::unchecked(ADMIN),
-                &get_valid_init_msg(cw721_code_id, poap_code_id),
+                &get_valid_init_msg(poap_code_id),
                 &[],
                 "poap_manager_contract",
                 None,
             )
             .unwrap();
+        (
+            poap_manager_contract_addr,
+            (cw721_code_id, poap_code_id, poap_manager_code_id),
+        )
+    }
+
+    fn proper_instantiate<M: DesmosModule>(app: &mut DesmosApp<M>) -> (Addr, (u64, u64, u64)) {
+        let (manager_addr, code_ids) = instantiate_manager(app);
+        create_event(
+            app,
+            &manager_addr,
+            get_valid_poap_instantiate_msg(code_ids.0),
+        )
+        .unwrap();
         app.update_block(|block| {
             // update the time to start time of event
             block.time = Timestamp::from_seconds(10);
         });
-        (
-            poap_manager_contract_addr,
-            (cw721_code_id, poap_code_id, poap_manager_code_id),
+        (manager_addr, code_ids)
+    }
+
+    fn enable_mint<M: DesmosModule>(app: &mut DesmosApp<M>, manager_addr: &Addr) {
+        app.execute_contract(
+            Addr::unchecked(CREATOR),
+            manager_addr.clone(),
+            &ExecuteMsg::EnableMint {
+                event_id: 1u64.into(),
+            },
+            &[],
         )
+        .unwrap();
+    }
+
+    fn query_poap_address<M: DesmosModule>(app: &DesmosApp<M>, manager_addr: &Addr) -> Addr {
+        let event: QueryEventResponse = app
+            .wrap()
+            .query_wasm_smart(
+                manager_addr,
+                &QueryMsg::Event {
+                    event_id: 1u64.into(),
+                },
+            )
+            .unwrap();
+        event.poap_contract_address
     }
 
     #[test]
-    fn instantiate_with_invalid_poap_code_id_error() {
+    fn create_event_with_invalid_poap_code_id_error() {
         let mut app = mock_desmos_app();
-        let (cw721_code_id, poap_code_id, poap_manager_code_id) = store_contracts(&mut app);
-        let mut init_msg = get_valid_init_msg(cw721_code_id, poap_code_id);
-        // change code poap_code_id to the invalid one
-        init_msg.poap_code_id = cw721_code_id.into();
-        let init_result = app.instantiate_contract(
-            poap_manager_code_id,
-            Addr::unchecked(ADMIN),
-            &init_msg,
-            &[],
-            "poap_manager_contract",
-            None,
+        let (cw721_code_id, _, poap_manager_code_id) = store_contracts(&mut app);
+        // use the cw721 code id as the poap one
+        let manager_addr = app
+            .instantiate_contract(
+                poap_manager_code_id,
+                Addr::unchecked(ADMIN),
+                &get_valid_init_msg(cw721_code_id),
+                &[],
+                "poap_manager_contract",
+                None,
+            )
+            .unwrap();
+        let result = create_event(
+            &mut app,
+            &manager_addr,
+            get_valid_poap_instantiate_msg(cw721_code_id),
         );
-        assert!(init_result.is_err());
+        assert!(result.is_err());
     }
 
     #[test]
-    fn instantiate_with_invalid_cw721_code_id_error() {
+    fn create_event_with_invalid_cw721_code_id_error() {
         let mut app = mock_desmos_app();
-        let (cw721_code_id, poap_code_id, poap_manager_code_id) = store_contracts(&mut app);
-        let mut init_msg = get_valid_init_msg(cw721_code_id, poap_code_id);
-        // change cw721_code_id to the invalid one
-        init_msg.poap_instantiate_msg.cw721_code_id = poap_code_id.into();
-        let init_result = app.instantiate_contract(
-            poap_manager_code_id,
-            Addr::unchecked(ADMIN),
-            &init_msg,
-            &[],
-            "poap_manager_contract",
-            None,
+        let (manager_addr, (_, poap_code_id, _)) = instantiate_manager(&mut app);
+        // use the poap code id as the cw721 one
+        let result = create_event(
+            &mut app,
+            &manager_addr,
+            get_valid_poap_instantiate_msg(poap_code_id),
         );
-        assert!(init_result.is_err());
+        assert!(result.is_err());
     }
 
     #[test]
-    fn instantiate_with_failing_poap_contract_error() {
+    fn create_event_with_failing_poap_contract_error() {
         let mut app = mock_desmos_app();
         let (cw721_code_id, _, poap_manager_code_id) = store_contracts(&mut app);
         let failing_poap_code_id = app.store_code(POAPTestContract::failing_contract());
-        let mut init_msg = get_valid_init_msg(cw721_code_id, failing_poap_code_id);
-        // change id to the failing one
-        init_msg.poap_code_id = failing_poap_code_id.into();
-        let init_result = app.instantiate_contract(
-            poap_manager_code_id,
-            Addr::unchecked(ADMIN),
-            &init_msg,
-            &[],
-            "poap_manager_contract",
-            None,
+        let manager_addr = app
+            .instantiate_contract(
+                poap_manager_code_id,
+                Addr::unchecked(ADMIN),
+                &get_valid_init_msg(failing_poap_code_id),
+                &[],
+                "poap_manager_contract",
+                None,
+            )
+            .unwrap();
+        let result = create_event(
+            &mut app,
+            &manager_addr,
+            get_valid_poap_instantiate_msg(cw721_code_id),
         );
-        assert!(init_result.is_err());
+        assert!(result.is_err());
     }
 
     #[test]
@@ -154,14 +230,77 @@ mod tests {
         assert_eq!(manager_config.admin, ADMIN);
         assert_eq!(manager_config.poap_code_id, paop_code_id);
 
-        // check if poap minter is manager contract
-        let poap_config: POAPQueryConfigResponse = querier
+        // check the event has been registered
+        let event: QueryEventResponse = querier
             .query_wasm_smart(
-                manager_config.poap_contract_address,
-                &POAPQueryMsg::Config {},
+                &manager_addr,
+                &QueryMsg::Event {
+                    event_id: 1u64.into(),
+                },
             )
             .unwrap();
+        assert_eq!(Addr::unchecked(CREATOR), event.creator);
+        assert_eq!(Timestamp::from_seconds(10), event.start_time);
+        assert_eq!(Timestamp::from_seconds(20), event.end_time);
+
+        // check if poap admin and minter are the manager contract
+        let poap_config: POAPQueryConfigResponse = querier
+            .query_wasm_smart(&event.poap_contract_address, &POAPQueryMsg::Config {})
+            .unwrap();
+        assert_eq!(manager_addr, poap_config.admin);
         assert_eq!(manager_addr, poap_config.minter);
+
+        // check if poap event creator is the manager contract
+        let poap_event: POAPQueryEventInfoResponse = querier
+            .query_wasm_smart(
+                &event.poap_contract_address,
+                &POAPQueryMsg::EventInfo {
+                    event_id: 1u64.into(),
+                },
+            )
+            .unwrap();
+        assert_eq!(manager_addr, poap_event.creator);
+    }
+
+    #[test]
+    fn create_multiple_events_properly() {
+        let mut app = mock_desmos_app();
+        let (manager_addr, (cw721_code_id, _, _)) = proper_instantiate(&mut app);
+        let mut poap_instantiate_msg = get_valid_poap_instantiate_msg(cw721_code_id);
+        poap_instantiate_msg.event_info.start_time = Timestamp::from_seconds(30);
+        poap_instantiate_msg.event_info.end_time = Timestamp::from_seconds(40);
+        create_event(&mut app, &manager_addr, poap_instantiate_msg).unwrap();
+
+        let querier = app.wrap();
+        let events: QueryEventsResponse = querier
+            .query_wasm_smart(
+                &manager_addr,
+                &QueryMsg::Events {
+                    creator: Some(CREATOR.into()),
+                    start_after: None,
+                    limit: None,
+                },
+            )
+            .unwrap();
+        assert_eq!(2, events.events.len());
+        // each event has its own POAP contract
+        assert_ne!(
+            events.events[0].poap_contract_address,
+            events.events[1].poap_contract_address
+        );
+
+        // only the first event is in progress
+        let active_events: QueryActiveEventsResponse = querier
+            .query_wasm_smart(
+                &manager_addr,
+                &QueryMsg::ActiveEvents {
+                    start_after: None,
+                    limit: None,
+                },
+            )
+            .unwrap();
+        assert_eq!(1, active_events.events.len());
+        assert_eq!(events.events[0], active_events.events[0]);
     }
 
     #[test]
@@ -170,54 +309,179 @@ mod tests {
         let (manager_addr, _) = proper_instantiate(&mut app);
         let result = app.execute(
             Addr::unchecked(ADMIN),
-            wasm_execute(&manager_addr, &ExecuteMsg::Claim {}, vec![])
-                .unwrap()
-                .into(),
+            wasm_execute(
+                &manager_addr,
+                &ExecuteMsg::Claim {
+                    event_id: 1u64.into(),
+                },
+                vec![],
+            )
+            .unwrap()
+            .into(),
         );
         assert!(result.is_err());
 
         // check the state of poap contract
-        let querier = app.wrap();
-        let manager_config: QueryConfigResponse = querier
-            .query_wasm_smart(&manager_addr, &QueryMsg::Config {})
-            .unwrap();
-        let minted_amount_response: POAPQueryMintedAmountResponse = querier
+        let poap_contract_address = query_poap_address(&app, &manager_addr);
+        let minted_amount_response: POAPQueryMintedAmountResponse = app
+            .wrap()
             .query_wasm_smart(
-                manager_config.poap_contract_address,
-                &POAPQueryMsg::MintedAmount { user: ADMIN.into() },
+                poap_contract_address,
+                &POAPQueryMsg::MintedAmount {
+                    event_id: 1u64.into(),
+                    user: ADMIN.into(),
+                },
             )
             .unwrap();
         assert_eq!(minted_amount_response.user, ADMIN);
         assert_eq!(minted_amount_response.amount, 0)
     }
 
+    #[test]
+    fn user_claim_with_mint_disabled_error() {
+        let mut app = mock_desmos_app();
+        let (manager_addr, _) = proper_instantiate(&mut app);
+        // the mint is disabled when the event is created
+        let result = app.execute_contract(
+            Addr::unchecked(ADMIN),
+            manager_addr,
+            &ExecuteMsg::Claim {
+                event_id: 1u64.into(),
+            },
+            &[],
+        );
+        assert!(result.is_err());
+    }
+
     #[test]
     fn user_claim_poap_properly() {
         let mut app = mock_desmos_app();
         let (manager_addr, _) = proper_instantiate(&mut app);
+        enable_mint(&mut app, &manager_addr);
         app.execute(
             Addr::unchecked(ADMIN),
-            wasm_execute(&manager_addr, &ExecuteMsg::Claim {}, vec![])
-                .unwrap()
-                .into(),
+            wasm_execute(
+                &manager_addr,
+                &ExecuteMsg::Claim {
+                    event_id: 1u64.into(),
+                },
+                vec![],
+            )
+            .unwrap()
+            .into(),
         )
         .unwrap();
 
         // check the state of poap contract
-        let querier = app.wrap();
-        let manager_config: QueryConfigResponse = querier
-            .query_wasm_smart(&manager_addr, &QueryMsg::Config {})
-            .unwrap();
-        let minted_amount_response: POAPQueryMintedAmountResponse = querier
+        let poap_contract_address = query_poap_address(&app, &manager_addr);
+        let minted_amount_response: POAPQueryMintedAmountResponse = app
+            .wrap()
             .query_wasm_smart(
-                manager_config.poap_contract_address,
-                &POAPQueryMsg::MintedAmount { user: ADMIN.into() },
+                poap_contract_address,
+                &POAPQueryMsg::MintedAmount {
+                    event_id: 1u64.into(),
+                    user: ADMIN.into(),
+                },
             )
             .unwrap();
         assert_eq!(minted_amount_response.user, ADMIN);
         assert_eq!(minted_amount_response.amount, 1)
     }
 
+    #[test]
+    fn proxy_enable_and_disable_mint_properly() {
+        let mut app = mock_desmos_app();
+        let (manager_addr, _) = proper_instantiate(&mut app);
+        let poap_contract_address = query_poap_address(&app, &manager_addr);
+
+        for (msg, expected) in [
+            (
+                ExecuteMsg::EnableMint {
+                    event_id: 1u64.into(),
+                },
+                true,
+            ),
+            (
+                ExecuteMsg::DisableMint {
+                    event_id: 1u64.into(),
+                },
+                false,
+            ),
+        ] {
+            app.execute_contract(Addr::unchecked(ADMIN), manager_addr.clone(), &msg, &[])
+                .unwrap();
+            let overview: QueryOverviewResponse = app
+                .wrap()
+                .query_wasm_smart(
+                    &manager_addr,
+                    &QueryMsg::Overview {
+                        event_id: 1u64.into(),
+                    },
+                )
+                .unwrap();
+            assert_eq!(expected, overview.mint_enabled);
+
+            // the mint status is forwarded to the POAP contract
+            let poap_event_info: POAPQueryEventInfoResponse = app
+                .wrap()
+                .query_wasm_smart(
+                    &poap_contract_address,
+                    &POAPQueryMsg::EventInfo {
+                        event_id: 1u64.into(),
+                    },
+                )
+                .unwrap();
+            assert_eq!(expected, poap_event_info.mint_enabled);
+        }
+    }
+
+    #[test]
+    fn proxy_update_event_info_properly() {
+        let mut app = mock_desmos_app();
+        let (manager_addr, (cw721_code_id, _, _)) = instantiate_manager(&mut app);
+        create_event(
+            &mut app,
+            &manager_addr,
+            get_valid_poap_instantiate_msg(cw721_code_id),
+        )
+        .unwrap();
+        app.execute_contract(
+            Addr::unchecked(ADMIN),
+            manager_addr.clone(),
+            &ExecuteMsg::UpdateEventInfo {
+                event_id: 1u64.into(),
+                start_time: Timestamp::from_seconds(30),
+                end_time: Timestamp::from_seconds(40),
+            },
+            &[],
+        )
+        .unwrap();
+
+        // check both the registry and the poap contract have been updated
+        let event: QueryEventResponse = app
+            .wrap()
+            .query_wasm_smart(
+                &manager_addr,
+                &QueryMsg::Event {
+                    event_id: 1u64.into(),
+                },
+            )
+            .unwrap();
+        assert_eq!(Timestamp::from_seconds(30), event.start_time);
+        assert_eq!(Timestamp::from_seconds(40), event.end_time);
+        let poap_event: POAPQueryEventInfoResponse = app
+            .wrap()
+            .query_wasm_smart(
+                &event.poap_contract_address,
+                &POAPQueryMsg::EventInfo {
+                    event_id: 1u64.into(),
+                },
+            )
+            .unwrap();
+        assert_eq!(Timestamp::from_seconds(30), poap_event.start_time);
+        assert_eq!(Timestamp::from_seconds(40), poap_event.end_time);
+    }
+
     #[test]
     fn mint_poap_to_recipient_properly() {
         let mut app = mock_desmos_app();
@@ -227,6 +491,7 @@ mod tests {
             wasm_execute(
                 &manager_addr,
                 &ExecuteMsg::MintTo {
+                    event_id: 1u64.into(),
                     recipient: RECIPIENT.into(),
                 },
                 vec![],
@@ -237,14 +502,13 @@ mod tests {
         .unwrap();
 
         // check the state of poap contract
-        let querier = app.wrap();
-        let manager_config: QueryConfigResponse = querier
-            .query_wasm_smart(&manager_addr, &QueryMsg::Config {})
-            .unwrap();
-        let minted_amount_response: POAPQueryMintedAmountResponse = querier
+        let poap_contract_address = query_poap_address(&app, &manager_addr);
+        let minted_amount_response: POAPQueryMintedAmountResponse = app
+            .wrap()
             .query_wasm_smart(
-                manager_config.poap_contract_address,
+                poap_contract_address,
                 &POAPQueryMsg::MintedAmount {
+                    event_id: 1u64.into(),
                     user: RECIPIENT.into(),
                 },
             )
@@ -252,4 +516,184 @@ mod tests {
         assert_eq!(minted_amount_response.user, RECIPIENT);
         assert_eq!(minted_amount_response.amount, 1)
     }
+
+    #[test]
+    fn query_poap_through_manager_properly() {
+        let mut app = mock_desmos_app();
+        let (manager_addr, _) = proper_instantiate(&mut app);
+        enable_mint(&mut app, &manager_addr);
+        app.execute_contract(
+            Addr::unchecked(ADMIN),
+            manager_addr.clone(),
+            &ExecuteMsg::Claim {
+                event_id: 1u64.into(),
+            },
+            &[],
+        )
+        .unwrap();
+        let querier = app.wrap();
+
+        let event_info: POAPQueryEventInfoResponse = querier
+            .query_wasm_smart(
+                &manager_addr,
+                &QueryMsg::EventInfo {
+                    event_id: 1u64.into(),
+                },
+            )
+            .unwrap();
+        assert_eq!(manager_addr, event_info.creator);
+        assert_eq!(Timestamp::from_seconds(10), event_info.start_time);
+
+        let minted_amount: POAPQueryMintedAmountResponse = querier
+            .query_wasm_smart(
+                &manager_addr,
+                &QueryMsg::MintedAmount {
+                    event_id: 1u64.into(),
+                    user: ADMIN.into(),
+                },
+            )
+            .unwrap();
+        assert_eq!(1, minted_amount.amount);
+
+        let tokens: TokensResponse = querier
+            .query_wasm_smart(
+                &manager_addr,
+                &QueryMsg::Tokens {
+                    event_id: 1u64.into(),
+                    owner: ADMIN.into(),
+                    start_after: None,
+                    limit: None,
+                },
+            )
+            .unwrap();
+        assert_eq!(1, tokens.tokens.len());
+
+        let nft_info: AllNftInfoResponse<Metadata> = querier
+            .query_wasm_smart(
+                &manager_addr,
+                &QueryMsg::AllNftInfo {
+                    event_id: 1u64.into(),
+                    token_id: tokens.tokens[0].clone(),
+                    include_expired: None,
+                },
+            )
+            .unwrap();
+        assert_eq!(ADMIN, nft_info.access.owner);
+    }
+
+    #[test]
+    fn claim_funds_through_manager_properly() {
+        let mut app = mock_desmos_app();
+        let (manager_addr, (cw721_code_id, _, _)) = instantiate_manager(&mut app);
+        let mut poap_instantiate_msg = get_valid_poap_instantiate_msg(cw721_code_id);
+        poap_instantiate_msg.event_info.mint_price = vec![Coin::new(100, "udsm")];
+        create_event(&mut app, &manager_addr, poap_instantiate_msg).unwrap();
+        app.update_block(|block| {
+            // update the time to start time of event
+            block.time = Timestamp::from_seconds(10);
+        });
+        enable_mint(&mut app, &manager_addr);
+        app.init_modules(|router, _, storage| {
+            router.bank.init_balance(
+                storage,
+                &Addr::unchecked(ADMIN),
+                vec![Coin::new(100, "udsm")],
+            )
+        })
+        .unwrap();
+
+        // the mint price is paid to the POAP contract while claiming
+        app.execute_contract(
+            Addr::unchecked(ADMIN),
+            manager_addr.clone(),
+            &ExecuteMsg::Claim {
+                event_id: 1u64.into(),
+            },
+            &[Coin::new(100, "udsm")],
+        )
+        .unwrap();
+
+        let claim_funds_msg = ExecuteMsg::ClaimFunds {
+            event_id: 1u64.into(),
+            receiver: RECIPIENT.into(),
+        };
+        // only the event creator or the admin can claim the proceeds
+        let result = app.execute_contract(
+            Addr::unchecked(RECIPIENT),
+            manager_addr.clone(),
+            &claim_funds_msg,
+            &[],
+        );
+        assert!(result.is_err());
+
+        app.execute_contract(
+            Addr::unchecked(CREATOR),
+            manager_addr.clone(),
+            &claim_funds_msg,
+            &[],
+        )
+        .unwrap();
+        assert_eq!(
+            Coin::new(100, "udsm"),
+            app.wrap().query_balance(RECIPIENT, "udsm").unwrap()
+        );
+    }
+
+    #[test]
+    fn close_event_through_manager_properly() {
+        let mut app = mock_desmos_app();
+        let (manager_addr, _) = proper_instantiate(&mut app);
+        app.execute_contract(
+            Addr::unchecked(CREATOR),
+            manager_addr.clone(),
+            &ExecuteMsg::CloseEvent {
+                event_id: 1u64.into(),
+            },
+            &[],
+        )
+        .unwrap();
+
+        let overview: QueryOverviewResponse = app
+            .wrap()
+            .query_wasm_smart(
+                &manager_addr,
+                &QueryMsg::Overview {
+                    event_id: 1u64.into(),
+                },
+            )
+            .unwrap();
+        assert_eq!(POAPStatus::Closed, overview.status);
+    }
+
+    #[test]
+    fn query_overview_properly() {
+        let mut app = mock_desmos_app();
+        let (manager_addr, (_, poap_code_id, _)) = proper_instantiate(&mut app);
+        enable_mint(&mut app, &manager_addr);
+        app.execute_contract(
+            Addr::unchecked(ADMIN),
+            manager_addr.clone(),
+            &ExecuteMsg::Claim {
+                event_id: 1u64.into(),
+            },
+            &[],
+        )
+        .unwrap();
+
+        let overview: QueryOverviewResponse = app
+            .wrap()
+            .query_wasm_smart(
+                &manager_addr,
+                &QueryMsg::Overview {
+                    event_id: 1u64.into(),
+                },
+            )
+            .unwrap();
+        assert_eq!(ADMIN, overview.config.admin);
+        assert_eq!(poap_code_id, overview.config.poap_code_id);
+        assert_eq!(1u64, overview.event_info.event_id.u64());
+        assert!(overview.mint_enabled);
+        assert_eq!(POAPStatus::Ready, overview.status);
+        assert_eq!(1, overview.claimed);
+    }
 }
@@ -1,18 +1,27 @@
 use cosmwasm_schema::{cw_serde, QueryResponses};
-use cosmwasm_std::{Addr, Uint64};
+use cosmwasm_std::{Addr, Timestamp, Uint64};
 
 use crate::error::ContractError;
-use poap::msg::InstantiateMsg as POAPInstantiateMsg;
+use cw721::{AllNftInfoResponse, TokensResponse};
+use cw721_poap::Metadata;
+use poap::msg::{
+    InstantiateMsg as POAPInstantiateMsg, QueryEventInfoResponse as POAPQueryEventInfoResponse,
+    QueryMintedAmountResponse as POAPQueryMintedAmountResponse, Status as POAPStatus,
+};
 
 #[cw_serde]
 #[schemars(rename = "PoapManagerInstantiateMsg", title = "InstantiateMsg")]
 pub struct InstantiateMsg {
     /// Address of who will have the right to administer the contract.
     pub admin: String,
-    /// Id of the POAP contract to be initialized along with this contract.
+    /// Id of the POAP contract to be initialized for each event.
     pub poap_code_id: Uint64,
-    /// Initialization message of the POAP contract.
-    pub poap_instantiate_msg: POAPInstantiateMsg,
+    /// Optional rules that users must satisfy to claim a POAP,
+    /// if not provided only a Desmos profile is required.
+    pub eligibility_rules: Option<Vec<EligibilityRule>>,
+    /// Optional limits applied to the claims, if not provided only one claim per DTag
+    /// is allowed for each event.
+    pub claim_limits: Option<ClaimLimits>,
 }
 
 impl InstantiateMsg {
@@ -20,18 +29,171 @@ impl InstantiateMsg {
         if self.poap_code_id == Uint64::zero() {
             return Err(ContractError::InvalidPOAPCodeID {});
         }
+        if let Some(rules) = &self.eligibility_rules {
+            rules.iter().try_for_each(|rule| rule.validate())?;
+        }
+        if let Some(claim_limits) = &self.claim_limits {
+            claim_limits.validate()?;
+        }
+        Ok(())
+    }
+}
+
+/// Limits applied to the claims performed with [`ExecuteMsg::Claim`].
+#[cw_serde]
+pub struct ClaimLimits {
+    /// If true, a POAP of an event can be claimed only once for each Desmos profile DTag.
+    pub one_claim_per_dtag: bool,
+    /// Optional number of seconds that an address must wait between two claims.
+    pub cooldown_seconds: Option<u64>,
+    /// Optional maximum number of claims that can be performed in a single block.
+    pub max_claims_per_block: Option<u32>,
+}
+
+impl Default for ClaimLimits {
+    fn default() -> Self {
+        ClaimLimits {
+            one_claim_per_dtag: true,
+            cooldown_seconds: None,
+            max_claims_per_block: None,
+        }
+    }
+}
+
+impl ClaimLimits {
+    /// Checks that the data inside the limits are coherent.
+    pub fn validate(&self) -> Result<(), ContractError> {
+        if self.cooldown_seconds == Some(0) || self.max_claims_per_block == Some(0) {
+            return Err(ContractError::InvalidClaimLimits {});
+        }
+        Ok(())
+    }
+}
+
+/// Max amount of posts of a subspace that are scanned to check the
+/// [`EligibilityRule::MinPosts`] rule.
+pub const MAX_SCANNED_SUBSPACE_POSTS: u32 = 500;
+
+/// Rule that a user must satisfy to claim a POAP with [`ExecuteMsg::Claim`].
+#[cw_serde]
+pub enum EligibilityRule {
+    /// The user must have a Desmos profile.
+    ProfileRequired {},
+    /// The user's profile must have been created at least `min_age_seconds` seconds ago.
+    MinProfileAge { min_age_seconds: u64 },
+    /// The user must have a verified link to the given application (e.g. twitter, github).
+    ApplicationLink { application: String },
+    /// The user must be a member of the subspace, having at least a permission inside it.
+    SubspaceMember { subspace_id: Uint64 },
+    /// The user must have the given permission inside the subspace.
+    SubspacePermission {
+        subspace_id: Uint64,
+        permission: String,
+    },
+    /// The user must have created at least `min_posts` posts inside the subspace.
+    /// Only the first [`MAX_SCANNED_SUBSPACE_POSTS`] posts of the subspace are counted,
+    /// so the rule is not satisfied if the user posts are found after them.
+    MinPosts { subspace_id: Uint64, min_posts: u32 },
+    /// The user must hold a POAP of another event created from this contract.
+    PoapHolder { event_id: Uint64 },
+    /// The user must be one of the given addresses.
+    Allowlist { addresses: Vec<String> },
+}
+
+impl EligibilityRule {
+    /// Checks that the data inside the rule are coherent.
+    /// NOTE: This function don't checks if the addresses are valid.
+    pub fn validate(&self) -> Result<(), ContractError> {
+        let is_valid = match self {
+            EligibilityRule::MinProfileAge { min_age_seconds } => *min_age_seconds > 0,
+            EligibilityRule::ApplicationLink { application } => !application.trim().is_empty(),
+            EligibilityRule::SubspaceMember { subspace_id } => !subspace_id.is_zero(),
+            EligibilityRule::SubspacePermission {
+                subspace_id,
+                permission,
+            } => !subspace_id.is_zero() && !permission.trim().is_empty(),
+            EligibilityRule::MinPosts {
+                subspace_id,
+                min_posts,
+            } => {
+                !subspace_id.is_zero() && *min_posts > 0 && *min_posts <= MAX_SCANNED_SUBSPACE_POSTS
+            }
+            EligibilityRule::PoapHolder { event_id } => !event_id.is_zero(),
+            EligibilityRule::Allowlist { addresses } => !addresses.is_empty(),
+            EligibilityRule::ProfileRequired {} => true,
+        };
+        if !is_valid {
+            return Err(ContractError::InvalidEligibilityRule {
+                rule: format!("{:?}", self),
+            });
+        }
         Ok(())
     }
 }
 
 #[cw_serde]
 pub enum ExecuteMsg {
-    /// Allows users to claim a POAP token.
-    Claim {},
-    /// Allows the contract's admin to mint a POAP for a specific recipient.
-    MintTo { recipient: String },
+    /// Allows any user to create a new event, instantiating a new POAP contract.
+    /// The sender becomes the event's creator and, together with the contract's admin,
+    /// is the only one allowed to manage the event.
+    CreateEvent {
+        poap_instantiate_msg: POAPInstantiateMsg,
+    },
+    /// Allows users to claim a POAP token of an event.
+    Claim { event_id: Uint64 },
+    /// Allows the event's creator or the contract's admin to mint a POAP of an event for a specific recipient.
+    MintTo { event_id: Uint64, recipient: String },
     /// Allows the contract's admin to transfer the admin rights to another user.
     UpdateAdmin { new_admin: String },
+    /// Allows the contract's admin to replace the rules that users must satisfy to claim a POAP.
+    UpdateEligibilityRules { rules: Vec<EligibilityRule> },
+    /// Allows the event's creator or the contract's admin to enable the mint of the POAPs of an event,
+    /// both through [`ExecuteMsg::Claim`] and through the public mint of the event's POAP contract.
+    EnableMint { event_id: Uint64 },
+    /// Allows the event's creator or the contract's admin to disable the mint of the POAPs of an event,
+    /// both through [`ExecuteMsg::Claim`] and through the public mint of the event's POAP contract.
+    DisableMint { event_id: Uint64 },
+    /// Allows the event's creator or the contract's admin to update the start and end time of an event
+    /// if it's not started yet.
+    UpdateEventInfo {
+        event_id: Uint64,
+        start_time: Timestamp,
+        end_time: Timestamp,
+    },
+    /// Allows the event's creator or the contract's admin to transfer the minting rights of an event's POAP
+    /// contract to another user.
+    UpdateMinter {
+        event_id: Uint64,
+        new_minter: String,
+    },
+    /// Allows the contract's admin to update the limits applied to the claims.
+    UpdateClaimLimits { claim_limits: ClaimLimits },
+    /// Allows the event's creator or the contract's admin to send the mint proceeds
+    /// of an event to `receiver`.
+    ClaimFunds { event_id: Uint64, receiver: String },
+    /// Allows the event's creator or the contract's admin to close an event before its end time.
+    CloseEvent { event_id: Uint64 },
+    /// Allows the event's creator or the contract's admin to revoke a POAP of an event,
+    /// burning it and recording the revocation reason.
+    Revoke {
+        event_id: Uint64,
+        token_id: String,
+        reason: String,
+    },
+}
+
+impl ExecuteMsg {
+    /// Checks that the data inside the message are coherent.
+    /// NOTE: This function don't checks if the addresses are valid.
+    pub fn validate(&self) -> Result<(), ContractError> {
+        match self {
+            ExecuteMsg::UpdateEligibilityRules { rules } => {
+                rules.iter().try_for_each(|rule| rule.validate())
+            }
+            ExecuteMsg::UpdateClaimLimits { claim_limits } => claim_limits.validate(),
+            _ => Ok(()),
+        }
+    }
 }
 
 #[cw_serde]
@@ -40,74 +202,245 @@ pub enum QueryMsg {
     /// Returns a ConfigResponse containing the configuration info of the Manager contract
     #[returns(QueryConfigResponse)]
     Config {},
+    /// Returns the info of an event as a [`QueryEventResponse`].
+    #[returns(QueryEventResponse)]
+    Event { event_id: Uint64 },
+    /// Returns the events ordered by id, optionally filtered by creator,
+    /// as a [`QueryEventsResponse`].
+    #[returns(QueryEventsResponse)]
+    Events {
+        creator: Option<String>,
+        start_after: Option<Uint64>,
+        limit: Option<u32>,
+    },
+    /// Returns the events that are in progress ordered by end time as a [`QueryActiveEventsResponse`].
+    /// `start_after` is the id of the event from which to start the iteration (exclusive).
+    /// At most 100 events are scanned for each request, including the ones not started yet.
+    #[returns(QueryActiveEventsResponse)]
+    ActiveEvents {
+        start_after: Option<Uint64>,
+        limit: Option<u32>,
+    },
+    /// Returns the rules that users must satisfy to claim a POAP as a [`QueryEligibilityRulesResponse`].
+    #[returns(QueryEligibilityRulesResponse)]
+    EligibilityRules {},
+    /// Checks if a user satisfies the eligibility rules as a [`QueryCheckEligibilityResponse`].
+    #[returns(QueryCheckEligibilityResponse)]
+    CheckEligibility { user: String },
+    /// Returns the claim status of a user for an event as a [`QueryClaimStatusResponse`].
+    #[returns(QueryClaimStatusResponse)]
+    ClaimStatus { event_id: Uint64, user: String },
+    /// Returns the info of an event from its POAP contract as a [`POAPQueryEventInfoResponse`].
+    #[returns(POAPQueryEventInfoResponse)]
+    EventInfo { event_id: Uint64 },
+    /// Returns the amount of POAPs of an event minted from `user` as a [`POAPQueryMintedAmountResponse`].
+    #[returns(POAPQueryMintedAmountResponse)]
+    MintedAmount { event_id: Uint64, user: String },
+    /// Returns the POAPs of an event owned by the given owner as a [`TokensResponse`].
+    #[returns(TokensResponse)]
+    Tokens {
+        event_id: Uint64,
+        owner: String,
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
+    /// Returns the nft info with approvals of a POAP of an event as a [`AllNftInfoResponse`].
+    #[returns(AllNftInfoResponse<Metadata>)]
+    AllNftInfo {
+        event_id: Uint64,
+        token_id: String,
+        include_expired: Option<bool>,
+    },
+    /// Returns the manager config together with the info, the mint status and the claimed
+    /// amount of an event as a [`QueryOverviewResponse`].
+    #[returns(QueryOverviewResponse)]
+    Overview { event_id: Uint64 },
 }
 
 #[cw_serde]
 pub struct QueryConfigResponse {
     /// Address of the contract administrator.
     pub admin: Addr,
-    /// Id of the POAP contract that this contract has initialized.
+    /// Id of the POAP contract that this contract initializes for each event.
     pub poap_code_id: u64,
-    /// Address of the POAP contract
+    /// Limits applied to the claims.
+    pub claim_limits: ClaimLimits,
+}
+
+/// Response to [`QueryMsg::Event`].
+#[cw_serde]
+pub struct QueryEventResponse {
+    /// Id of the event.
+    pub event_id: Uint64,
+    /// Address of the event creator.
+    pub creator: Addr,
+    /// Address of the POAP contract of the event.
     pub poap_contract_address: Addr,
+    /// Time at which the event begins.
+    pub start_time: Timestamp,
+    /// Time at which the event ends.
+    pub end_time: Timestamp,
+}
+
+/// Response to [`QueryMsg::Events`].
+#[cw_serde]
+pub struct QueryEventsResponse {
+    /// List of the events.
+    pub events: Vec<QueryEventResponse>,
+}
+
+/// Response to [`QueryMsg::ActiveEvents`].
+#[cw_serde]
+pub struct QueryActiveEventsResponse {
+    /// List of the events in progress.
+    pub events: Vec<QueryEventResponse>,
+    /// Id of the last scanned event to use as `start_after` of the next request,
+    /// `None` if there are no more events to scan.
+    pub next_start_after: Option<Uint64>,
+}
+
+/// Response to [`QueryMsg::EligibilityRules`].
+#[cw_serde]
+pub struct QueryEligibilityRulesResponse {
+    /// Rules that users must satisfy to claim a POAP.
+    pub rules: Vec<EligibilityRule>,
+}
+
+/// Response to [`QueryMsg::CheckEligibility`].
+#[cw_serde]
+pub struct QueryCheckEligibilityResponse {
+    /// Tells if the user satisfies all the eligibility rules.
+    pub eligible: bool,
+    /// Rules that the user doesn't satisfy.
+    pub failed_rules: Vec<EligibilityRule>,
+}
+
+/// Response to [`QueryMsg::ClaimStatus`].
+#[cw_serde]
+pub struct QueryClaimStatusResponse {
+    /// Address of the user.
+    pub user: Addr,
+    /// DTag of the user's Desmos profile, if any.
+    pub dtag: Option<String>,
+    /// Address that claimed the event's POAP with the user's DTag, if any.
+    pub claimed_by: Option<Addr>,
+    /// Time of the last claim performed by the user, if any.
+    pub last_claim_time: Option<Timestamp>,
+    /// Time from which the user can claim again, if the cooldown is still running.
+    pub next_claim_time: Option<Timestamp>,
+}
+
+/// Response to [`QueryMsg::Overview`].
+#[cw_serde]
+pub struct QueryOverviewResponse {
+    /// Configuration of the manager contract.
+    pub config: QueryConfigResponse,
+    /// Info of the event stored inside its POAP contract.
+    pub event_info: POAPQueryEventInfoResponse,
+    /// Tells if the users can claim the event's POAPs with [`ExecuteMsg::Claim`].
+    /// When enabled, the public mint of the event's POAP contract is enabled too and
+    /// it doesn't check the eligibility rules of this contract.
+    pub mint_enabled: bool,
+    /// Lifecycle status of the event.
+    pub status: POAPStatus,
+    /// Amount of POAPs of the event claimed so far.
+    pub claimed: u32,
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use cosmwasm_std::Timestamp;
-    use cw721_base::InstantiateMsg as Cw721InstantiateMsg;
-    use poap::msg::EventInfo;
 
     #[test]
     fn instantiate_msg_with_invalid_poap_id_error() {
         let msg = InstantiateMsg {
             admin: "desmos1nwp8gxrnmrsrzjdhvk47vvmthzxjtphgxp5ftc".into(),
             poap_code_id: 0u64.into(),
-            poap_instantiate_msg: POAPInstantiateMsg {
-                admin: "test".into(),
-                minter: "test".into(),
-                cw721_code_id: 2u64.into(),
-                cw721_instantiate_msg: Cw721InstantiateMsg {
-                    minter: "".into(),
-                    name: "test".into(),
-                    symbol: "test".into(),
-                },
-                event_info: EventInfo {
-                    creator: "creator".to_string(),
-                    start_time: Timestamp::from_seconds(10),
-                    end_time: Timestamp::from_seconds(20),
-                    per_address_limit: 2,
-                    poap_uri: "ipfs://popap-uri".to_string(),
-                },
-            },
+            eligibility_rules: None,
+            claim_limits: None,
         };
         let result = msg.validate();
         assert_eq!(result.unwrap_err(), ContractError::InvalidPOAPCodeID {},)
     }
 
+    #[test]
+    fn instantiate_msg_with_invalid_claim_limits_error() {
+        let msg = InstantiateMsg {
+            admin: "desmos1nwp8gxrnmrsrzjdhvk47vvmthzxjtphgxp5ftc".into(),
+            poap_code_id: 1u64.into(),
+            eligibility_rules: None,
+            claim_limits: Some(ClaimLimits {
+                one_claim_per_dtag: true,
+                cooldown_seconds: Some(0),
+                max_claims_per_block: None,
+            }),
+        };
+        let result = msg.validate();
+        assert_eq!(result.unwrap_err(), ContractError::InvalidClaimLimits {})
+    }
+
+    #[test]
+    fn min_posts_rule_over_scanned_posts_error() {
+        let rule = EligibilityRule::MinPosts {
+            subspace_id: 1u64.into(),
+            min_posts: MAX_SCANNED_SUBSPACE_POSTS + 1,
+        };
+        assert_eq!(
+            rule.validate().unwrap_err(),
+            ContractError::InvalidEligibilityRule {
+                rule: format!("{:?}", rule)
+            }
+        )
+    }
+
+    #[test]
+    fn instantiate_msg_with_invalid_eligibility_rule_error() {
+        let msg = InstantiateMsg {
+            admin: "desmos1nwp8gxrnmrsrzjdhvk47vvmthzxjtphgxp5ftc".into(),
+            poap_code_id: 1u64.into(),
+            eligibility_rules: Some(vec![EligibilityRule::MinPosts {
+                subspace_id: 1u64.into(),
+                min_posts: 0,
+            }]),
+            claim_limits: None,
+        };
+        let result = msg.validate();
+        assert_eq!(
+            result.unwrap_err(),
+            ContractError::InvalidEligibilityRule {
+                rule: "MinPosts { subspace_id: Uint64(1), min_posts: 0 }".into()
+            }
+        )
+    }
+
+    #[test]
+    fn update_eligibility_rules_with_empty_allowlist_error() {
+        let msg = ExecuteMsg::UpdateEligibilityRules {
+            rules: vec![
+                EligibilityRule::ProfileRequired {},
+                EligibilityRule::Allowlist { addresses: vec![] },
+            ],
+        };
+        assert_eq!(
+            msg.validate().unwrap_err(),
+            ContractError::InvalidEligibilityRule {
+                rule: "Allowlist { addresses: [] }".into()
+            }
+        )
+    }
+
     #[test]
     fn proper_instantiate_msg_no_error() {
         let msg = InstantiateMsg {
             admin: "desmos1nwp8gxrnmrsrzjdhvk47vvmthzxjtphgxp5ftc".into(),
             poap_code_id: 1u64.into(),
-            poap_instantiate_msg: POAPInstantiateMsg {
-                admin: "test".into(),
-                minter: "test".into(),
-                cw721_code_id: 2u64.into(),
-                cw721_instantiate_msg: Cw721InstantiateMsg {
-                    minter: "".into(),
-                    name: "test".into(),
-                    symbol: "test".into(),
-                },
-                event_info: EventInfo {
-                    creator: "creator".to_string(),
-                    start_time: Timestamp::from_seconds(10),
-                    end_time: Timestamp::from_seconds(20),
-                    per_address_limit: 2,
-                    poap_uri: "ipfs://popap-uri".to_string(),
+            eligibility_rules: Some(vec![
+                EligibilityRule::ProfileRequired {},
+                EligibilityRule::MinProfileAge {
+                    min_age_seconds: 3600,
                 },
-            },
+            ]),
+            claim_limits: None,
         };
         msg.validate().unwrap();
     }
@@ -1,6 +1,7 @@
+use crate::msg::{ClaimLimits, EligibilityRule};
 use cosmwasm_schema::cw_serde;
-use cosmwasm_std::Addr;
-use cw_storage_plus::Item;
+use cosmwasm_std::{Addr, Timestamp};
+use cw_storage_plus::{index_list, IndexedMap, Item, Map, MultiIndex};
 
 #[cw_serde]
 pub struct Config {
@@ -8,6 +9,65 @@ pub struct Config {
     pub poap_code_id: u64,
 }
 
-pub const POAP_CONTRACT_ADDRESS: Item<Addr> = Item::new("poap_contract_address");
+#[cw_serde]
+pub struct BlockClaims {
+    pub height: u64,
+    pub count: u32,
+}
+
+#[cw_serde]
+pub struct PendingEvent {
+    pub creator: Addr,
+    pub start_time: Timestamp,
+    pub end_time: Timestamp,
+}
+
+#[cw_serde]
+pub struct EventRecord {
+    pub creator: Addr,
+    pub poap_contract_address: Addr,
+    pub start_time: Timestamp,
+    pub end_time: Timestamp,
+    /// Tells if the mint of the event's POAPs is enabled, mirroring the POAP contract.
+    pub mint_enabled: bool,
+}
+
+#[index_list(EventRecord)]
+pub struct EventRecordIndexes<'a> {
+    pub creator: MultiIndex<'a, Addr, EventRecord, u64>,
+    pub end_time: MultiIndex<'a, u64, EventRecord, u64>,
+}
 
 pub const CONFIG: Item<Config> = Item::new("config");
+pub const NEXT_EVENT_ID: Item<u64> = Item::new("next_event_id");
+/// Rules that users must satisfy to claim a POAP.
+pub const ELIGIBILITY_RULES: Item<Vec<EligibilityRule>> = Item::new("eligibility_rules");
+/// Limits applied to the claims.
+pub const CLAIM_LIMITS: Item<ClaimLimits> = Item::new("claim_limits");
+/// Number of claims performed in the last block in which a claim happened.
+pub const BLOCK_CLAIMS: Item<BlockClaims> = Item::new("block_claims");
+/// Map of the time of the last claim performed by each address.
+pub const LAST_CLAIM_TIMES: Map<&Addr, Timestamp> = Map::new("last_claim_times");
+/// Map of the addresses that claimed a POAP, keyed by event id and lowercase DTag.
+pub const CLAIMED_DTAGS: Map<(u64, &str), Addr> = Map::new("claimed_dtags");
+/// Map of the events whose POAP contract is being instantiated, keyed by the id
+/// used as reply id of the instantiation.
+pub const PENDING_EVENTS: Map<u64, PendingEvent> = Map::new("pending_events");
+
+/// Registry of the events created from this contract, keyed by event id.
+pub fn events<'a>() -> IndexedMap<'a, u64, EventRecord, EventRecordIndexes<'a>> {
+    let indexes = EventRecordIndexes {
+        creator: MultiIndex::new(
+            |_pk, data| data.creator.clone(),
+            "events",
+            "events__creator",
+        ),
+        end_time: MultiIndex::new(
+            |_pk, data| data.end_time.seconds(),
+            "events",
+            "events__end_time",
+        ),
+    };
+
+    IndexedMap::new("events", indexes)
+}
@@ -1,12 +1,10 @@
 #![cfg(test)]
-use cosmwasm_std::{
-    Binary, Deps, DepsMut, Empty, Env, MessageInfo, Reply, Response, StdError, StdResult,
-};
-use cw721_base::{
-    ContractError as Cw721ContractError, Cw721Contract, ExecuteMsg as Cw721ExecuteMsg,
+use cosmwasm_std::{Binary, Deps, DepsMut, Env, MessageInfo, Reply, Response, StdError, StdResult};
+use cw721_base::InstantiateMsg as Cw721BaseInstantiateMsg;
+use cw721_poap::{
+    entry as cw721_entry, ContractError as Cw721ContractError, ExecuteMsg as Cw721ExecuteMsg,
     InstantiateMsg as Cw721InstantiateMsg, QueryMsg as Cw721QueryMsg,
 };
-use cw721_poap::Metadata;
 use cw_multi_test::{Contract, ContractWrapper};
 use desmos_bindings::{msg::DesmosMsg, query::DesmosQuery};
 use poap::{
@@ -36,7 +34,7 @@ impl POAPTestContract {
         _deps: DepsMut<DesmosQuery>,
         _env: Env,
         _info: MessageInfo,
-        _msg: Cw721InstantiateMsg,
+        _msg: Cw721BaseInstantiateMsg,
     ) -> Result<Response<DesmosMsg>, POAPContractError> {
         Err(POAPContractError::Std(StdError::generic_err(
             "poap initialization failed",
@@ -87,9 +85,8 @@ impl CW721TestContract {
         env: Env,
         info: MessageInfo,
         msg: Cw721InstantiateMsg,
-    ) -> Result<Response<DesmosMsg>, StdError> {
-        Cw721Contract::<'static, Metadata, Empty, Empty, DesmosMsg, DesmosQuery>::default()
-            .instantiate(deps, env, info, msg)
+    ) -> Result<Response<DesmosMsg>, Cw721ContractError> {
+        cw721_entry::instantiate(deps, env, info, msg)
     }
 
     fn failing_instantiate(
@@ -97,23 +94,21 @@ impl CW721TestContract {
         _env: Env,
         _info: MessageInfo,
         _msg: Cw721InstantiateMsg,
-    ) -> Result<Response<DesmosMsg>, StdError> {
-        Err(StdError::generic_err("cw721 initialization failed"))
+    ) -> Result<Response<DesmosMsg>, Cw721ContractError> {
+        Err(StdError::generic_err("cw721 initialization failed").into())
     }
 
     fn execute(
         deps: DepsMut<DesmosQuery>,
         env: Env,
         info: MessageInfo,
-        msg: Cw721ExecuteMsg<Metadata, Empty>,
+        msg: Cw721ExecuteMsg,
     ) -> Result<Response<DesmosMsg>, Cw721ContractError> {
-        Cw721Contract::<'static, Metadata, Empty, Empty, DesmosMsg, DesmosQuery>::default()
-            .execute(deps, env, info, msg)
+        cw721_entry::execute(deps, env, info, msg)
     }
 
-    fn query(deps: Deps<DesmosQuery>, env: Env, msg: Cw721QueryMsg<Empty>) -> StdResult<Binary> {
-        Cw721Contract::<'static, Metadata, Empty, Empty, DesmosMsg, DesmosQuery>::default()
-            .query(deps, env, msg)
+    fn query(deps: Deps<DesmosQuery>, env: Env, msg: Cw721QueryMsg) -> StdResult<Binary> {
+        cw721_entry::query(deps, env, msg)
     }
 
     /// Provides an instance of a cw721 contract.
@@ -1,43 +1,72 @@
 use crate::error::ContractError;
+use crate::merkle::{leaf_hash, verify_proof};
 use crate::msg::{
-    ExecuteMsg, InstantiateMsg, QueryConfigResponse, QueryEventInfoResponse,
-    QueryMintedAmountResponse, QueryMsg,
+    validate_mint_phases, ContractStatus, EventInfo as MsgEventInfo, ExecuteMsg, InstantiateMsg,
+    MintPath, QueryClaimCodesResponse, QueryConfigResponse, QueryEventInfoResponse,
+    QueryEventsResponse, QueryHasAttendedResponse, QueryHoldersResponse, QueryMintedAmountResponse,
+    QueryMintedAmountsResponse, QueryMsg, QueryProceedsResponse, QueryRevocationResponse,
+    QueryStatsResponse, QueryStatusResponse, Status,
 };
 use crate::state::{
-    Config, EventInfo, CONFIG, CW721_ADDRESS, EVENT_INFO, MINTER_ADDRESS, NEXT_POAP_ID,
+    Config, EventInfo, Revocation, CLAIM_CODES, CLAIM_CODES_INFO, CONFIG, CW721_ADDRESS, EVENTS,
+    EVENT_PROCEEDS, EVENT_STATS, MINTER_ADDRESS, NEXT_EVENT_ID, NEXT_POAP_ID, PHASE_MINTER_ADDRESS,
+    REVOCATIONS, USED_NONCES,
 };
 #[cfg(not(feature = "library"))]
 use cosmwasm_std::entry_point;
 use cosmwasm_std::{
-    to_binary, wasm_execute, wasm_instantiate, Addr, Binary, Deps, DepsMut, Empty, Env,
-    MessageInfo, Reply, Response, StdResult, SubMsg, Timestamp,
+    to_binary, wasm_execute, wasm_instantiate, Addr, BankMsg, Binary, Coin, Deps, DepsMut, Empty,
+    Env, Event, MessageInfo, Order, Reply, Response, StdError, StdResult, Storage, SubMsg,
+    Timestamp, WasmMsg,
 };
 use cw2::set_contract_version;
-use cw721::{AllNftInfoResponse, TokensResponse};
-use cw721_base::{
-    msg::ExecuteMsg as Cw721ExecuteMsg, InstantiateMsg as Cw721InstantiateMsg, MintMsg,
+use cw721::{AllNftInfoResponse, NftInfoResponse, TokensResponse};
+use cw721_base::{msg::ExecuteMsg as Cw721ExecuteMsg, MintMsg};
+use cw721_poap::{
+    InstantiateMsg as Cw721PoapInstantiateMsg, Metadata, QueryMsg as Cw721PoapQueryMsg,
 };
-use cw721_poap::{Metadata, QueryMsg as Cw721PoapQueryMsg};
+use cw_storage_plus::Bound;
 use cw_utils::parse_reply_instantiate_data;
 use desmos_bindings::{msg::DesmosMsg, query::DesmosQuery};
+use sha2::{Digest, Sha256};
 // version info for migration info
 const CONTRACT_NAME: &str = "crates.io:poap";
 const CONTRACT_VERSION: &str = env!("CARGO_PKG_VERSION");
 // actions consts
 const ACTION_ENABLE_MINT: &str = "enable_mint";
 const ACTION_DISABLE_MINT: &str = "disable_mint";
+const ACTION_CREATE_EVENT: &str = "create_event";
 const ACTION_MINT: &str = "mint";
 const ACTION_MINT_TO: &str = "mint_to";
+const ACTION_MINT_TO_MANY: &str = "mint_to_many";
+const ACTION_MINT_WITH_CODE: &str = "mint_with_code";
+const ACTION_ADD_CLAIM_CODES: &str = "add_claim_codes";
+const ACTION_UPDATE_CLAIM_SECRET: &str = "update_claim_secret";
+const ACTION_MINT_WITH_PROOF: &str = "mint_with_proof";
+const ACTION_UPDATE_MERKLE_ROOT: &str = "update_merkle_root";
+const ACTION_MINT_WITH_SIGNATURE: &str = "mint_with_signature";
+const ACTION_UPDATE_ATTESTOR: &str = "update_attestor";
+const ACTION_UPDATE_MAX_SUPPLY: &str = "update_max_supply";
+const ACTION_CLAIM_FUNDS: &str = "claim_funds";
+const ACTION_REVOKE: &str = "revoke";
 const ACTION_UPDATE_EVENT_INFO: &str = "update_event_info";
+const ACTION_EXTEND_EVENT: &str = "extend_event";
+const ACTION_CLOSE_EVENT: &str = "close_event";
 const ACTION_UPDATE_ADMIN: &str = "update_admin";
 const ACTION_UPDATE_MINTER: &str = "update_minter";
 // response attributes
 const ATTRIBUTE_ACTION: &str = "action";
 const ATTRIBUTE_SENDER: &str = "sender";
 const ATTRIBUTE_CREATOR: &str = "creator";
+const ATTRIBUTE_EVENT_ID: &str = "event_id";
+// events consts
+const EVENT_POAP_REVOKED: &str = "poap_revoked";
 
 const INSTANTIATE_CW721_REPLY_ID: u64 = 1;
 
+const DEFAULT_QUERY_LIMIT: u32 = 10;
+const MAX_QUERY_LIMIT: u32 = 30;
+
 #[cfg_attr(not(feature = "library"), entry_point)]
 pub fn instantiate(
     deps: DepsMut<DesmosQuery>,
@@ -55,52 +84,28 @@ pub fn instantiate(
     // Validate the minter address
     let minter = deps.api.addr_validate(&msg.minter)?;
 
-    // Validate the creator address
-    let creator = deps.api.addr_validate(&msg.event_info.creator)?;
-
-    // Check that the end time is in the future
-    if !msg.event_info.end_time.gt(&env.block.time) {
-        return Err(ContractError::EndTimeBeforeCurrentTime {
-            current_time: env.block.time,
-            end_time: msg.event_info.end_time,
-        });
-    }
-
-    // Check that the start time is in the future
-    if !msg.event_info.start_time.gt(&env.block.time) {
-        return Err(ContractError::StartTimeBeforeCurrentTime {
-            current_time: env.block.time,
-            start_time: msg.event_info.start_time,
-        });
-    }
-
     let config = Config {
         admin: admin.clone(),
         minter: minter.clone(),
-        per_address_limit: msg.event_info.per_address_limit,
         cw721_code_id: msg.cw721_code_id.u64(),
-        mint_enabled: false,
+        attestor_pubkey: msg.attestor_pubkey,
+        status: ContractStatus::Initializing,
     };
     // Save the received event info.
     CONFIG.save(deps.storage, &config)?;
 
-    let event_info = EventInfo {
-        creator: creator.clone(),
-        start_time: msg.event_info.start_time,
-        end_time: msg.event_info.end_time,
-        poap_uri: msg.event_info.poap_uri.clone(),
-    };
-    // Save the event info
-    EVENT_INFO.save(deps.storage, &event_info)?;
+    // Save the first event
+    let (event_id, event_info) = save_new_event(deps, &env, &msg.event_info)?;
 
     // Submessage to instantiate cw721 contract
     let cw721_submessage = SubMsg::reply_on_success(
         wasm_instantiate(
             msg.cw721_code_id.into(),
-            &Cw721InstantiateMsg {
+            &Cw721PoapInstantiateMsg {
                 name: msg.cw721_instantiate_msg.name,
                 symbol: msg.cw721_instantiate_msg.symbol,
                 minter: env.contract.address.to_string(),
+                soulbound: msg.soulbound,
             },
             info.funds,
             "poap_cw721".to_string(),
@@ -110,20 +115,85 @@ pub fn instantiate(
 
     Ok(Response::new()
         .add_attribute(ATTRIBUTE_ACTION, "instantiate")
-        .add_attribute(ATTRIBUTE_CREATOR, creator)
         .add_attribute("admin", admin)
         .add_attribute("minter", minter)
-        .add_attribute("start_time", msg.event_info.start_time.to_string())
-        .add_attribute("end_time", msg.event_info.end_time.to_string())
-        .add_attribute(
-            "per_address_limit",
-            msg.event_info.per_address_limit.to_string(),
-        )
-        .add_attribute("poap_uri", &msg.event_info.poap_uri)
+        .add_attributes(event_attributes(event_id, &event_info))
         .add_attribute("cw721_code_id", &msg.cw721_code_id.to_string())
+        .add_attribute("soulbound", msg.soulbound.to_string())
         .add_submessage(cw721_submessage))
 }
 
+/// Validates the provided event info and saves it as a new event.
+/// Returns the id assigned to the event together with the stored info.
+fn save_new_event(
+    deps: DepsMut<DesmosQuery>,
+    env: &Env,
+    msg_event_info: &MsgEventInfo,
+) -> Result<(u64, EventInfo), ContractError> {
+    // Validate the creator address
+    let creator = deps.api.addr_validate(&msg_event_info.creator)?;
+
+    // Check that the end time is in the future
+    if !msg_event_info.end_time.gt(&env.block.time) {
+        return Err(ContractError::EndTimeBeforeCurrentTime {
+            current_time: env.block.time,
+            end_time: msg_event_info.end_time,
+        });
+    }
+
+    // Check that the start time is in the future
+    if !msg_event_info.start_time.gt(&env.block.time) {
+        return Err(ContractError::StartTimeBeforeCurrentTime {
+            current_time: env.block.time,
+            start_time: msg_event_info.start_time,
+        });
+    }
+
+    let event_info = EventInfo {
+        creator,
+        start_time: msg_event_info.start_time,
+        end_time: msg_event_info.end_time,
+        per_address_limit: msg_event_info.per_address_limit,
+        poap_uri: msg_event_info.poap_uri.clone(),
+        merkle_root: msg_event_info.merkle_root.clone(),
+        max_supply: msg_event_info.max_supply,
+        mint_price: msg_event_info.mint_price.clone(),
+        tier_uris: msg_event_info.tier_uris.clone(),
+        phases: msg_event_info.phases.clone(),
+        mint_enabled: false,
+        closed: false,
+    };
+
+    // Save the event info under the next event id
+    let event_id = NEXT_EVENT_ID.may_load(deps.storage)?.unwrap_or(1);
+    EVENTS.save(deps.storage, event_id, &event_info)?;
+    NEXT_EVENT_ID.save(deps.storage, &(event_id + 1))?;
+
+    Ok((event_id, event_info))
+}
+
+/// Builds the attributes that describe a newly created event.
+fn event_attributes(event_id: u64, event_info: &EventInfo) -> Vec<(&'static str, String)> {
+    vec![
+        (ATTRIBUTE_EVENT_ID, event_id.to_string()),
+        (ATTRIBUTE_CREATOR, event_info.creator.to_string()),
+        ("start_time", event_info.start_time.to_string()),
+        ("end_time", event_info.end_time.to_string()),
+        (
+            "per_address_limit",
+            event_info.per_address_limit.to_string(),
+        ),
+        ("poap_uri", event_info.poap_uri.clone()),
+    ]
+}
+
+/// Loads the info of the event having the given id.
+fn load_event(storage: &dyn Storage, event_id: u64) -> Result<EventInfo, ContractError> {
+    EVENTS
+        .may_load(storage, event_id)?
+        .ok_or(ContractError::EventNotFound { event_id })
+}
+
 #[cfg_attr(not(feature = "library"), entry_point)]
 pub fn execute(
     deps: DepsMut<DesmosQuery>,
@@ -133,21 +203,113 @@ pub fn execute(
 ) -> Result<Response<DesmosMsg>, ContractError> {
     msg.validate()?;
 
+    // Check that the cw721 contract has been instantiated
+    if CONFIG.load(deps.storage)?.status == ContractStatus::Initializing {
+        return Err(ContractError::ContractNotReady {});
+    }
+
     match msg {
-        ExecuteMsg::EnableMint {} => execute_set_mint_enabled(deps, info, true),
-        ExecuteMsg::DisableMint {} => execute_set_mint_enabled(deps, info, false),
-        ExecuteMsg::Mint {} => {
+        ExecuteMsg::EnableMint { event_id } => {
+            execute_set_mint_enabled(deps, info, event_id.u64(), true)
+        }
+        ExecuteMsg::DisableMint { event_id } => {
+            execute_set_mint_enabled(deps, info, event_id.u64(), false)
+        }
+        ExecuteMsg::CreateEvent { event_info } => execute_create_event(deps, env, info, event_info),
+        ExecuteMsg::Mint { event_id } => {
             let recipient_addr = info.sender.clone();
-            execute_mint(deps, env, info, ACTION_MINT, recipient_addr, false, false)
+            execute_mint(
+                deps,
+                env,
+                info,
+                ACTION_MINT,
+                event_id.u64(),
+                recipient_addr,
+                None,
+                false,
+                false,
+            )
         }
-        ExecuteMsg::MintTo { recipient } => {
+        ExecuteMsg::MintTo {
+            event_id,
+            recipient,
+            tier,
+        } => {
             let recipient_addr = deps.api.addr_validate(&recipient)?;
-            execute_mint(deps, env, info, ACTION_MINT_TO, recipient_addr, true, true)
+            execute_mint(
+                deps,
+                env,
+                info,
+                ACTION_MINT_TO,
+                event_id.u64(),
+                recipient_addr,
+                tier,
+                true,
+                true,
+            )
         }
+        ExecuteMsg::MintToMany {
+            event_id,
+            recipients,
+            skip_ineligible,
+        } => execute_mint_to_many(deps, env, info, event_id.u64(), recipients, skip_ineligible),
         ExecuteMsg::UpdateEventInfo {
+            event_id,
             start_time,
             end_time,
-        } => execute_update_event_info(deps, env, info, start_time, end_time),
+        } => execute_update_event_info(deps, env, info, event_id.u64(), start_time, end_time),
+        ExecuteMsg::ExtendEvent {
+            event_id,
+            new_end_time,
+        } => execute_extend_event(deps, env, info, event_id.u64(), new_end_time),
+        ExecuteMsg::CloseEvent { event_id } => execute_close_event(deps, env, info, event_id.u64()),
+        ExecuteMsg::AddClaimCodes {
+            event_id,
+            code_hashes,
+        } => execute_add_claim_codes(deps, env, info, event_id.u64(), code_hashes),
+        ExecuteMsg::UpdateClaimSecret {
+            event_id,
+            secret_hash,
+        } => execute_update_claim_secret(deps, env, info, event_id.u64(), secret_hash),
+        ExecuteMsg::MintWithCode { event_id, code } => {
+            execute_mint_with_code(deps, env, info, event_id.u64(), code)
+        }
+        ExecuteMsg::MintWithProof {
+            event_id,
+            amount,
+            proof,
+        } => execute_mint_with_proof(deps, env, info, event_id.u64(), amount, proof),
+        ExecuteMsg::UpdateMerkleRoot {
+            event_id,
+            merkle_root,
+        } => execute_update_merkle_root(deps, env, info, event_id.u64(), merkle_root),
+        ExecuteMsg::MintWithSignature {
+            event_id,
+            signature,
+            expiration,
+            nonce,
+        } => execute_mint_with_signature(
+            deps,
+            env,
+            info,
+            event_id.u64(),
+            signature,
+            expiration,
+            nonce.u64(),
+        ),
+        ExecuteMsg::UpdateMaxSupply {
+            event_id,
+            max_supply,
+        } => execute_update_max_supply(deps, env, info, event_id.u64(), max_supply),
+        ExecuteMsg::ClaimFunds { event_id, receiver } => {
+            execute_claim_funds(deps, info, event_id.u64(), receiver)
+        }
+        ExecuteMsg::Revoke { token_id, reason } => {
+            execute_revoke(deps, env, info, token_id, reason)
+        }
+        ExecuteMsg::UpdateAttestor { attestor_pubkey } => {
+            execute_update_attestor(deps, info, attestor_pubkey)
+        }
         ExecuteMsg::UpdateAdmin { new_admin } => execute_update_admin(deps, info, new_admin),
         ExecuteMsg::UpdateMinter { new_minter } => execute_update_minter(deps, info, new_minter),
     }
@@ -156,18 +318,20 @@ pub fn execute(
 fn execute_set_mint_enabled(
     deps: DepsMut<DesmosQuery>,
     info: MessageInfo,
+    event_id: u64,
     mint_enabled: bool,
 ) -> Result<Response<DesmosMsg>, ContractError> {
-    let mut config = CONFIG.load(deps.storage)?;
+    let config = CONFIG.load(deps.storage)?;
 
     // Check that the sender is the admin
     if info.sender != config.admin {
         return Err(ContractError::Unauthorized {});
     }
 
-    config.mint_enabled = mint_enabled;
-    // Save the new configurations
-    CONFIG.save(deps.storage, &config)?;
+    let mut event_info = load_event(deps.storage, event_id)?;
+    event_info.mint_enabled = mint_enabled;
+    // Save the new event info
+    EVENTS.save(deps.storage, event_id, &event_info)?;
 
     let action = if mint_enabled {
         ACTION_ENABLE_MINT
@@ -177,20 +341,172 @@ fn execute_set_mint_enabled(
 
     Ok(Response::new()
         .add_attribute(ATTRIBUTE_ACTION, action)
-        .add_attribute(ATTRIBUTE_SENDER, info.sender))
+        .add_attribute(ATTRIBUTE_SENDER, info.sender)
+        .add_attribute(ATTRIBUTE_EVENT_ID, event_id.to_string()))
+}
+
+fn execute_create_event(
+    deps: DepsMut<DesmosQuery>,
+    env: Env,
+    info: MessageInfo,
+    event_info: MsgEventInfo,
+) -> Result<Response<DesmosMsg>, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+
+    // Check that the sender is the admin
+    if info.sender != config.admin {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    let (event_id, event_info) = save_new_event(deps, &env, &event_info)?;
+
+    Ok(Response::new()
+        .add_attribute(ATTRIBUTE_ACTION, ACTION_CREATE_EVENT)
+        .add_attribute(ATTRIBUTE_SENDER, info.sender)
+        .add_attributes(event_attributes(event_id, &event_info)))
 }
 
+#[allow(clippy::too_many_arguments)]
 fn execute_mint(
     deps: DepsMut<DesmosQuery>,
     env: Env,
     info: MessageInfo,
     action: &str,
+    event_id: u64,
     recipient_addr: Addr,
+    tier: Option<String>,
     bypass_mint_enable: bool,
     check_authorized_to_mint: bool,
 ) -> Result<Response<DesmosMsg>, ContractError> {
-    let config = CONFIG.load(deps.storage)?;
-    let event_info = EVENT_INFO.load(deps.storage)?;
+    let event_info = load_mintable_event(
+        deps.storage,
+        &env,
+        &info,
+        event_id,
+        bypass_mint_enable,
+        check_authorized_to_mint,
+    )?;
+
+    // Allowlisted events can only be minted by the users with a valid proof
+    if action == ACTION_MINT && event_info.merkle_root.is_some() {
+        return Err(ContractError::MerkleProofRequired { event_id });
+    }
+
+    let mut funds = info.funds.clone();
+    let (wasm_execute_mint_msg, poap_id) = mint_poap(
+        deps.storage,
+        &env,
+        &info.sender,
+        action,
+        event_id,
+        &event_info,
+        &mut funds,
+        &recipient_addr,
+        tier.clone(),
+    )?;
+
+    let mut response = Response::new()
+        .add_attribute(ATTRIBUTE_ACTION, action)
+        .add_attribute(ATTRIBUTE_SENDER, info.sender.clone())
+        .add_attribute(ATTRIBUTE_EVENT_ID, event_id.to_string())
+        .add_attribute("recipient", recipient_addr.to_string())
+        .add_attribute("poap_id", poap_id.to_string())
+        .add_message(wasm_execute_mint_msg);
+
+    if let Some(tier) = tier {
+        response = response.add_attribute("tier", tier);
+    }
+
+    // Refund what exceeds the mint price
+    if !funds.is_empty() {
+        response = response.add_message(BankMsg::Send {
+            to_address: info.sender.to_string(),
+            amount: funds,
+        });
+    }
+
+    Ok(response)
+}
+
+fn execute_mint_to_many(
+    deps: DepsMut<DesmosQuery>,
+    env: Env,
+    info: MessageInfo,
+    event_id: u64,
+    recipients: Vec<String>,
+    skip_ineligible: bool,
+) -> Result<Response<DesmosMsg>, ContractError> {
+    let event_info = load_mintable_event(deps.storage, &env, &info, event_id, true, true)?;
+
+    // Validate all the recipients before minting
+    let recipients = recipients
+        .iter()
+        .map(|recipient| deps.api.addr_validate(recipient))
+        .collect::<StdResult<Vec<Addr>>>()?;
+
+    let mut funds = info.funds.clone();
+    let mut mint_msgs = vec![];
+    let mut minted_ids = vec![];
+    let mut skipped = vec![];
+    for recipient_addr in recipients {
+        match mint_poap(
+            deps.storage,
+            &env,
+            &info.sender,
+            ACTION_MINT_TO_MANY,
+            event_id,
+            &event_info,
+            &mut funds,
+            &recipient_addr,
+            None,
+        ) {
+            Ok((mint_msg, poap_id)) => {
+                mint_msgs.push(mint_msg);
+                minted_ids.push(poap_id.to_string());
+            }
+            Err(ContractError::MaxPerAddressLimitExceeded { .. }) if skip_ineligible => {
+                skipped.push(recipient_addr.to_string());
+            }
+            Err(err) => return Err(err),
+        }
+    }
+
+    let mut response = Response::new()
+        .add_attribute(ATTRIBUTE_ACTION, ACTION_MINT_TO_MANY)
+        .add_attribute(ATTRIBUTE_SENDER, info.sender.clone())
+        .add_attribute(ATTRIBUTE_EVENT_ID, event_id.to_string())
+        .add_attribute("poap_ids", minted_ids.join(","))
+        .add_attribute("skipped_recipients", skipped.join(","))
+        .add_messages(mint_msgs);
+
+    // Refund what exceeds the mint price
+    if !funds.is_empty() {
+        response = response.add_message(BankMsg::Send {
+            to_address: info.sender.to_string(),
+            amount: funds,
+        });
+    }
+
+    Ok(response)
+}
+
+/// Loads the info of the event having the given id ensuring that the event is
+/// in progress and that the sender is allowed to mint.
+fn load_mintable_event(
+    storage: &dyn Storage,
+    env: &Env,
+    info: &MessageInfo,
+    event_id: u64,
+    bypass_mint_enable: bool,
+    check_authorized_to_mint: bool,
+) -> Result<EventInfo, ContractError> {
+    let config = CONFIG.load(storage)?;
+    let event_info = load_event(storage, event_id)?;
+
+    // Check if the event has been closed
+    if event_info.closed {
+        return Err(ContractError::EventClosed { event_id });
+    }
 
     // Check if the event is started
     if !event_info.is_started(&env.block.time) {
@@ -208,8 +524,8 @@ fn execute_mint(
         });
     }
 
-    // Check if the mint is enabled
-    if !bypass_mint_enable && !config.mint_enabled {
+    // Check if the mint of the event is enabled
+    if !bypass_mint_enable && !event_info.mint_enabled {
         return Err(ContractError::MintDisabled {});
     }
 
@@ -218,74 +534,196 @@ fn execute_mint(
         return Err(ContractError::Unauthorized {});
     }
 
+    Ok(event_info)
+}
+
+/// Mints a poap of an event to the recipient, taking the mint price from `funds`.
+/// The recipient's limits and the event supply are checked before changing any state.
+/// Returns the message that mints the poap on the cw721 contract together with the poap id.
+#[allow(clippy::too_many_arguments)]
+fn mint_poap(
+    storage: &mut dyn Storage,
+    env: &Env,
+    minter: &Addr,
+    action: &str,
+    event_id: u64,
+    event_info: &EventInfo,
+    funds: &mut Vec<Coin>,
+    recipient_addr: &Addr,
+    tier: Option<String>,
+) -> Result<(WasmMsg, u64), ContractError> {
     // Check per address limit
-    let recipient_mint_count = (MINTER_ADDRESS
-        .key(recipient_addr.clone())
-        .may_load(deps.storage)?)
-    .unwrap_or(0);
+    let recipient_mint_count = MINTER_ADDRESS
+        .may_load(storage, (event_id, recipient_addr.clone()))?
+        .unwrap_or(0);
 
-    if recipient_mint_count >= config.per_address_limit {
+    if recipient_mint_count >= event_info.per_address_limit {
         return Err(ContractError::MaxPerAddressLimitExceeded {
             recipient_addr: recipient_addr.to_string(),
         });
     }
 
+    // Check the active phase, if the event has minting phases
+    let active_phase = if event_info.phases.is_empty() {
+        None
+    } else {
+        let (phase_index, phase) = event_info
+            .active_phase(&env.block.time)
+            .ok_or(ContractError::NoActiveMintPhase { event_id })?;
+
+        if !is_mint_path_allowed(&phase.mint_path, action) {
+            return Err(ContractError::MintPathNotAllowed { phase: phase_index });
+        }
+
+        let phase_mint_count = PHASE_MINTER_ADDRESS
+            .may_load(storage, (event_id, phase_index, recipient_addr.clone()))?
+            .unwrap_or(0);
+        if phase_mint_count >= phase.per_address_limit {
+            return Err(ContractError::MaxPerAddressLimitExceeded {
+                recipient_addr: recipient_addr.to_string(),
+            });
+        }
+
+        Some((phase_index, phase_mint_count))
+    };
+
+    // Check the event max supply
+    let mut event_stats = EVENT_STATS.may_load(storage, event_id)?.unwrap_or_default();
+    if let Some(max_supply) = event_info.max_supply {
+        if event_stats.minted >= max_supply {
+            return Err(ContractError::MaxSupplyReached { event_id });
+        }
+    }
+
+    // Take the mint price from the sent funds
+    *funds = take_mint_price(funds.clone(), &event_info.mint_price)?;
+    if !event_info.mint_price.is_empty() {
+        EVENT_PROCEEDS.update(storage, event_id, |proceeds| -> StdResult<_> {
+            let mut proceeds = proceeds.unwrap_or_default();
+            add_coins(&mut proceeds, &event_info.mint_price);
+            Ok(proceeds)
+        })?;
+    }
+
     // Get the next poap id
-    let poap_id = NEXT_POAP_ID.may_load(deps.storage)?.unwrap_or(1);
+    let poap_id = NEXT_POAP_ID.may_load(storage)?.unwrap_or(1);
+
+    // Use the tier's uri if the event has one for the poap's tier,
+    // the tier must be one of the event's tiers if it defines any
+    let token_uri = match tier.as_ref() {
+        Some(tier) if !event_info.tier_uris.is_empty() => event_info
+            .tier_uris
+            .iter()
+            .find(|tier_uri| &tier_uri.tier == tier)
+            .map(|tier_uri| tier_uri.uri.clone())
+            .ok_or_else(|| ContractError::TierNotFound {
+                event_id,
+                tier: tier.clone(),
+            })?,
+        _ => event_info.poap_uri.clone(),
+    };
 
     // Create the cw721 message to send to mint the poap
     let mint_msg = Cw721ExecuteMsg::<Metadata, Empty>::Mint(MintMsg::<Metadata> {
         token_id: poap_id.to_string(),
         owner: recipient_addr.to_string(),
-        token_uri: Some(event_info.poap_uri),
+        token_uri: Some(token_uri),
         extension: Metadata {
             claimer: recipient_addr.clone(),
+            event_id,
+            mint_time: env.block.time,
+            minter: minter.clone(),
+            tier,
         },
     });
 
-    let cw721_address = CW721_ADDRESS.load(deps.storage)?;
+    let cw721_address = CW721_ADDRESS.load(storage)?;
     let wasm_execute_mint_msg = wasm_execute(cw721_address, &mint_msg, vec![])?;
 
     // Update the next poap id state
     let new_poap_id = poap_id + 1;
-    NEXT_POAP_ID.save(deps.storage, &new_poap_id)?;
+    NEXT_POAP_ID.save(storage, &new_poap_id)?;
     // Save the new mint count for the sender's address
     let new_recipient_mint_count = recipient_mint_count + 1;
     MINTER_ADDRESS.save(
-        deps.storage,
-        recipient_addr.clone(),
+        storage,
+        (event_id, recipient_addr.clone()),
         &new_recipient_mint_count,
     )?;
+    // Save the new mint count for the sender's address in the active phase
+    if let Some((phase_index, phase_mint_count)) = active_phase {
+        PHASE_MINTER_ADDRESS.save(
+            storage,
+            (event_id, phase_index, recipient_addr.clone()),
+            &(phase_mint_count + 1),
+        )?;
+    }
+    // Update the event statistics
+    event_stats.minted += 1;
+    if recipient_mint_count == 0 {
+        event_stats.unique_holders += 1;
+    }
+    EVENT_STATS.save(storage, event_id, &event_stats)?;
 
-    Ok(Response::new()
-        .add_attribute(ATTRIBUTE_ACTION, action)
-        .add_attribute(ATTRIBUTE_SENDER, info.sender)
-        .add_attribute("recipient", recipient_addr.to_string())
-        .add_attribute("poap_id", poap_id.to_string())
-        .add_message(wasm_execute_mint_msg))
+    Ok((wasm_execute_mint_msg, poap_id))
+}
+
+/// Tells if the mint performed with the given action is allowed by the mint path.
+fn is_mint_path_allowed(mint_path: &MintPath, action: &str) -> bool {
+    match mint_path {
+        MintPath::Public => true,
+        MintPath::MintToOnly => action == ACTION_MINT_TO || action == ACTION_MINT_TO_MANY,
+        MintPath::CodeOnly => action == ACTION_MINT_WITH_CODE,
+    }
+}
+
+/// Takes the mint price out of the given funds.
+/// Returns the funds exceeding the price that have to be refunded to the sender.
+fn take_mint_price(mut funds: Vec<Coin>, mint_price: &[Coin]) -> Result<Vec<Coin>, ContractError> {
+    for price in mint_price {
+        let paid = funds
+            .iter_mut()
+            .find(|coin| coin.denom == price.denom && coin.amount >= price.amount)
+            .ok_or_else(|| ContractError::MintPriceNotPaid {
+                mint_price: coins_to_string(mint_price),
+            })?;
+        paid.amount -= price.amount;
+    }
+    funds.retain(|coin| !coin.amount.is_zero());
+    Ok(funds)
+}
+
+/// Adds the given coins to the total, merging the ones having the same denom.
+fn add_coins(total: &mut Vec<Coin>, coins: &[Coin]) {
+    for coin in coins {
+        match total
+            .iter_mut()
+            .find(|total_coin| total_coin.denom == coin.denom)
+        {
+            Some(total_coin) => total_coin.amount += coin.amount,
+            None => total.push(coin.clone()),
+        }
+    }
+}
+
+/// Serializes the coins as a comma separated list.
+fn coins_to_string(coins: &[Coin]) -> String {
+    coins
+        .iter()
+        .map(|coin| coin.to_string())
+        .collect::<Vec<_>>()
+        .join(",")
 }
 
 fn execute_update_event_info(
     deps: DepsMut<DesmosQuery>,
     env: Env,
     info: MessageInfo,
+    event_id: u64,
     start_time: Timestamp,
     end_time: Timestamp,
 ) -> Result<Response<DesmosMsg>, ContractError> {
-    let mut event_info = EVENT_INFO.load(deps.storage)?;
-
-    // Check that is the event creator that is changing the event time frame
-    if event_info.creator != info.sender {
-        return Err(ContractError::Unauthorized {});
-    }
-
-    // Check that the event is not ended
-    if event_info.is_ended(&env.block.time) {
-        return Err(ContractError::EventTerminated {
-            current_time: env.block.time,
-            end_time: event_info.end_time,
-        });
-    }
+    let mut event_info = load_editable_event(deps.storage, &env, &info, event_id)?;
 
     // Check that the event is not started
     if event_info.is_started(&env.block.time) {
@@ -303,1056 +741,3387 @@ fn execute_update_event_info(
         });
     }
 
+    // Check that the minting phases are still within the event time frame
+    validate_mint_phases(&event_info.phases, &start_time, &end_time)?;
+
     // Update the event info
     event_info.start_time = start_time;
     event_info.end_time = end_time;
-    EVENT_INFO.save(deps.storage, &event_info)?;
+    EVENTS.save(deps.storage, event_id, &event_info)?;
 
     Ok(Response::new()
         .add_attribute(ATTRIBUTE_ACTION, ACTION_UPDATE_EVENT_INFO)
         .add_attribute(ATTRIBUTE_SENDER, &info.sender)
+        .add_attribute(ATTRIBUTE_EVENT_ID, event_id.to_string())
         .add_attribute("new_start_time", start_time.to_string())
         .add_attribute("new_end_time", end_time.to_string()))
 }
 
-fn execute_update_admin(
+fn execute_extend_event(
     deps: DepsMut<DesmosQuery>,
+    env: Env,
     info: MessageInfo,
-    admin_address: String,
+    event_id: u64,
+    new_end_time: Timestamp,
 ) -> Result<Response<DesmosMsg>, ContractError> {
-    let mut config = CONFIG.load(deps.storage)?;
+    let mut event_info = load_editable_event(deps.storage, &env, &info, event_id)?;
 
-    // Check that the sender is the admin
-    if info.sender != config.admin {
-        return Err(ContractError::Unauthorized {});
+    // Check that the event is in progress
+    if !event_info.is_started(&env.block.time) {
+        return Err(ContractError::EventNotStarted {
+            current_time: env.block.time,
+            start_time: event_info.start_time,
+        });
     }
 
-    // Update the admin address.
-    let new_admin = deps.api.addr_validate(&admin_address)?;
-    config.admin = new_admin;
-    CONFIG.save(deps.storage, &config)?;
+    // Check that the event is postponed
+    if new_end_time <= event_info.end_time {
+        return Err(ContractError::InvalidEndTimeExtension {
+            end_time: event_info.end_time,
+            new_end_time,
+        });
+    }
+
+    // Extend also the last phase if it was lasting until the end of the event
+    if let Some(last_phase) = event_info.phases.last_mut() {
+        if last_phase.end_time == event_info.end_time {
+            last_phase.end_time = new_end_time;
+        }
+    }
+    event_info.end_time = new_end_time;
+    EVENTS.save(deps.storage, event_id, &event_info)?;
 
     Ok(Response::new()
-        .add_attribute(ATTRIBUTE_ACTION, ACTION_UPDATE_ADMIN)
-        .add_attribute("new_admin", &admin_address))
+        .add_attribute(ATTRIBUTE_ACTION, ACTION_EXTEND_EVENT)
+        .add_attribute(ATTRIBUTE_SENDER, &info.sender)
+        .add_attribute(ATTRIBUTE_EVENT_ID, event_id.to_string())
+        .add_attribute("new_end_time", new_end_time.to_string()))
 }
 
-fn execute_update_minter(
+/// Loads the info of the event having the given id ensuring that the sender is the
+/// event's creator and that the event is not ended.
+fn load_editable_event(
+    storage: &dyn Storage,
+    env: &Env,
+    info: &MessageInfo,
+    event_id: u64,
+) -> Result<EventInfo, ContractError> {
+    let event_info = load_event(storage, event_id)?;
+
+    // Check that is the event creator that is performing the action
+    if event_info.creator != info.sender {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    // Check that the event has not been closed
+    if event_info.closed {
+        return Err(ContractError::EventClosed { event_id });
+    }
+
+    // Check that the event is not ended
+    if event_info.is_ended(&env.block.time) {
+        return Err(ContractError::EventTerminated {
+            current_time: env.block.time,
+            end_time: event_info.end_time,
+        });
+    }
+
+    Ok(event_info)
+}
+
+fn execute_close_event(
     deps: DepsMut<DesmosQuery>,
+    env: Env,
     info: MessageInfo,
-    minter_address: String,
+    event_id: u64,
 ) -> Result<Response<DesmosMsg>, ContractError> {
-    let mut config = CONFIG.load(deps.storage)?;
+    let config = CONFIG.load(deps.storage)?;
+    let mut event_info = load_event(deps.storage, event_id)?;
 
-    // Check that the sender is the admin
-    if info.sender != config.admin {
+    // Check that the sender is the event creator or the admin
+    if info.sender != event_info.creator && info.sender != config.admin {
         return Err(ContractError::Unauthorized {});
     }
 
-    // Update the minter address.
-    let new_minter = deps.api.addr_validate(&minter_address)?;
-    config.minter = new_minter;
-    CONFIG.save(deps.storage, &config)?;
+    // Check that the event is not already closed
+    if event_info.is_closed(&env.block.time) {
+        return Err(ContractError::EventClosed { event_id });
+    }
+
+    event_info.closed = true;
+    EVENTS.save(deps.storage, event_id, &event_info)?;
 
     Ok(Response::new()
-        .add_attribute(ATTRIBUTE_ACTION, ACTION_UPDATE_MINTER)
-        .add_attribute("new_minter", &minter_address))
+        .add_attribute(ATTRIBUTE_ACTION, ACTION_CLOSE_EVENT)
+        .add_attribute(ATTRIBUTE_SENDER, info.sender)
+        .add_attribute(ATTRIBUTE_EVENT_ID, event_id.to_string()))
 }
 
-#[cfg_attr(not(feature = "library"), entry_point)]
-pub fn query(deps: Deps<DesmosQuery>, _env: Env, msg: QueryMsg) -> StdResult<Binary> {
-    match msg {
-        QueryMsg::Config {} => to_binary(&query_config(deps)?),
-        QueryMsg::EventInfo {} => to_binary(&query_event_info(deps)?),
-        QueryMsg::MintedAmount { user } => to_binary(&query_minted_amount(deps, user)?),
-        QueryMsg::AllNftInfo {
-            token_id,
-            include_expired,
-        } => to_binary(&query_all_nft_info(deps, token_id, include_expired)?),
-        QueryMsg::Tokens {
-            owner,
-            start_after,
-            limit,
-        } => to_binary(&query_tokens(deps, owner, start_after, limit)?),
+fn execute_add_claim_codes(
+    deps: DepsMut<DesmosQuery>,
+    env: Env,
+    info: MessageInfo,
+    event_id: u64,
+    code_hashes: Vec<String>,
+) -> Result<Response<DesmosMsg>, ContractError> {
+    load_editable_event(deps.storage, &env, &info, event_id)?;
+
+    for hash in code_hashes.iter() {
+        if CLAIM_CODES.has(deps.storage, (event_id, hash.as_str())) {
+            return Err(ContractError::ClaimCodeAlreadyRegistered { hash: hash.clone() });
+        }
+        CLAIM_CODES.save(deps.storage, (event_id, hash.as_str()), &false)?;
     }
-}
 
-fn query_config(deps: Deps<DesmosQuery>) -> StdResult<QueryConfigResponse> {
-    let config = CONFIG.load(deps.storage)?;
-    let cw721_address = CW721_ADDRESS.load(deps.storage)?;
+    let mut claim_codes_info = CLAIM_CODES_INFO
+        .may_load(deps.storage, event_id)?
+        .unwrap_or_default();
+    claim_codes_info.total += code_hashes.len() as u32;
+    CLAIM_CODES_INFO.save(deps.storage, event_id, &claim_codes_info)?;
 
-    Ok(QueryConfigResponse {
-        admin: config.admin,
-        minter: config.minter,
-        mint_enabled: config.mint_enabled,
-        per_address_limit: config.per_address_limit,
-        cw721_contract_code: config.cw721_code_id.into(),
-        cw721_contract: cw721_address,
-    })
+    Ok(Response::new()
+        .add_attribute(ATTRIBUTE_ACTION, ACTION_ADD_CLAIM_CODES)
+        .add_attribute(ATTRIBUTE_SENDER, info.sender)
+        .add_attribute(ATTRIBUTE_EVENT_ID, event_id.to_string())
+        .add_attribute("added_codes", code_hashes.len().to_string()))
 }
 
-fn query_event_info(deps: Deps<DesmosQuery>) -> StdResult<QueryEventInfoResponse> {
-    let event_info = EVENT_INFO.load(deps.storage)?;
-
-    Ok(QueryEventInfoResponse {
-        creator: event_info.creator,
-        start_time: event_info.start_time,
-        end_time: event_info.end_time,
-        poap_uri: event_info.poap_uri,
-    })
+fn execute_update_claim_secret(
+    deps: DepsMut<DesmosQuery>,
+    env: Env,
+    info: MessageInfo,
+    event_id: u64,
+    secret_hash: Option<String>,
+) -> Result<Response<DesmosMsg>, ContractError> {
+    load_editable_event(deps.storage, &env, &info, event_id)?;
+
+    let mut claim_codes_info = CLAIM_CODES_INFO
+        .may_load(deps.storage, event_id)?
+        .unwrap_or_default();
+    let secret_enabled = secret_hash.is_some();
+    claim_codes_info.secret_hash = secret_hash;
+    CLAIM_CODES_INFO.save(deps.storage, event_id, &claim_codes_info)?;
+
+    Ok(Response::new()
+        .add_attribute(ATTRIBUTE_ACTION, ACTION_UPDATE_CLAIM_SECRET)
+        .add_attribute(ATTRIBUTE_SENDER, info.sender)
+        .add_attribute(ATTRIBUTE_EVENT_ID, event_id.to_string())
+        .add_attribute("secret_enabled", secret_enabled.to_string()))
 }
 
-fn query_minted_amount(
-    deps: Deps<DesmosQuery>,
-    user: String,
-) -> StdResult<QueryMintedAmountResponse> {
-    let user_addr = deps.api.addr_validate(&user)?;
+fn execute_mint_with_code(
+    deps: DepsMut<DesmosQuery>,
+    env: Env,
+    info: MessageInfo,
+    event_id: u64,
+    code: String,
+) -> Result<Response<DesmosMsg>, ContractError> {
+    let code_hash = format!("{:x}", Sha256::digest(code.as_bytes()));
+    let mut claim_codes_info = CLAIM_CODES_INFO
+        .may_load(deps.storage, event_id)?
+        .unwrap_or_default();
+
+    match CLAIM_CODES.may_load(deps.storage, (event_id, code_hash.as_str()))? {
+        // The code has already been used by someone else
+        Some(true) => return Err(ContractError::ClaimCodeAlreadyUsed {}),
+        // Mark the code as used
+        Some(false) => {
+            CLAIM_CODES.save(deps.storage, (event_id, code_hash.as_str()), &true)?;
+            claim_codes_info.used += 1;
+            CLAIM_CODES_INFO.save(deps.storage, event_id, &claim_codes_info)?;
+        }
+        // The code is not registered, check if it is the event's shared secret
+        None => {
+            if claim_codes_info.secret_hash != Some(code_hash) {
+                return Err(ContractError::InvalidClaimCode {});
+            }
+        }
+    }
 
-    let minted_amount = MINTER_ADDRESS
-        .may_load(deps.storage, user_addr.clone())?
+    let recipient_addr = info.sender.clone();
+    execute_mint(
+        deps,
+        env,
+        info,
+        ACTION_MINT_WITH_CODE,
+        event_id,
+        recipient_addr,
+        None,
+        true,
+        false,
+    )
+}
+
+fn execute_mint_with_proof(
+    deps: DepsMut<DesmosQuery>,
+    env: Env,
+    info: MessageInfo,
+    event_id: u64,
+    amount: u32,
+    proof: Vec<String>,
+) -> Result<Response<DesmosMsg>, ContractError> {
+    let event_info = load_event(deps.storage, event_id)?;
+    let merkle_root = event_info
+        .merkle_root
+        .ok_or(ContractError::MerkleRootNotSet { event_id })?;
+
+    // Check that the sender is allowlisted for the given amount
+    if !verify_proof(
+        &merkle_root,
+        leaf_hash(info.sender.as_str(), amount),
+        &proof,
+    )? {
+        return Err(ContractError::InvalidMerkleProof {});
+    }
+
+    // Check the allowlisted amount, the per address limit is checked while minting
+    let recipient_mint_count = MINTER_ADDRESS
+        .may_load(deps.storage, (event_id, info.sender.clone()))?
         .unwrap_or(0);
+    if recipient_mint_count >= amount {
+        return Err(ContractError::MaxPerAddressLimitExceeded {
+            recipient_addr: info.sender.to_string(),
+        });
+    }
 
-    Ok(QueryMintedAmountResponse {
-        user: user_addr,
-        amount: minted_amount,
-    })
+    let recipient_addr = info.sender.clone();
+    execute_mint(
+        deps,
+        env,
+        info,
+        ACTION_MINT_WITH_PROOF,
+        event_id,
+        recipient_addr,
+        None,
+        true,
+        false,
+    )
 }
 
-fn query_all_nft_info(
-    deps: Deps<DesmosQuery>,
+fn execute_update_merkle_root(
+    deps: DepsMut<DesmosQuery>,
+    env: Env,
+    info: MessageInfo,
+    event_id: u64,
+    merkle_root: Option<String>,
+) -> Result<Response<DesmosMsg>, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+
+    // Check that the sender is the admin
+    if info.sender != config.admin {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    let mut event_info = load_event(deps.storage, event_id)?;
+
+    // Check that the event has not been closed
+    if event_info.closed {
+        return Err(ContractError::EventClosed { event_id });
+    }
+
+    // Check that the event is not started
+    if event_info.is_started(&env.block.time) {
+        return Err(ContractError::EventStarted {
+            current_time: env.block.time,
+            start_time: event_info.start_time,
+        });
+    }
+
+    event_info.merkle_root = merkle_root;
+    EVENTS.save(deps.storage, event_id, &event_info)?;
+
+    Ok(Response::new()
+        .add_attribute(ATTRIBUTE_ACTION, ACTION_UPDATE_MERKLE_ROOT)
+        .add_attribute(ATTRIBUTE_SENDER, info.sender)
+        .add_attribute(ATTRIBUTE_EVENT_ID, event_id.to_string())
+        .add_attribute(
+            "new_merkle_root",
+            event_info.merkle_root.unwrap_or_default(),
+        ))
+}
+
+fn execute_update_max_supply(
+    deps: DepsMut<DesmosQuery>,
+    env: Env,
+    info: MessageInfo,
+    event_id: u64,
+    max_supply: Option<u32>,
+) -> Result<Response<DesmosMsg>, ContractError> {
+    let mut event_info = load_editable_event(deps.storage, &env, &info, event_id)?;
+
+    // Check that the new max supply is not lower than the already minted poaps
+    if let Some(max_supply) = max_supply {
+        let minted = EVENT_STATS
+            .may_load(deps.storage, event_id)?
+            .unwrap_or_default()
+            .minted;
+        if max_supply < minted {
+            return Err(ContractError::MaxSupplyBelowMinted { max_supply, minted });
+        }
+    }
+
+    event_info.max_supply = max_supply;
+    EVENTS.save(deps.storage, event_id, &event_info)?;
+
+    Ok(Response::new()
+        .add_attribute(ATTRIBUTE_ACTION, ACTION_UPDATE_MAX_SUPPLY)
+        .add_attribute(ATTRIBUTE_SENDER, info.sender)
+        .add_attribute(ATTRIBUTE_EVENT_ID, event_id.to_string())
+        .add_attribute(
+            "new_max_supply",
+            max_supply
+                .map(|supply| supply.to_string())
+                .unwrap_or_default(),
+        ))
+}
+
+fn execute_claim_funds(
+    deps: DepsMut<DesmosQuery>,
+    info: MessageInfo,
+    event_id: u64,
+    receiver: String,
+) -> Result<Response<DesmosMsg>, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+    let event_info = load_event(deps.storage, event_id)?;
+
+    // Check that the sender is the event creator or the admin
+    if info.sender != event_info.creator && info.sender != config.admin {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    let receiver = deps.api.addr_validate(&receiver)?;
+
+    let proceeds = EVENT_PROCEEDS
+        .may_load(deps.storage, event_id)?
+        .unwrap_or_default();
+    if proceeds.is_empty() {
+        return Err(ContractError::NoFundsToClaim { event_id });
+    }
+    EVENT_PROCEEDS.remove(deps.storage, event_id);
+
+    Ok(Response::new()
+        .add_attribute(ATTRIBUTE_ACTION, ACTION_CLAIM_FUNDS)
+        .add_attribute(ATTRIBUTE_SENDER, info.sender)
+        .add_attribute(ATTRIBUTE_EVENT_ID, event_id.to_string())
+        .add_attribute("receiver", receiver.as_str())
+        .add_attribute("amount", coins_to_string(&proceeds))
+        .add_message(BankMsg::Send {
+            to_address: receiver.to_string(),
+            amount: proceeds,
+        }))
+}
+
+fn execute_revoke(
+    deps: DepsMut<DesmosQuery>,
+    env: Env,
+    info: MessageInfo,
     token_id: String,
-    include_expired: Option<bool>,
-) -> StdResult<AllNftInfoResponse<Metadata>> {
+    reason: String,
+) -> Result<Response<DesmosMsg>, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
     let cw721_address = CW721_ADDRESS.load(deps.storage)?;
-    deps.querier.query_wasm_smart(
-        cw721_address,
-        &Cw721PoapQueryMsg::AllNftInfo {
-            token_id,
-            include_expired,
+
+    // Get the event and the holder of the poap from its metadata
+    let nft_info: NftInfoResponse<Metadata> = deps.querier.query_wasm_smart(
+        &cw721_address,
+        &Cw721PoapQueryMsg::NftInfo {
+            token_id: token_id.clone(),
         },
-    )
-}
+    )?;
+    let event_id = nft_info.extension.event_id;
+    let holder = nft_info.extension.claimer;
+    let event_info = load_event(deps.storage, event_id)?;
 
-fn query_tokens(
-    deps: Deps<DesmosQuery>,
-    owner: String,
-    start_after: Option<String>,
-    limit: Option<u32>,
-) -> StdResult<TokensResponse> {
-    let cw721_address = CW721_ADDRESS.load(deps.storage)?;
-    deps.querier.query_wasm_smart(
-        cw721_address,
-        &Cw721PoapQueryMsg::Tokens {
-            owner,
-            start_after,
-            limit,
+    // Check that the sender is the event creator or the admin
+    if info.sender != event_info.creator && info.sender != config.admin {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    // Decrement the holder's mint count, removing it if it reaches zero
+    let holder_mint_count = MINTER_ADDRESS
+        .may_load(deps.storage, (event_id, holder.clone()))?
+        .unwrap_or_default()
+        .saturating_sub(1);
+    if holder_mint_count == 0 {
+        MINTER_ADDRESS.remove(deps.storage, (event_id, holder.clone()));
+    } else {
+        MINTER_ADDRESS.save(deps.storage, (event_id, holder.clone()), &holder_mint_count)?;
+    }
+    // Update the event statistics, the minted amount is kept so that
+    // the revoked poaps still count against the max supply
+    EVENT_STATS.update(deps.storage, event_id, |stats| -> StdResult<_> {
+        let mut stats = stats.unwrap_or_default();
+        stats.revoked += 1;
+        if holder_mint_count == 0 {
+            stats.unique_holders = stats.unique_holders.saturating_sub(1);
+        }
+        Ok(stats)
+    })?;
+
+    REVOCATIONS.save(
+        deps.storage,
+        &token_id,
+        &Revocation {
+            event_id,
+            holder: holder.clone(),
+            revoked_by: info.sender.clone(),
+            revoked_at: env.block.time,
+            reason: reason.clone(),
         },
+    )?;
+
+    let burn_msg = Cw721ExecuteMsg::<Metadata, Empty>::Burn {
+        token_id: token_id.clone(),
+    };
+
+    Ok(Response::new()
+        .add_attribute(ATTRIBUTE_ACTION, ACTION_REVOKE)
+        .add_attribute(ATTRIBUTE_SENDER, &info.sender)
+        .add_attribute(ATTRIBUTE_EVENT_ID, event_id.to_string())
+        .add_attribute("token_id", &token_id)
+        .add_event(
+            Event::new(EVENT_POAP_REVOKED)
+                .add_attribute(ATTRIBUTE_EVENT_ID, event_id.to_string())
+                .add_attribute("token_id", token_id)
+                .add_attribute("holder", holder)
+                .add_attribute("revoked_by", info.sender)
+                .add_attribute("reason", reason),
+        )
+        .add_message(wasm_execute(cw721_address, &burn_msg, vec![])?))
+}
+
+/// Builds the attestation that the attestor must sign to allow `recipient`
+/// to mint a poap of an event with [`ExecuteMsg::MintWithSignature`].
+/// The attestor signs the sha256 hash of the returned message.
+pub fn attestation_message(
+    contract: &Addr,
+    event_id: u64,
+    recipient: &Addr,
+    expiration: &Timestamp,
+    nonce: u64,
+) -> String {
+    format!(
+        "{}/{}/{}/{}/{}",
+        contract,
+        event_id,
+        recipient,
+        expiration.nanos(),
+        nonce
     )
 }
 
-// Reply callback triggered from cw721 contract instantiation
-#[cfg_attr(not(feature = "library"), entry_point)]
-pub fn reply(
+fn execute_mint_with_signature(
     deps: DepsMut<DesmosQuery>,
-    _env: Env,
-    msg: Reply,
+    env: Env,
+    info: MessageInfo,
+    event_id: u64,
+    signature: Binary,
+    expiration: Timestamp,
+    nonce: u64,
 ) -> Result<Response<DesmosMsg>, ContractError> {
-    if msg.id != INSTANTIATE_CW721_REPLY_ID {
-        return Err(ContractError::InvalidReplyID {});
+    let config = CONFIG.load(deps.storage)?;
+    let attestor_pubkey = config
+        .attestor_pubkey
+        .ok_or(ContractError::AttestorNotSet {})?;
+
+    // Check that the attestation is not expired
+    if env.block.time >= expiration {
+        return Err(ContractError::SignatureExpired {
+            current_time: env.block.time,
+            expiration,
+        });
     }
 
-    let reply = parse_reply_instantiate_data(msg);
-    match reply {
-        Ok(res) => {
-            CW721_ADDRESS.save(deps.storage, &Addr::unchecked(res.contract_address))?;
-            Ok(Response::default().add_attribute(ATTRIBUTE_ACTION, "instantiate_cw721_reply"))
-        }
-        Err(_) => Err(ContractError::InstantiateCw721Error {}),
+    // Check that the nonce has not been used before
+    if USED_NONCES.has(deps.storage, nonce) {
+        return Err(ContractError::NonceAlreadyUsed { nonce });
+    }
+
+    // Check that the attestation has been signed by the attestor
+    let message = attestation_message(
+        &env.contract.address,
+        event_id,
+        &info.sender,
+        &expiration,
+        nonce,
+    );
+    let message_hash = Sha256::digest(message.as_bytes());
+    let verified = deps
+        .api
+        .secp256k1_verify(&message_hash, &signature, &attestor_pubkey)
+        .map_err(StdError::from)?;
+    if !verified {
+        return Err(ContractError::InvalidSignature {});
     }
+
+    USED_NONCES.save(deps.storage, nonce, &Empty {})?;
+
+    let recipient_addr = info.sender.clone();
+    let response = execute_mint(
+        deps,
+        env,
+        info,
+        ACTION_MINT_WITH_SIGNATURE,
+        event_id,
+        recipient_addr,
+        None,
+        true,
+        false,
+    )?;
+    Ok(response.add_attribute("nonce", nonce.to_string()))
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use crate::test_utils::{
-        get_valid_init_msg, EVENT_END_SECONDS, EVENT_START_SECONDS, INITIAL_BLOCK_TIME_SECONDS,
-    };
-    use crate::ContractError::Unauthorized;
-    use cosmwasm_std::testing::{mock_env, mock_info};
-    use cosmwasm_std::{DepsMut, Timestamp};
-    use desmos_bindings::mocks::mock_queriers::mock_desmos_dependencies;
+fn execute_update_attestor(
+    deps: DepsMut<DesmosQuery>,
+    info: MessageInfo,
+    attestor_pubkey: Option<Binary>,
+) -> Result<Response<DesmosMsg>, ContractError> {
+    let mut config = CONFIG.load(deps.storage)?;
 
-    const CREATOR: &str = "creator";
-    const ADMIN: &str = "admin";
-    const MINTER: &str = "minter";
-    const USER: &str = "user";
-    const FAKE_CW721_ADDRESS: &str = "cw721-contract";
+    // Check that the sender is the admin
+    if info.sender != config.admin {
+        return Err(ContractError::Unauthorized {});
+    }
 
-    fn do_instantiate(deps: DepsMut<DesmosQuery>) {
-        let mut env = mock_env();
-        let info = mock_info(CREATOR, &vec![]);
+    // Update the attestor public key.
+    config.attestor_pubkey = attestor_pubkey;
+    CONFIG.save(deps.storage, &config)?;
 
-        // Change block time to event start.
-        env.block.time = Timestamp::from_seconds(INITIAL_BLOCK_TIME_SECONDS);
+    Ok(Response::new()
+        .add_attribute(ATTRIBUTE_ACTION, ACTION_UPDATE_ATTESTOR)
+        .add_attribute(
+            "new_attestor_pubkey",
+            config
+                .attestor_pubkey
+                .map(|pubkey| pubkey.to_base64())
+                .unwrap_or_default(),
+        ))
+}
 
-        // Since replay is not called, fake the stored cw721 contract address.
-        CW721_ADDRESS
-            .save(deps.storage, &Addr::unchecked(FAKE_CW721_ADDRESS))
-            .unwrap();
+fn execute_update_admin(
+    deps: DepsMut<DesmosQuery>,
+    info: MessageInfo,
+    admin_address: String,
+) -> Result<Response<DesmosMsg>, ContractError> {
+    let mut config = CONFIG.load(deps.storage)?;
 
-        let msg = get_valid_init_msg(1);
-        assert!(instantiate(deps, env, info, msg).is_ok());
+    // Check that the sender is the admin
+    if info.sender != config.admin {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    // Update the admin address.
+    let new_admin = deps.api.addr_validate(&admin_address)?;
+    config.admin = new_admin;
+    CONFIG.save(deps.storage, &config)?;
+
+    Ok(Response::new()
+        .add_attribute(ATTRIBUTE_ACTION, ACTION_UPDATE_ADMIN)
+        .add_attribute("new_admin", &admin_address))
+}
+
+fn execute_update_minter(
+    deps: DepsMut<DesmosQuery>,
+    info: MessageInfo,
+    minter_address: String,
+) -> Result<Response<DesmosMsg>, ContractError> {
+    let mut config = CONFIG.load(deps.storage)?;
+
+    // Check that the sender is the admin
+    if info.sender != config.admin {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    // Update the minter address.
+    let new_minter = deps.api.addr_validate(&minter_address)?;
+    config.minter = new_minter;
+    CONFIG.save(deps.storage, &config)?;
+
+    Ok(Response::new()
+        .add_attribute(ATTRIBUTE_ACTION, ACTION_UPDATE_MINTER)
+        .add_attribute("new_minter", &minter_address))
+}
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn query(deps: Deps<DesmosQuery>, env: Env, msg: QueryMsg) -> StdResult<Binary> {
+    match msg {
+        QueryMsg::Config {} => to_binary(&query_config(deps)?),
+        QueryMsg::EventInfo { event_id } => to_binary(&query_event_info(deps, event_id.u64())?),
+        QueryMsg::Events { start_after, limit } => {
+            to_binary(&query_events(deps, start_after.map(|id| id.u64()), limit)?)
+        }
+        QueryMsg::ClaimCodes { event_id } => to_binary(&query_claim_codes(deps, event_id.u64())?),
+        QueryMsg::Stats { event_id } => to_binary(&query_stats(deps, event_id.u64())?),
+        QueryMsg::Status { event_id } => to_binary(&query_status(deps, env, event_id.u64())?),
+        QueryMsg::Proceeds { event_id } => to_binary(&query_proceeds(deps, event_id.u64())?),
+        QueryMsg::MintedAmount { event_id, user } => {
+            to_binary(&query_minted_amount(deps, event_id.u64(), user)?)
+        }
+        QueryMsg::MintedAmounts { event_id, users } => {
+            to_binary(&query_minted_amounts(deps, event_id.u64(), users)?)
+        }
+        QueryMsg::Holders {
+            event_id,
+            start_after,
+            limit,
+        } => to_binary(&query_holders(deps, event_id.u64(), start_after, limit)?),
+        QueryMsg::HasAttended { event_id, user } => {
+            to_binary(&query_has_attended(deps, event_id.u64(), user)?)
+        }
+        QueryMsg::Revocation { token_id } => to_binary(&query_revocation(deps, token_id)?),
+        QueryMsg::AllNftInfo {
+            token_id,
+            include_expired,
+        } => to_binary(&query_all_nft_info(deps, token_id, include_expired)?),
+        QueryMsg::Tokens {
+            owner,
+            start_after,
+            limit,
+        } => to_binary(&query_tokens(deps, owner, start_after, limit)?),
+    }
+}
+
+fn query_config(deps: Deps<DesmosQuery>) -> StdResult<QueryConfigResponse> {
+    let config = CONFIG.load(deps.storage)?;
+    let cw721_address = CW721_ADDRESS.load(deps.storage)?;
+
+    Ok(QueryConfigResponse {
+        admin: config.admin,
+        minter: config.minter,
+        attestor_pubkey: config.attestor_pubkey,
+        cw721_contract_code: config.cw721_code_id.into(),
+        cw721_contract: cw721_address,
+        status: config.status,
+    })
+}
+
+fn query_status(
+    deps: Deps<DesmosQuery>,
+    env: Env,
+    event_id: u64,
+) -> StdResult<QueryStatusResponse> {
+    let config = CONFIG.load(deps.storage)?;
+    let event_info = EVENTS.load(deps.storage, event_id)?;
+
+    let status = if config.status == ContractStatus::Initializing {
+        Status::Initializing
+    } else if event_info.is_closed(&env.block.time) {
+        Status::Closed
+    } else {
+        Status::Ready
+    };
+    Ok(QueryStatusResponse { status })
+}
+
+fn event_info_response(event_id: u64, event_info: EventInfo) -> QueryEventInfoResponse {
+    QueryEventInfoResponse {
+        event_id: event_id.into(),
+        creator: event_info.creator,
+        start_time: event_info.start_time,
+        end_time: event_info.end_time,
+        per_address_limit: event_info.per_address_limit,
+        poap_uri: event_info.poap_uri,
+        merkle_root: event_info.merkle_root,
+        max_supply: event_info.max_supply,
+        mint_price: event_info.mint_price,
+        tier_uris: event_info.tier_uris,
+        phases: event_info.phases,
+        mint_enabled: event_info.mint_enabled,
+    }
+}
+
+fn query_event_info(deps: Deps<DesmosQuery>, event_id: u64) -> StdResult<QueryEventInfoResponse> {
+    let event_info = EVENTS.load(deps.storage, event_id)?;
+    Ok(event_info_response(event_id, event_info))
+}
+
+fn query_events(
+    deps: Deps<DesmosQuery>,
+    start_after: Option<u64>,
+    limit: Option<u32>,
+) -> StdResult<QueryEventsResponse> {
+    let limit = limit.unwrap_or(DEFAULT_QUERY_LIMIT).min(MAX_QUERY_LIMIT) as usize;
+    let events = EVENTS
+        .range(
+            deps.storage,
+            start_after.map(Bound::exclusive),
+            None,
+            Order::Ascending,
+        )
+        .take(limit)
+        .map(|item| item.map(|(event_id, event_info)| event_info_response(event_id, event_info)))
+        .collect::<StdResult<Vec<_>>>()?;
+    Ok(QueryEventsResponse { events })
+}
+
+fn query_claim_codes(deps: Deps<DesmosQuery>, event_id: u64) -> StdResult<QueryClaimCodesResponse> {
+    let claim_codes_info = CLAIM_CODES_INFO
+        .may_load(deps.storage, event_id)?
+        .unwrap_or_default();
+
+    Ok(QueryClaimCodesResponse {
+        total_codes: claim_codes_info.total,
+        remaining_codes: claim_codes_info.total - claim_codes_info.used,
+        secret_enabled: claim_codes_info.secret_hash.is_some(),
+    })
+}
+
+fn query_stats(deps: Deps<DesmosQuery>, event_id: u64) -> StdResult<QueryStatsResponse> {
+    let event_info = EVENTS.load(deps.storage, event_id)?;
+    let event_stats = EVENT_STATS
+        .may_load(deps.storage, event_id)?
+        .unwrap_or_default();
+
+    Ok(QueryStatsResponse {
+        minted: event_stats.minted,
+        unique_holders: event_stats.unique_holders,
+        revoked: event_stats.revoked,
+        remaining_supply: event_info
+            .max_supply
+            .map(|max_supply| max_supply.saturating_sub(event_stats.minted)),
+    })
+}
+
+fn query_proceeds(deps: Deps<DesmosQuery>, event_id: u64) -> StdResult<QueryProceedsResponse> {
+    Ok(QueryProceedsResponse {
+        amount: EVENT_PROCEEDS
+            .may_load(deps.storage, event_id)?
+            .unwrap_or_default(),
+    })
+}
+
+fn query_minted_amount(
+    deps: Deps<DesmosQuery>,
+    event_id: u64,
+    user: String,
+) -> StdResult<QueryMintedAmountResponse> {
+    let user_addr = deps.api.addr_validate(&user)?;
+
+    let minted_amount = MINTER_ADDRESS
+        .may_load(deps.storage, (event_id, user_addr.clone()))?
+        .unwrap_or(0);
+
+    Ok(QueryMintedAmountResponse {
+        user: user_addr,
+        amount: minted_amount,
+    })
+}
+
+fn query_minted_amounts(
+    deps: Deps<DesmosQuery>,
+    event_id: u64,
+    users: Vec<String>,
+) -> StdResult<QueryMintedAmountsResponse> {
+    let amounts = users
+        .into_iter()
+        .map(|user| query_minted_amount(deps, event_id, user))
+        .collect::<StdResult<Vec<_>>>()?;
+    Ok(QueryMintedAmountsResponse { amounts })
+}
+
+fn query_holders(
+    deps: Deps<DesmosQuery>,
+    event_id: u64,
+    start_after: Option<String>,
+    limit: Option<u32>,
+) -> StdResult<QueryHoldersResponse> {
+    let start_after = start_after
+        .map(|addr| deps.api.addr_validate(&addr))
+        .transpose()?;
+    let limit = limit.unwrap_or(DEFAULT_QUERY_LIMIT).min(MAX_QUERY_LIMIT) as usize;
+    let holders = MINTER_ADDRESS
+        .prefix(event_id)
+        .range(
+            deps.storage,
+            start_after.map(Bound::exclusive),
+            None,
+            Order::Ascending,
+        )
+        .take(limit)
+        .map(|item| item.map(|(user, amount)| QueryMintedAmountResponse { user, amount }))
+        .collect::<StdResult<Vec<_>>>()?;
+    Ok(QueryHoldersResponse { holders })
+}
+
+fn query_has_attended(
+    deps: Deps<DesmosQuery>,
+    event_id: u64,
+    user: String,
+) -> StdResult<QueryHasAttendedResponse> {
+    let minted_amount = query_minted_amount(deps, event_id, user)?;
+    Ok(QueryHasAttendedResponse {
+        attended: minted_amount.amount > 0,
+        amount: minted_amount.amount,
+    })
+}
+
+fn query_revocation(
+    deps: Deps<DesmosQuery>,
+    token_id: String,
+) -> StdResult<QueryRevocationResponse> {
+    let revocation = REVOCATIONS.load(deps.storage, &token_id)?;
+    Ok(QueryRevocationResponse {
+        token_id,
+        event_id: revocation.event_id.into(),
+        holder: revocation.holder,
+        revoked_by: revocation.revoked_by,
+        revoked_at: revocation.revoked_at,
+        reason: revocation.reason,
+    })
+}
+
+fn query_all_nft_info(
+    deps: Deps<DesmosQuery>,
+    token_id: String,
+    include_expired: Option<bool>,
+) -> StdResult<AllNftInfoResponse<Metadata>> {
+    let cw721_address = CW721_ADDRESS.load(deps.storage)?;
+    deps.querier.query_wasm_smart(
+        cw721_address,
+        &Cw721PoapQueryMsg::AllNftInfo {
+            token_id,
+            include_expired,
+        },
+    )
+}
+
+fn query_tokens(
+    deps: Deps<DesmosQuery>,
+    owner: String,
+    start_after: Option<String>,
+    limit: Option<u32>,
+) -> StdResult<TokensResponse> {
+    let cw721_address = CW721_ADDRESS.load(deps.storage)?;
+    deps.querier.query_wasm_smart(
+        cw721_address,
+        &Cw721PoapQueryMsg::Tokens {
+            owner,
+            start_after,
+            limit,
+        },
+    )
+}
+
+// Reply callback triggered from cw721 contract instantiation
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn reply(
+    deps: DepsMut<DesmosQuery>,
+    _env: Env,
+    msg: Reply,
+) -> Result<Response<DesmosMsg>, ContractError> {
+    if msg.id != INSTANTIATE_CW721_REPLY_ID {
+        return Err(ContractError::InvalidReplyID {});
+    }
+
+    let reply = parse_reply_instantiate_data(msg);
+    match reply {
+        Ok(res) => {
+            CW721_ADDRESS.save(deps.storage, &Addr::unchecked(res.contract_address))?;
+            // The cw721 contract is wired, the contract can now be used
+            CONFIG.update(deps.storage, |mut config| -> StdResult<_> {
+                config.status = ContractStatus::Ready;
+                Ok(config)
+            })?;
+            Ok(Response::default().add_attribute(ATTRIBUTE_ACTION, "instantiate_cw721_reply"))
+        }
+        Err(_) => Err(ContractError::InstantiateCw721Error {}),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::merkle::{build_tree, tree_proof, tree_root};
+    use crate::msg::{MintPhase, TierUri};
+    use crate::test_utils::{
+        get_valid_init_msg, EVENT_END_SECONDS, EVENT_START_SECONDS, INITIAL_BLOCK_TIME_SECONDS,
+    };
+    use crate::ContractError::Unauthorized;
+    use cosmwasm_std::testing::{mock_env, mock_info};
+    use cosmwasm_std::{Attribute, DepsMut, Timestamp, Uint64};
+    use desmos_bindings::mocks::mock_queriers::mock_desmos_dependencies;
+    use k256::ecdsa::{signature::Signer, Signature, SigningKey};
+
+    const CREATOR: &str = "creator";
+    const ADMIN: &str = "admin";
+    const MINTER: &str = "minter";
+    const USER: &str = "user";
+    const FAKE_CW721_ADDRESS: &str = "cw721-contract";
+    const EVENT_ID: u64 = 1;
+
+    fn do_instantiate(mut deps: DepsMut<DesmosQuery>) {
+        let mut env = mock_env();
+        let info = mock_info(CREATOR, &vec![]);
+
+        // Change block time to event start.
+        env.block.time = Timestamp::from_seconds(INITIAL_BLOCK_TIME_SECONDS);
+
+        // Since replay is not called, fake the stored cw721 contract address.
+        CW721_ADDRESS
+            .save(deps.storage, &Addr::unchecked(FAKE_CW721_ADDRESS))
+            .unwrap();
+
+        let msg = get_valid_init_msg(1);
+        assert!(instantiate(deps.branch(), env, info, msg).is_ok());
+
+        // Since replay is not called, fake the ready status.
+        CONFIG
+            .update(deps.storage, |mut config| -> StdResult<_> {
+                config.status = ContractStatus::Ready;
+                Ok(config)
+            })
+            .unwrap();
+    }
+
+    #[test]
+    fn instantiate_with_invalid_admin_addr_error() {
+        let mut deps = mock_desmos_dependencies();
+        let env = mock_env();
+        let info = mock_info(ADMIN, &vec![]);
+
+        let mut init_msg = get_valid_init_msg(1);
+        init_msg.admin = "a".to_string();
+
+        let result = instantiate(deps.as_mut(), env, info, init_msg);
+        assert!(result.is_err())
+    }
+
+    #[test]
+    fn instantiate_with_invalid_minter_addr_error() {
+        let mut deps = mock_desmos_dependencies();
+        let env = mock_env();
+        let info = mock_info(ADMIN, &vec![]);
+
+        let mut init_msg = get_valid_init_msg(1);
+        init_msg.minter = "a".to_string();
+
+        let result = instantiate(deps.as_mut(), env, info, init_msg);
+        assert!(result.is_err())
+    }
+
+    #[test]
+    fn instantiate_with_invalid_creator_addr_error() {
+        let mut deps = mock_desmos_dependencies();
+        let env = mock_env();
+        let info = mock_info(ADMIN, &vec![]);
+
+        let mut init_msg = get_valid_init_msg(1);
+        init_msg.event_info.creator = "a".to_string();
+
+        let result = instantiate(deps.as_mut(), env, info, init_msg);
+        assert!(result.is_err())
+    }
+
+    #[test]
+    fn instantiate_with_event_start_before_current_time_error() {
+        let mut deps = mock_desmos_dependencies();
+        let env = mock_env();
+        let info = mock_info(ADMIN, &vec![]);
+
+        let mut init_msg = get_valid_init_msg(1);
+        // Create a start time 200 seconds before the current block time
+        let start = &env.block.time.seconds() - 200;
+        init_msg.event_info.start_time = Timestamp::from_seconds(start);
+        init_msg.event_info.end_time = Timestamp::from_seconds(start + 600);
+
+        let init_result = instantiate(deps.as_mut(), env.clone(), info, init_msg);
+        assert_eq!(
+            ContractError::StartTimeBeforeCurrentTime {
+                current_time: env.block.time,
+                start_time: Timestamp::from_seconds(start)
+            },
+            init_result.unwrap_err()
+        );
+    }
+
+    #[test]
+    fn instantiate_with_event_start_equal_current_time_error() {
+        let mut deps = mock_desmos_dependencies();
+        let env = mock_env();
+        let info = mock_info(ADMIN, &vec![]);
+        let mut init_msg = get_valid_init_msg(1);
+
+        let start = env.block.time.nanos();
+        init_msg.event_info.start_time = Timestamp::from_nanos(start);
+        init_msg.event_info.end_time = Timestamp::from_nanos(start + 600);
+
+        let init_result = instantiate(deps.as_mut(), env, info, init_msg);
+        assert_eq!(
+            ContractError::StartTimeBeforeCurrentTime {
+                current_time: Timestamp::from_nanos(start),
+                start_time: Timestamp::from_nanos(start)
+            },
+            init_result.unwrap_err()
+        );
+    }
+
+    #[test]
+    fn instantiate_with_event_end_before_current_time_error() {
+        let mut deps = mock_desmos_dependencies();
+        let env = mock_env();
+        let info = mock_info(ADMIN, &vec![]);
+        let mut init_msg = get_valid_init_msg(1);
+
+        // Create a start time 200 seconds before the current block time
+        let start = env.block.time.seconds() - 200;
+        let end = env.block.time.seconds() - 100;
+        init_msg.event_info.start_time = Timestamp::from_seconds(start);
+        // Start time 100 seconds before the current block time
+        init_msg.event_info.end_time = Timestamp::from_seconds(end);
+
+        let init_result = instantiate(deps.as_mut(), env.clone(), info, init_msg);
+        assert_eq!(
+            ContractError::EndTimeBeforeCurrentTime {
+                current_time: env.block.time,
+                end_time: Timestamp::from_seconds(end)
+            },
+            init_result.unwrap_err()
+        );
+    }
+
+    #[test]
+    fn instantiate_with_event_end_equal_current_time_error() {
+        let mut deps = mock_desmos_dependencies();
+        let env = mock_env();
+        let info = mock_info(ADMIN, &vec![]);
+        let mut init_msg = get_valid_init_msg(1);
+
+        let start = env.block.time.seconds() - 200;
+        let end = env.block.time.nanos();
+        init_msg.event_info.start_time = Timestamp::from_seconds(start);
+        init_msg.event_info.end_time = Timestamp::from_nanos(end);
+
+        let init_result = instantiate(deps.as_mut(), env, info, init_msg);
+        assert_eq!(
+            ContractError::EndTimeBeforeCurrentTime {
+                current_time: Timestamp::from_nanos(end),
+                end_time: Timestamp::from_nanos(end),
+            },
+            init_result.unwrap_err()
+        );
+    }
+
+    #[test]
+    fn instantiate_with_event_start_after_end_error() {
+        let mut deps = mock_desmos_dependencies();
+        let env = mock_env();
+        let info = mock_info(ADMIN, &vec![]);
+        let mut init_msg = get_valid_init_msg(1);
+
+        // Create a start time 200 seconds after the current block time
+        let start = env.block.time.seconds() + 200;
+        let end = env.block.time.seconds() + 100;
+        init_msg.event_info.start_time = Timestamp::from_seconds(start);
+        // Start time 100 seconds before the event start time
+        init_msg.event_info.end_time = Timestamp::from_seconds(end);
+
+        let init_result = instantiate(deps.as_mut(), env, info, init_msg);
+        assert_eq!(
+            ContractError::StartTimeAfterEndTime {
+                start: Timestamp::from_seconds(start),
+                end: Timestamp::from_seconds(end),
+            },
+            init_result.unwrap_err()
+        );
+    }
+
+    #[test]
+    fn instantiate_with_event_start_equal_end_error() {
+        let mut deps = mock_desmos_dependencies();
+        let env = mock_env();
+        let info = mock_info(ADMIN, &vec![]);
+        let mut init_msg = get_valid_init_msg(1);
+
+        // Create a start time 200 seconds after the current block time
+        let start = env.block.time.seconds() + 200;
+        init_msg.event_info.start_time = Timestamp::from_seconds(start);
+        init_msg.event_info.end_time = Timestamp::from_seconds(start);
+
+        let init_result = instantiate(deps.as_mut(), env, info, init_msg);
+        assert_eq!(
+            ContractError::StartTimeAfterEndTime {
+                start: Timestamp::from_seconds(start),
+                end: Timestamp::from_seconds(start),
+            },
+            init_result.unwrap_err()
+        );
+    }
+
+    #[test]
+    fn instantiate_with_invalid_poap_uri_error() {
+        let mut deps = mock_desmos_dependencies();
+        let env = mock_env();
+        let info = mock_info(ADMIN, &vec![]);
+        let mut init_msg = get_valid_init_msg(1);
+
+        // Invalid uri
+        init_msg.event_info.poap_uri = "invalid_uri".to_string();
+
+        let init_result = instantiate(deps.as_mut(), env, info, init_msg);
+        assert!(init_result.is_err());
+    }
+
+    #[test]
+    fn instantiate_with_non_ipfs_poap_uri_error() {
+        let mut deps = mock_desmos_dependencies();
+        let env = mock_env();
+        let info = mock_info(ADMIN, &vec![]);
+        let mut init_msg = get_valid_init_msg(1);
+
+        init_msg.event_info.poap_uri = "https://random_domain.com".to_string();
+
+        let init_result = instantiate(deps.as_mut(), env, info, init_msg);
+        assert_eq!(ContractError::InvalidPoapUri {}, init_result.unwrap_err());
+    }
+
+    #[test]
+    fn enable_mint_properly() {
+        let mut deps = mock_desmos_dependencies();
+        let env = mock_env();
+        let info = mock_info(ADMIN, &vec![]);
+
+        do_instantiate(deps.as_mut());
+
+        let msg = ExecuteMsg::EnableMint {
+            event_id: EVENT_ID.into(),
+        };
+        execute(deps.as_mut(), env, info, msg).unwrap();
+
+        let event_info = EVENTS.load(&deps.storage, EVENT_ID).unwrap();
+        assert_eq!(true, event_info.mint_enabled);
+    }
+
+    #[test]
+    fn enable_mint_without_permission_error() {
+        let mut deps = mock_desmos_dependencies();
+        let env = mock_env();
+        let info = mock_info(USER, &vec![]);
+
+        do_instantiate(deps.as_mut());
+
+        let msg = ExecuteMsg::EnableMint {
+            event_id: EVENT_ID.into(),
+        };
+        let execute_result = execute(deps.as_mut(), env, info, msg);
+        assert_eq!(Unauthorized {}, execute_result.unwrap_err());
+    }
+
+    #[test]
+    fn disable_mint_properly() {
+        let mut deps = mock_desmos_dependencies();
+        let env = mock_env();
+        let info = mock_info(ADMIN, &vec![]);
+
+        do_instantiate(deps.as_mut());
+
+        let msg = ExecuteMsg::DisableMint {
+            event_id: EVENT_ID.into(),
+        };
+        execute(deps.as_mut(), env, info, msg).unwrap();
+
+        let event_info = EVENTS.load(&deps.storage, EVENT_ID).unwrap();
+        assert_eq!(false, event_info.mint_enabled);
+    }
+
+    #[test]
+    fn normal_user_can_not_disable_mint_error() {
+        let mut deps = mock_desmos_dependencies();
+        let env = mock_env();
+        let info = mock_info(USER, &vec![]);
+
+        do_instantiate(deps.as_mut());
+
+        let msg = ExecuteMsg::DisableMint {
+            event_id: EVENT_ID.into(),
+        };
+        let execute_result = execute(deps.as_mut(), env, info, msg);
+        assert_eq!(Unauthorized {}, execute_result.unwrap_err());
+    }
+
+    #[test]
+    fn enable_mint_only_for_one_event_properly() {
+        let mut deps = mock_desmos_dependencies();
+        let mut env = mock_env();
+        env.block.time = Timestamp::from_seconds(INITIAL_BLOCK_TIME_SECONDS);
+        let info = mock_info(ADMIN, &vec![]);
+
+        do_instantiate(deps.as_mut());
+
+        execute(
+            deps.as_mut(),
+            env.clone(),
+            info.clone(),
+            ExecuteMsg::CreateEvent {
+                event_info: second_event_info(),
+            },
+        )
+        .unwrap();
+        execute(
+            deps.as_mut(),
+            env.clone(),
+            info,
+            ExecuteMsg::EnableMint {
+                event_id: EVENT_ID.into(),
+            },
+        )
+        .unwrap();
+
+        // Change current time to the second event start
+        env.block.time = Timestamp::from_seconds(EVENT_END_SECONDS);
+
+        let result = execute(
+            deps.as_mut(),
+            env,
+            mock_info(USER, &vec![]),
+            ExecuteMsg::Mint {
+                event_id: (EVENT_ID + 1).into(),
+            },
+        );
+        assert_eq!(ContractError::MintDisabled {}, result.unwrap_err());
+    }
+
+    fn second_event_info() -> MsgEventInfo {
+        MsgEventInfo {
+            creator: CREATOR.to_string(),
+            start_time: Timestamp::from_seconds(EVENT_END_SECONDS),
+            end_time: Timestamp::from_seconds(EVENT_END_SECONDS + 3600),
+            per_address_limit: 1,
+            poap_uri: "ipfs://second-poap-uri".to_string(),
+            merkle_root: None,
+            max_supply: None,
+            mint_price: vec![],
+            tier_uris: vec![],
+            phases: vec![],
+        }
+    }
+
+    #[test]
+    fn create_event_without_permission_error() {
+        let mut deps = mock_desmos_dependencies();
+        let mut env = mock_env();
+        env.block.time = Timestamp::from_seconds(INITIAL_BLOCK_TIME_SECONDS);
+
+        do_instantiate(deps.as_mut());
+
+        let msg = ExecuteMsg::CreateEvent {
+            event_info: second_event_info(),
+        };
+        let result = execute(deps.as_mut(), env, mock_info(CREATOR, &vec![]), msg);
+        assert_eq!(Unauthorized {}, result.unwrap_err());
+    }
+
+    #[test]
+    fn create_event_with_start_before_current_time_error() {
+        let mut deps = mock_desmos_dependencies();
+        let mut env = mock_env();
+
+        do_instantiate(deps.as_mut());
+
+        // Fake current time to the second event start
+        env.block.time = Timestamp::from_seconds(EVENT_END_SECONDS);
+
+        let msg = ExecuteMsg::CreateEvent {
+            event_info: second_event_info(),
+        };
+        let result = execute(deps.as_mut(), env.clone(), mock_info(ADMIN, &vec![]), msg);
+        assert_eq!(
+            ContractError::StartTimeBeforeCurrentTime {
+                current_time: env.block.time,
+                start_time: Timestamp::from_seconds(EVENT_END_SECONDS),
+            },
+            result.unwrap_err()
+        );
+    }
+
+    #[test]
+    fn create_event_properly() {
+        let mut deps = mock_desmos_dependencies();
+        let mut env = mock_env();
+        env.block.time = Timestamp::from_seconds(INITIAL_BLOCK_TIME_SECONDS);
+
+        do_instantiate(deps.as_mut());
+
+        let msg = ExecuteMsg::CreateEvent {
+            event_info: second_event_info(),
+        };
+        execute(deps.as_mut(), env, mock_info(ADMIN, &vec![]), msg).unwrap();
+
+        let event_info = EVENTS.load(&deps.storage, EVENT_ID + 1).unwrap();
+        assert_eq!(
+            EventInfo {
+                creator: Addr::unchecked(CREATOR),
+                start_time: Timestamp::from_seconds(EVENT_END_SECONDS),
+                end_time: Timestamp::from_seconds(EVENT_END_SECONDS + 3600),
+                per_address_limit: 1,
+                poap_uri: "ipfs://second-poap-uri".to_string(),
+                merkle_root: None,
+                max_supply: None,
+                mint_price: vec![],
+                tier_uris: vec![],
+                phases: vec![],
+                mint_enabled: false,
+                closed: false,
+            },
+            event_info
+        );
+        assert_eq!(EVENT_ID + 2, NEXT_EVENT_ID.load(&deps.storage).unwrap());
+    }
+
+    #[test]
+    fn query_events_properly() {
+        let mut deps = mock_desmos_dependencies();
+        let mut env = mock_env();
+        env.block.time = Timestamp::from_seconds(INITIAL_BLOCK_TIME_SECONDS);
+
+        do_instantiate(deps.as_mut());
+
+        let msg = ExecuteMsg::CreateEvent {
+            event_info: second_event_info(),
+        };
+        execute(deps.as_mut(), env.clone(), mock_info(ADMIN, &vec![]), msg).unwrap();
+
+        let response = query_events(deps.as_ref(), None, Some(1)).unwrap();
+        assert_eq!(1, response.events.len());
+        assert_eq!(Uint64::new(EVENT_ID), response.events[0].event_id);
+
+        let response = query_events(deps.as_ref(), Some(EVENT_ID), None).unwrap();
+        assert_eq!(1, response.events.len());
+        assert_eq!(Uint64::new(EVENT_ID + 1), response.events[0].event_id);
+        assert_eq!(1, response.events[0].per_address_limit);
+    }
+
+    #[test]
+    fn creator_change_event_info_properly() {
+        let mut deps = mock_desmos_dependencies();
+        let mut env = mock_env();
+        let info = mock_info(CREATOR, &vec![]);
+        let new_start_time = Timestamp::from_seconds(env.block.time.seconds() + 100);
+        let new_end_time = Timestamp::from_seconds(env.block.time.seconds() + 400);
+
+        do_instantiate(deps.as_mut());
+
+        env.block.time = Timestamp::from_seconds(INITIAL_BLOCK_TIME_SECONDS);
+
+        let msg = ExecuteMsg::UpdateEventInfo {
+            event_id: EVENT_ID.into(),
+            start_time: new_start_time.clone(),
+            end_time: new_end_time.clone(),
+        };
+
+        execute(deps.as_mut(), env, info, msg).unwrap();
+
+        let event_info = EVENTS.load(&deps.storage, EVENT_ID).unwrap();
+        assert_eq!(new_start_time, event_info.start_time);
+        assert_eq!(new_end_time, event_info.end_time)
+    }
+
+    #[test]
+    fn non_creator_change_event_info_error() {
+        let mut deps = mock_desmos_dependencies();
+        let env = mock_env();
+        let info = mock_info(USER, &vec![]);
+        let new_start_time = Timestamp::from_seconds(env.block.time.seconds() + 100);
+        let new_end_time = Timestamp::from_seconds(env.block.time.seconds() + 400);
+        let msg = ExecuteMsg::UpdateEventInfo {
+            event_id: EVENT_ID.into(),
+            start_time: new_start_time.clone(),
+            end_time: new_end_time.clone(),
+        };
+
+        do_instantiate(deps.as_mut());
+
+        let result = execute(deps.as_mut(), env, info, msg.clone());
+        // User should not be authorized to update the event info
+        assert_eq!(Unauthorized {}, result.unwrap_err());
+
+        let env = mock_env();
+        let info = mock_info(ADMIN, &vec![]);
+
+        let result = execute(deps.as_mut(), env, info, msg);
+        // Admin should not be authorized to update the event info
+        assert_eq!(Unauthorized {}, result.unwrap_err());
+    }
+
+    #[test]
+    fn event_info_update_after_event_started_error() {
+        let mut deps = mock_desmos_dependencies();
+        let mut env = mock_env();
+        let info = mock_info(CREATOR, &vec![]);
+
+        do_instantiate(deps.as_mut());
+
+        let msg = ExecuteMsg::UpdateEventInfo {
+            event_id: EVENT_ID.into(),
+            start_time: Timestamp::from_seconds(EVENT_START_SECONDS),
+            end_time: Timestamp::from_seconds(EVENT_END_SECONDS),
+        };
+
+        // Fake current time to event in progress
+        env.block.time = Timestamp::from_seconds(EVENT_START_SECONDS + 100);
+
+        let result = execute(deps.as_mut(), env.clone(), info.clone(), msg.clone());
+        assert_eq!(
+            ContractError::EventStarted {
+                start_time: Timestamp::from_seconds(EVENT_START_SECONDS),
+                current_time: env.block.time.clone(),
+            },
+            result.unwrap_err()
+        );
+
+        // Edge case current time is event start
+        env.block.time = Timestamp::from_seconds(EVENT_START_SECONDS);
+
+        let result = execute(deps.as_mut(), env.clone(), info.clone(), msg.clone());
+        assert_eq!(
+            ContractError::EventStarted {
+                start_time: Timestamp::from_seconds(EVENT_START_SECONDS),
+                current_time: env.block.time.clone(),
+            },
+            result.unwrap_err()
+        );
+    }
+
+    #[test]
+    fn event_info_update_after_event_terminated_error() {
+        let mut deps = mock_desmos_dependencies();
+        let mut env = mock_env();
+        let info = mock_info(CREATOR, &vec![]);
+
+        do_instantiate(deps.as_mut());
+
+        let msg = ExecuteMsg::UpdateEventInfo {
+            event_id: EVENT_ID.into(),
+            start_time: Timestamp::from_seconds(EVENT_START_SECONDS),
+            // Add 300 seconds to prevent end time to be already passed
+            end_time: Timestamp::from_seconds(EVENT_END_SECONDS + 300),
+        };
+
+        // Fake current time to event ended
+        env.block.time = Timestamp::from_seconds(EVENT_END_SECONDS + 100);
+
+        let result = execute(deps.as_mut(), env.clone(), info.clone(), msg.clone());
+        assert_eq!(
+            ContractError::EventTerminated {
+                end_time: Timestamp::from_seconds(EVENT_END_SECONDS),
+                current_time: env.block.time.clone(),
+            },
+            result.unwrap_err()
+        );
+
+        // Edge case current time is event end
+        env.block.time = Timestamp::from_seconds(EVENT_END_SECONDS);
+
+        let result = execute(deps.as_mut(), env.clone(), info.clone(), msg.clone());
+        assert_eq!(
+            ContractError::EventTerminated {
+                end_time: Timestamp::from_seconds(EVENT_END_SECONDS),
+                current_time: env.block.time.clone(),
+            },
+            result.unwrap_err()
+        );
+    }
+
+    #[test]
+    fn event_info_update_properly() {
+        let mut deps = mock_desmos_dependencies();
+        let mut env = mock_env();
+        let info = mock_info(CREATOR, &vec![]);
+
+        do_instantiate(deps.as_mut());
+
+        let msg = ExecuteMsg::UpdateEventInfo {
+            event_id: EVENT_ID.into(),
+            start_time: Timestamp::from_seconds(EVENT_START_SECONDS),
+            end_time: Timestamp::from_seconds(EVENT_END_SECONDS + 300),
+        };
+
+        // Current time is before event started
+        env.block.time = Timestamp::from_seconds(EVENT_START_SECONDS - 100);
+
+        execute(deps.as_mut(), env, info, msg).unwrap();
+    }
+
+    #[test]
+    fn event_info_start_time_equal_end_time_error() {
+        let mut deps = mock_desmos_dependencies();
+        let mut env = mock_env();
+        let info = mock_info(CREATOR, &vec![]);
+
+        do_instantiate(deps.as_mut());
+        env.block.time = Timestamp::from_seconds(INITIAL_BLOCK_TIME_SECONDS);
+
+        // Start time eq end time
+        let msg = ExecuteMsg::UpdateEventInfo {
+            event_id: EVENT_ID.into(),
+            start_time: Timestamp::from_seconds(EVENT_START_SECONDS),
+            end_time: Timestamp::from_seconds(EVENT_START_SECONDS),
+        };
+
+        let result = execute(deps.as_mut(), env.clone(), info.clone(), msg);
+        assert_eq!(
+            ContractError::StartTimeAfterEndTime {
+                start: Timestamp::from_seconds(EVENT_START_SECONDS),
+                end: Timestamp::from_seconds(EVENT_START_SECONDS)
+            },
+            result.unwrap_err()
+        );
+    }
+
+    #[test]
+    fn event_info_start_time_after_end_time_error() {
+        let mut deps = mock_desmos_dependencies();
+        let mut env = mock_env();
+        let info = mock_info(CREATOR, &vec![]);
+
+        do_instantiate(deps.as_mut());
+        env.block.time = Timestamp::from_seconds(INITIAL_BLOCK_TIME_SECONDS);
+
+        let msg = ExecuteMsg::UpdateEventInfo {
+            event_id: EVENT_ID.into(),
+            start_time: Timestamp::from_seconds(EVENT_START_SECONDS + 100),
+            end_time: Timestamp::from_seconds(EVENT_START_SECONDS),
+        };
+
+        let result = execute(deps.as_mut(), env.clone(), info.clone(), msg);
+        assert_eq!(
+            ContractError::StartTimeAfterEndTime {
+                start: Timestamp::from_seconds(EVENT_START_SECONDS + 100),
+                end: Timestamp::from_seconds(EVENT_START_SECONDS)
+            },
+            result.unwrap_err()
+        );
+    }
+
+    #[test]
+    fn event_info_start_time_before_current_time_error() {
+        let mut deps = mock_desmos_dependencies();
+        let mut env = mock_env();
+        let info = mock_info(CREATOR, &vec![]);
+
+        do_instantiate(deps.as_mut());
+        env.block.time = Timestamp::from_seconds(INITIAL_BLOCK_TIME_SECONDS);
+
+        let msg = ExecuteMsg::UpdateEventInfo {
+            event_id: EVENT_ID.into(),
+            start_time: Timestamp::from_seconds(INITIAL_BLOCK_TIME_SECONDS - 1),
+            end_time: Timestamp::from_seconds(EVENT_END_SECONDS),
+        };
+
+        let result = execute(deps.as_mut(), env.clone(), info.clone(), msg);
+        assert_eq!(
+            ContractError::StartTimeBeforeCurrentTime {
+                start_time: Timestamp::from_seconds(INITIAL_BLOCK_TIME_SECONDS - 1),
+                current_time: Timestamp::from_seconds(INITIAL_BLOCK_TIME_SECONDS),
+            },
+            result.unwrap_err()
+        );
+    }
+
+    #[test]
+    fn event_info_start_time_equal_current_time_error() {
+        let mut deps = mock_desmos_dependencies();
+        let mut env = mock_env();
+        let info = mock_info(CREATOR, &vec![]);
+
+        do_instantiate(deps.as_mut());
+        env.block.time = Timestamp::from_seconds(INITIAL_BLOCK_TIME_SECONDS);
+
+        let msg = ExecuteMsg::UpdateEventInfo {
+            event_id: EVENT_ID.into(),
+            start_time: Timestamp::from_seconds(INITIAL_BLOCK_TIME_SECONDS),
+            end_time: Timestamp::from_seconds(EVENT_END_SECONDS),
+        };
+
+        let result = execute(deps.as_mut(), env.clone(), info.clone(), msg);
+        assert_eq!(
+            ContractError::StartTimeBeforeCurrentTime {
+                start_time: Timestamp::from_seconds(INITIAL_BLOCK_TIME_SECONDS),
+                current_time: Timestamp::from_seconds(INITIAL_BLOCK_TIME_SECONDS),
+            },
+            result.unwrap_err()
+        );
+    }
+
+    #[test]
+    fn event_info_end_time_before_current_time_error() {
+        let mut deps = mock_desmos_dependencies();
+        let mut env = mock_env();
+        let info = mock_info(CREATOR, &vec![]);
+
+        do_instantiate(deps.as_mut());
+        env.block.time = Timestamp::from_seconds(INITIAL_BLOCK_TIME_SECONDS);
+
+        let msg = ExecuteMsg::UpdateEventInfo {
+            event_id: EVENT_ID.into(),
+            start_time: Timestamp::from_seconds(INITIAL_BLOCK_TIME_SECONDS + 2),
+            end_time: Timestamp::from_seconds(INITIAL_BLOCK_TIME_SECONDS - 1),
+        };
+
+        let result = execute(deps.as_mut(), env.clone(), info.clone(), msg);
+        assert_eq!(
+            ContractError::StartTimeAfterEndTime {
+                start: Timestamp::from_seconds(INITIAL_BLOCK_TIME_SECONDS + 2),
+                end: Timestamp::from_seconds(INITIAL_BLOCK_TIME_SECONDS - 1),
+            },
+            result.unwrap_err()
+        );
+    }
+
+    #[test]
+    fn event_info_end_time_equal_current_time_error() {
+        let mut deps = mock_desmos_dependencies();
+        let mut env = mock_env();
+        let info = mock_info(CREATOR, &vec![]);
+
+        do_instantiate(deps.as_mut());
+        env.block.time = Timestamp::from_seconds(INITIAL_BLOCK_TIME_SECONDS);
+
+        let msg = ExecuteMsg::UpdateEventInfo {
+            event_id: EVENT_ID.into(),
+            start_time: Timestamp::from_seconds(INITIAL_BLOCK_TIME_SECONDS + 2),
+            end_time: Timestamp::from_seconds(INITIAL_BLOCK_TIME_SECONDS),
+        };
+
+        let result = execute(deps.as_mut(), env.clone(), info.clone(), msg);
+        assert_eq!(
+            ContractError::StartTimeAfterEndTime {
+                start: Timestamp::from_seconds(INITIAL_BLOCK_TIME_SECONDS + 2),
+                end: Timestamp::from_seconds(INITIAL_BLOCK_TIME_SECONDS),
+            },
+            result.unwrap_err()
+        );
+    }
+
+    #[test]
+    fn update_admin_without_permission_error() {
+        let mut deps = mock_desmos_dependencies();
+        let env = mock_env();
+        const NEW_ADMIN: &str = "admin2";
+
+        do_instantiate(deps.as_mut());
+
+        let msg = ExecuteMsg::UpdateAdmin {
+            new_admin: NEW_ADMIN.to_string(),
+        };
+
+        let result = execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info(USER, &vec![]),
+            msg.clone(),
+        );
+        assert_eq!(ContractError::Unauthorized {}, result.unwrap_err());
+
+        let result = execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info(CREATOR, &vec![]),
+            msg.clone(),
+        );
+        assert_eq!(ContractError::Unauthorized {}, result.unwrap_err());
+    }
+
+    #[test]
+    fn update_admin_with_permission_properly() {
+        let mut deps = mock_desmos_dependencies();
+        let env = mock_env();
+        const NEW_ADMIN: &str = "admin2";
+
+        do_instantiate(deps.as_mut());
+
+        let msg = ExecuteMsg::UpdateAdmin {
+            new_admin: NEW_ADMIN.to_string(),
+        };
+
+        execute(deps.as_mut(), env.clone(), mock_info(ADMIN, &vec![]), msg).unwrap();
+
+        let config = CONFIG.load(&deps.storage).unwrap();
+        assert_eq!(NEW_ADMIN, config.admin.as_str());
+    }
+
+    #[test]
+    fn update_minter_permission_error() {
+        let mut deps = mock_desmos_dependencies();
+        let env = mock_env();
+        const NEW_MINTER: &str = "minter2";
+
+        do_instantiate(deps.as_mut());
+
+        let msg = ExecuteMsg::UpdateMinter {
+            new_minter: NEW_MINTER.to_string(),
+        };
+
+        let result = execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info(USER, &vec![]),
+            msg.clone(),
+        );
+        assert_eq!(ContractError::Unauthorized {}, result.unwrap_err());
+
+        let result = execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info(CREATOR, &vec![]),
+            msg.clone(),
+        );
+        assert_eq!(ContractError::Unauthorized {}, result.unwrap_err());
+
+        let result = execute(deps.as_mut(), env.clone(), mock_info(MINTER, &vec![]), msg);
+        assert_eq!(ContractError::Unauthorized {}, result.unwrap_err());
+    }
+
+    #[test]
+    fn update_minter_with_permission_properly() {
+        let mut deps = mock_desmos_dependencies();
+        let env = mock_env();
+        const NEW_MINTER: &str = "minter2";
+
+        do_instantiate(deps.as_mut());
+
+        let msg = ExecuteMsg::UpdateMinter {
+            new_minter: NEW_MINTER.to_string(),
+        };
+
+        execute(deps.as_mut(), env.clone(), mock_info(ADMIN, &vec![]), msg).unwrap();
+
+        let config = CONFIG.load(&deps.storage).unwrap();
+        assert_eq!(NEW_MINTER, config.minter.as_str());
+    }
+
+    #[test]
+    fn mint_with_event_not_started_error() {
+        let mut deps = mock_desmos_dependencies();
+        let mut env = mock_env();
+        let info = mock_info(ADMIN, &vec![]);
+
+        do_instantiate(deps.as_mut());
+
+        env.block.time = Timestamp::from_seconds(INITIAL_BLOCK_TIME_SECONDS);
+
+        // Enable mint since is disable by default.
+        let msg = ExecuteMsg::EnableMint {
+            event_id: EVENT_ID.into(),
+        };
+        execute(deps.as_mut(), env.clone(), info, msg).unwrap();
+
+        let msg = ExecuteMsg::Mint {
+            event_id: EVENT_ID.into(),
+        };
+        let info = mock_info(USER, &vec![]);
+        let result = execute(deps.as_mut(), env.clone(), info, msg);
+
+        // Event is not started
+        assert_eq!(
+            ContractError::EventNotStarted {
+                current_time: env.block.time,
+                start_time: Timestamp::from_seconds(EVENT_START_SECONDS),
+            },
+            result.unwrap_err()
+        );
+    }
+
+    #[test]
+    fn mint_with_event_terminated_error() {
+        let mut deps = mock_desmos_dependencies();
+        let mut env = mock_env();
+        let info = mock_info(ADMIN, &vec![]);
+
+        do_instantiate(deps.as_mut());
+
+        // Enable mint since is disable by default.
+        let msg = ExecuteMsg::EnableMint {
+            event_id: EVENT_ID.into(),
+        };
+        execute(deps.as_mut(), env.clone(), info, msg).unwrap();
+
+        env.block.time = Timestamp::from_seconds(EVENT_END_SECONDS);
+
+        let msg = ExecuteMsg::Mint {
+            event_id: EVENT_ID.into(),
+        };
+        let info = mock_info(USER, &vec![]);
+        let result = execute(deps.as_mut(), env.clone(), info, msg);
+
+        // Event is not started
+        assert_eq!(
+            ContractError::EventTerminated {
+                current_time: env.block.time,
+                end_time: Timestamp::from_seconds(EVENT_END_SECONDS),
+            },
+            result.unwrap_err()
+        );
+    }
+
+    #[test]
+    fn mint_without_permissions_error() {
+        let mut deps = mock_desmos_dependencies();
+        let mut env = mock_env();
+        let info = mock_info(USER, &vec![]);
+
+        do_instantiate(deps.as_mut());
+
+        // Change current time to event start
+        env.block.time = Timestamp::from_seconds(EVENT_START_SECONDS);
+
+        let msg = ExecuteMsg::Mint {
+            event_id: EVENT_ID.into(),
+        };
+        let result = execute(deps.as_mut(), env.clone(), info, msg);
+
+        // Event is not started
+        assert_eq!(ContractError::MintDisabled {}, result.unwrap_err());
+    }
+
+    #[test]
+    fn mint_out_of_max_amount_error() {
+        let mut deps = mock_desmos_dependencies();
+        let mut env = mock_env();
+        let info = mock_info(ADMIN, &vec![]);
+
+        do_instantiate(deps.as_mut());
+
+        // Change current time to event start
+        env.block.time = Timestamp::from_seconds(EVENT_START_SECONDS);
+
+        execute(
+            deps.as_mut(),
+            env.clone(),
+            info,
+            ExecuteMsg::EnableMint {
+                event_id: EVENT_ID.into(),
+            },
+        )
+        .unwrap();
+
+        let info = mock_info(USER, &vec![]);
+        // Mint the first poap
+        execute(
+            deps.as_mut(),
+            env.clone(),
+            info.clone(),
+            ExecuteMsg::Mint {
+                event_id: EVENT_ID.into(),
+            },
+        )
+        .unwrap();
+        // Mint the second poap
+        execute(
+            deps.as_mut(),
+            env.clone(),
+            info.clone(),
+            ExecuteMsg::Mint {
+                event_id: EVENT_ID.into(),
+            },
+        )
+        .unwrap();
+
+        let response = execute(
+            deps.as_mut(),
+            env.clone(),
+            info.clone(),
+            ExecuteMsg::Mint {
+                event_id: EVENT_ID.into(),
+            },
+        );
+        assert_eq!(
+            ContractError::MaxPerAddressLimitExceeded {
+                recipient_addr: USER.to_string()
+            },
+            response.unwrap_err()
+        );
+
+        // Ensure that mint to also fails when minting for the user
+        let info = mock_info(ADMIN, &vec![]);
+        let response = execute(
+            deps.as_mut(),
+            env.clone(),
+            info,
+            ExecuteMsg::MintTo {
+                event_id: EVENT_ID.into(),
+                recipient: USER.to_string(),
+                tier: None,
+            },
+        );
+
+        assert_eq!(
+            ContractError::MaxPerAddressLimitExceeded {
+                recipient_addr: USER.to_string()
+            },
+            response.unwrap_err()
+        );
+    }
+
+    #[test]
+    fn mint_to_out_of_max_amount_error() {
+        let mut deps = mock_desmos_dependencies();
+        let mut env = mock_env();
+        let info = mock_info(ADMIN, &vec![]);
+
+        do_instantiate(deps.as_mut());
+
+        // Change current time to event start
+        env.block.time = Timestamp::from_seconds(EVENT_START_SECONDS);
+
+        execute(
+            deps.as_mut(),
+            env.clone(),
+            info.clone(),
+            ExecuteMsg::EnableMint {
+                event_id: EVENT_ID.into(),
+            },
+        )
+        .unwrap();
+
+        // Mint the first poap
+        execute(
+            deps.as_mut(),
+            env.clone(),
+            info.clone(),
+            ExecuteMsg::MintTo {
+                event_id: EVENT_ID.into(),
+                recipient: USER.to_string(),
+                tier: None,
+            },
+        )
+        .unwrap();
+        // Mint the second and last allowed poap
+        execute(
+            deps.as_mut(),
+            env.clone(),
+            info.clone(),
+            ExecuteMsg::MintTo {
+                event_id: EVENT_ID.into(),
+                recipient: USER.to_string(),
+                tier: None,
+            },
+        )
+        .unwrap();
+
+        let response = execute(
+            deps.as_mut(),
+            env.clone(),
+            info.clone(),
+            ExecuteMsg::MintTo {
+                event_id: EVENT_ID.into(),
+                recipient: USER.to_string(),
+                tier: None,
+            },
+        );
+        // Should fail since the user have already received the max allowed poaps.
+        assert_eq!(
+            ContractError::MaxPerAddressLimitExceeded {
+                recipient_addr: USER.to_string()
+            },
+            response.unwrap_err()
+        );
+
+        // Test also with Mint from use
+        let info = mock_info(USER, &vec![]);
+        let response = execute(
+            deps.as_mut(),
+            env.clone(),
+            info,
+            ExecuteMsg::Mint {
+                event_id: EVENT_ID.into(),
+            },
+        );
+        assert_eq!(
+            ContractError::MaxPerAddressLimitExceeded {
+                recipient_addr: USER.to_string()
+            },
+            response.unwrap_err()
+        );
+    }
+
+    #[test]
+    fn mint_with_not_existing_event_error() {
+        let mut deps = mock_desmos_dependencies();
+        let mut env = mock_env();
+
+        do_instantiate(deps.as_mut());
+
+        // Change current time to event start
+        env.block.time = Timestamp::from_seconds(EVENT_START_SECONDS);
+
+        let response = execute(
+            deps.as_mut(),
+            env,
+            mock_info(ADMIN, &vec![]),
+            ExecuteMsg::MintTo {
+                event_id: (EVENT_ID + 1).into(),
+                recipient: USER.to_string(),
+                tier: None,
+            },
+        );
+        assert_eq!(
+            ContractError::EventNotFound {
+                event_id: EVENT_ID + 1
+            },
+            response.unwrap_err()
+        );
+    }
+
+    #[test]
+    fn mint_limit_tracked_per_event_properly() {
+        let mut deps = mock_desmos_dependencies();
+        let mut env = mock_env();
+        env.block.time = Timestamp::from_seconds(INITIAL_BLOCK_TIME_SECONDS);
+
+        do_instantiate(deps.as_mut());
+
+        // Create a second event that starts together with the first one
+        let mut event_info = second_event_info();
+        event_info.start_time = Timestamp::from_seconds(EVENT_START_SECONDS);
+        execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info(ADMIN, &vec![]),
+            ExecuteMsg::CreateEvent { event_info },
+        )
+        .unwrap();
+
+        // Change current time to event start
+        env.block.time = Timestamp::from_seconds(EVENT_START_SECONDS);
+
+        // Mint the only allowed poap of the second event
+        execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info(MINTER, &vec![]),
+            ExecuteMsg::MintTo {
+                event_id: (EVENT_ID + 1).into(),
+                recipient: USER.to_string(),
+                tier: None,
+            },
+        )
+        .unwrap();
+
+        let response = execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info(MINTER, &vec![]),
+            ExecuteMsg::MintTo {
+                event_id: (EVENT_ID + 1).into(),
+                recipient: USER.to_string(),
+                tier: None,
+            },
+        );
+        assert_eq!(
+            ContractError::MaxPerAddressLimitExceeded {
+                recipient_addr: USER.to_string()
+            },
+            response.unwrap_err()
+        );
+
+        // The first event limit is not affected
+        execute(
+            deps.as_mut(),
+            env,
+            mock_info(MINTER, &vec![]),
+            ExecuteMsg::MintTo {
+                event_id: EVENT_ID.into(),
+                recipient: USER.to_string(),
+                tier: None,
+            },
+        )
+        .unwrap();
+
+        let first_event_amount =
+            query_minted_amount(deps.as_ref(), EVENT_ID, USER.to_string()).unwrap();
+        assert_eq!(1, first_event_amount.amount);
+        let second_event_amount =
+            query_minted_amount(deps.as_ref(), EVENT_ID + 1, USER.to_string()).unwrap();
+        assert_eq!(1, second_event_amount.amount);
+    }
+
+    const CODE_1_HASH: &str = "51bd6639fed7c0b4826af6c06bfe4f4cce3aa7a8db3653978cd4d88ad0a18a8a";
+    const CODE_2_HASH: &str = "0caa88c257d7122268f6494539faedf6b1d213409ff0dd264107182f174ea14a";
+    const SECRET_HASH: &str = "2bb80d537b1da3e38bd30361aa855686bde0eacd7162fef6a25fe97bf527a25b";
+
+    fn add_claim_codes(deps: DepsMut<DesmosQuery>) {
+        let mut env = mock_env();
+        env.block.time = Timestamp::from_seconds(INITIAL_BLOCK_TIME_SECONDS);
+
+        let msg = ExecuteMsg::AddClaimCodes {
+            event_id: EVENT_ID.into(),
+            code_hashes: vec![CODE_1_HASH.to_string(), CODE_2_HASH.to_string()],
+        };
+        execute(deps, env, mock_info(CREATOR, &vec![]), msg).unwrap();
+    }
+
+    #[test]
+    fn add_claim_codes_without_permission_error() {
+        let mut deps = mock_desmos_dependencies();
+        let mut env = mock_env();
+        env.block.time = Timestamp::from_seconds(INITIAL_BLOCK_TIME_SECONDS);
+
+        do_instantiate(deps.as_mut());
+
+        let msg = ExecuteMsg::AddClaimCodes {
+            event_id: EVENT_ID.into(),
+            code_hashes: vec![CODE_1_HASH.to_string()],
+        };
+        let result = execute(deps.as_mut(), env, mock_info(ADMIN, &vec![]), msg);
+        assert_eq!(Unauthorized {}, result.unwrap_err());
+    }
+
+    #[test]
+    fn add_claim_codes_already_registered_error() {
+        let mut deps = mock_desmos_dependencies();
+        let mut env = mock_env();
+        env.block.time = Timestamp::from_seconds(INITIAL_BLOCK_TIME_SECONDS);
+
+        do_instantiate(deps.as_mut());
+        add_claim_codes(deps.as_mut());
+
+        let msg = ExecuteMsg::AddClaimCodes {
+            event_id: EVENT_ID.into(),
+            code_hashes: vec![CODE_1_HASH.to_string()],
+        };
+        let result = execute(deps.as_mut(), env, mock_info(CREATOR, &vec![]), msg);
+        assert_eq!(
+            ContractError::ClaimCodeAlreadyRegistered {
+                hash: CODE_1_HASH.to_string()
+            },
+            result.unwrap_err()
+        );
+    }
+
+    #[test]
+    fn mint_with_invalid_code_error() {
+        let mut deps = mock_desmos_dependencies();
+        let mut env = mock_env();
+
+        do_instantiate(deps.as_mut());
+        add_claim_codes(deps.as_mut());
+
+        // Change current time to event start
+        env.block.time = Timestamp::from_seconds(EVENT_START_SECONDS);
+
+        let msg = ExecuteMsg::MintWithCode {
+            event_id: EVENT_ID.into(),
+            code: "invalid-code".to_string(),
+        };
+        let result = execute(deps.as_mut(), env, mock_info(USER, &vec![]), msg);
+        assert_eq!(ContractError::InvalidClaimCode {}, result.unwrap_err());
+    }
+
+    #[test]
+    fn mint_with_code_properly() {
+        let mut deps = mock_desmos_dependencies();
+        let mut env = mock_env();
+
+        do_instantiate(deps.as_mut());
+        add_claim_codes(deps.as_mut());
+
+        // Change current time to event start
+        env.block.time = Timestamp::from_seconds(EVENT_START_SECONDS);
+
+        let msg = ExecuteMsg::MintWithCode {
+            event_id: EVENT_ID.into(),
+            code: "code-1".to_string(),
+        };
+        execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info(USER, &vec![]),
+            msg.clone(),
+        )
+        .unwrap();
+
+        let response = query_claim_codes(deps.as_ref(), EVENT_ID).unwrap();
+        assert_eq!(
+            QueryClaimCodesResponse {
+                total_codes: 2,
+                remaining_codes: 1,
+                secret_enabled: false,
+            },
+            response
+        );
+
+        // The same code can't be used twice
+        let result = execute(deps.as_mut(), env, mock_info(MINTER, &vec![]), msg);
+        assert_eq!(ContractError::ClaimCodeAlreadyUsed {}, result.unwrap_err());
+    }
+
+    #[test]
+    fn mint_with_shared_secret_properly() {
+        let mut deps = mock_desmos_dependencies();
+        let mut env = mock_env();
+        env.block.time = Timestamp::from_seconds(INITIAL_BLOCK_TIME_SECONDS);
+
+        do_instantiate(deps.as_mut());
+
+        let msg = ExecuteMsg::UpdateClaimSecret {
+            event_id: EVENT_ID.into(),
+            secret_hash: Some(SECRET_HASH.to_string()),
+        };
+        execute(deps.as_mut(), env.clone(), mock_info(CREATOR, &vec![]), msg).unwrap();
+
+        // Change current time to event start
+        env.block.time = Timestamp::from_seconds(EVENT_START_SECONDS);
+
+        // The shared secret can be used by multiple users
+        let msg = ExecuteMsg::MintWithCode {
+            event_id: EVENT_ID.into(),
+            code: "secret".to_string(),
+        };
+        execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info(USER, &vec![]),
+            msg.clone(),
+        )
+        .unwrap();
+        execute(deps.as_mut(), env, mock_info(MINTER, &vec![]), msg).unwrap();
+
+        let response = query_claim_codes(deps.as_ref(), EVENT_ID).unwrap();
+        assert!(response.secret_enabled);
+        assert_eq!(0, response.remaining_codes);
+    }
+
+    /// Sets the merkle root of the event allowing [`USER`] to mint a single poap
+    /// and [`MINTER`] to mint two poaps.
+    /// Returns the proofs of the two users.
+    fn set_merkle_root(deps: DepsMut<DesmosQuery>) -> (Vec<String>, Vec<String>) {
+        let mut env = mock_env();
+        env.block.time = Timestamp::from_seconds(INITIAL_BLOCK_TIME_SECONDS);
+
+        let tree = build_tree(vec![leaf_hash(USER, 1), leaf_hash(MINTER, 2)]);
+        let msg = ExecuteMsg::UpdateMerkleRoot {
+            event_id: EVENT_ID.into(),
+            merkle_root: tree_root(&tree),
+        };
+        execute(deps, env, mock_info(ADMIN, &vec![]), msg).unwrap();
+
+        (tree_proof(&tree, 0), tree_proof(&tree, 1))
+    }
+
+    #[test]
+    fn update_merkle_root_without_permission_error() {
+        let mut deps = mock_desmos_dependencies();
+        let mut env = mock_env();
+        env.block.time = Timestamp::from_seconds(INITIAL_BLOCK_TIME_SECONDS);
+
+        do_instantiate(deps.as_mut());
+
+        let msg = ExecuteMsg::UpdateMerkleRoot {
+            event_id: EVENT_ID.into(),
+            merkle_root: None,
+        };
+        let result = execute(deps.as_mut(), env, mock_info(CREATOR, &vec![]), msg);
+        assert_eq!(Unauthorized {}, result.unwrap_err());
+    }
+
+    #[test]
+    fn update_merkle_root_after_event_started_error() {
+        let mut deps = mock_desmos_dependencies();
+        let mut env = mock_env();
+
+        do_instantiate(deps.as_mut());
+
+        // Change current time to event start
+        env.block.time = Timestamp::from_seconds(EVENT_START_SECONDS);
+
+        let msg = ExecuteMsg::UpdateMerkleRoot {
+            event_id: EVENT_ID.into(),
+            merkle_root: None,
+        };
+        let result = execute(deps.as_mut(), env.clone(), mock_info(ADMIN, &vec![]), msg);
+        assert_eq!(
+            ContractError::EventStarted {
+                current_time: env.block.time,
+                start_time: Timestamp::from_seconds(EVENT_START_SECONDS),
+            },
+            result.unwrap_err()
+        );
+    }
+
+    #[test]
+    fn mint_with_proof_without_merkle_root_error() {
+        let mut deps = mock_desmos_dependencies();
+        let mut env = mock_env();
+
+        do_instantiate(deps.as_mut());
+
+        // Change current time to event start
+        env.block.time = Timestamp::from_seconds(EVENT_START_SECONDS);
+
+        let msg = ExecuteMsg::MintWithProof {
+            event_id: EVENT_ID.into(),
+            amount: 1,
+            proof: vec![],
+        };
+        let result = execute(deps.as_mut(), env, mock_info(USER, &vec![]), msg);
+        assert_eq!(
+            ContractError::MerkleRootNotSet { event_id: EVENT_ID },
+            result.unwrap_err()
+        );
+    }
+
+    #[test]
+    fn mint_with_invalid_proof_error() {
+        let mut deps = mock_desmos_dependencies();
+        let mut env = mock_env();
+
+        do_instantiate(deps.as_mut());
+        let (user_proof, _) = set_merkle_root(deps.as_mut());
+
+        // Change current time to event start
+        env.block.time = Timestamp::from_seconds(EVENT_START_SECONDS);
+
+        // The user is allowlisted for a single poap
+        let msg = ExecuteMsg::MintWithProof {
+            event_id: EVENT_ID.into(),
+            amount: 2,
+            proof: user_proof,
+        };
+        let result = execute(deps.as_mut(), env, mock_info(USER, &vec![]), msg);
+        assert_eq!(ContractError::InvalidMerkleProof {}, result.unwrap_err());
+    }
+
+    #[test]
+    fn mint_with_proof_properly() {
+        let mut deps = mock_desmos_dependencies();
+        let mut env = mock_env();
+
+        do_instantiate(deps.as_mut());
+        let (user_proof, minter_proof) = set_merkle_root(deps.as_mut());
+
+        // Change current time to event start
+        env.block.time = Timestamp::from_seconds(EVENT_START_SECONDS);
+
+        let msg = ExecuteMsg::MintWithProof {
+            event_id: EVENT_ID.into(),
+            amount: 1,
+            proof: user_proof,
+        };
+        execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info(USER, &vec![]),
+            msg.clone(),
+        )
+        .unwrap();
+
+        // The user can't mint more than the allowlisted amount
+        let result = execute(deps.as_mut(), env.clone(), mock_info(USER, &vec![]), msg);
+        assert_eq!(
+            ContractError::MaxPerAddressLimitExceeded {
+                recipient_addr: USER.to_string()
+            },
+            result.unwrap_err()
+        );
+
+        let msg = ExecuteMsg::MintWithProof {
+            event_id: EVENT_ID.into(),
+            amount: 2,
+            proof: minter_proof,
+        };
+        execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info(MINTER, &vec![]),
+            msg.clone(),
+        )
+        .unwrap();
+        execute(deps.as_mut(), env, mock_info(MINTER, &vec![]), msg).unwrap();
+    }
+
+    #[test]
+    fn mint_without_proof_with_merkle_root_error() {
+        let mut deps = mock_desmos_dependencies();
+        let mut env = mock_env();
+
+        do_instantiate(deps.as_mut());
+        set_merkle_root(deps.as_mut());
+
+        execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info(ADMIN, &vec![]),
+            ExecuteMsg::EnableMint {
+                event_id: EVENT_ID.into(),
+            },
+        )
+        .unwrap();
+
+        // Change current time to event start
+        env.block.time = Timestamp::from_seconds(EVENT_START_SECONDS);
+
+        let result = execute(
+            deps.as_mut(),
+            env,
+            mock_info(USER, &vec![]),
+            ExecuteMsg::Mint {
+                event_id: EVENT_ID.into(),
+            },
+        );
+        assert_eq!(
+            ContractError::MerkleProofRequired { event_id: EVENT_ID },
+            result.unwrap_err()
+        );
     }
 
-    #[test]
-    fn instantiate_with_invalid_admin_addr_error() {
-        let mut deps = mock_desmos_dependencies();
-        let env = mock_env();
-        let info = mock_info(ADMIN, &vec![]);
-
-        let mut init_msg = get_valid_init_msg(1);
-        init_msg.admin = "a".to_string();
+    /// Sets the attestor public key returning the key used to sign the attestations.
+    fn set_attestor(deps: DepsMut<DesmosQuery>) -> SigningKey {
+        let signing_key = SigningKey::from_bytes(&[1u8; 32]).unwrap();
+        let msg = ExecuteMsg::UpdateAttestor {
+            attestor_pubkey: Some(Binary::from(
+                signing_key
+                    .verifying_key()
+                    .to_encoded_point(true)
+                    .as_bytes(),
+            )),
+        };
+        execute(deps, mock_env(), mock_info(ADMIN, &vec![]), msg).unwrap();
+        signing_key
+    }
 
-        let result = instantiate(deps.as_mut(), env, info, init_msg);
-        assert!(result.is_err())
+    fn sign_attestation(
+        signing_key: &SigningKey,
+        env: &Env,
+        recipient: &str,
+        expiration: &Timestamp,
+        nonce: u64,
+    ) -> Binary {
+        let message = attestation_message(
+            &env.contract.address,
+            EVENT_ID,
+            &Addr::unchecked(recipient),
+            expiration,
+            nonce,
+        );
+        let signature: Signature = signing_key.sign(message.as_bytes());
+        Binary::from(signature.as_ref())
     }
 
     #[test]
-    fn instantiate_with_invalid_minter_addr_error() {
+    fn update_attestor_without_permission_error() {
         let mut deps = mock_desmos_dependencies();
-        let env = mock_env();
-        let info = mock_info(ADMIN, &vec![]);
 
-        let mut init_msg = get_valid_init_msg(1);
-        init_msg.minter = "a".to_string();
+        do_instantiate(deps.as_mut());
 
-        let result = instantiate(deps.as_mut(), env, info, init_msg);
-        assert!(result.is_err())
+        let msg = ExecuteMsg::UpdateAttestor {
+            attestor_pubkey: None,
+        };
+        let result = execute(deps.as_mut(), mock_env(), mock_info(MINTER, &vec![]), msg);
+        assert_eq!(Unauthorized {}, result.unwrap_err());
     }
 
     #[test]
-    fn instantiate_with_invalid_creator_addr_error() {
+    fn mint_with_signature_without_attestor_error() {
         let mut deps = mock_desmos_dependencies();
-        let env = mock_env();
-        let info = mock_info(ADMIN, &vec![]);
+        let mut env = mock_env();
 
-        let mut init_msg = get_valid_init_msg(1);
-        init_msg.event_info.creator = "a".to_string();
+        do_instantiate(deps.as_mut());
 
-        let result = instantiate(deps.as_mut(), env, info, init_msg);
-        assert!(result.is_err())
+        // Change current time to event start
+        env.block.time = Timestamp::from_seconds(EVENT_START_SECONDS);
+
+        let msg = ExecuteMsg::MintWithSignature {
+            event_id: EVENT_ID.into(),
+            signature: Binary::default(),
+            expiration: Timestamp::from_seconds(EVENT_END_SECONDS),
+            nonce: 1u64.into(),
+        };
+        let result = execute(deps.as_mut(), env, mock_info(USER, &vec![]), msg);
+        assert_eq!(ContractError::AttestorNotSet {}, result.unwrap_err());
     }
 
     #[test]
-    fn instantiate_with_event_start_before_current_time_error() {
+    fn mint_with_expired_signature_error() {
         let mut deps = mock_desmos_dependencies();
-        let env = mock_env();
-        let info = mock_info(ADMIN, &vec![]);
+        let mut env = mock_env();
 
-        let mut init_msg = get_valid_init_msg(1);
-        // Create a start time 200 seconds before the current block time
-        let start = &env.block.time.seconds() - 200;
-        init_msg.event_info.start_time = Timestamp::from_seconds(start);
-        init_msg.event_info.end_time = Timestamp::from_seconds(start + 600);
+        do_instantiate(deps.as_mut());
+        let signing_key = set_attestor(deps.as_mut());
 
-        let init_result = instantiate(deps.as_mut(), env.clone(), info, init_msg);
+        // Change current time to event start
+        env.block.time = Timestamp::from_seconds(EVENT_START_SECONDS);
+
+        let expiration = Timestamp::from_seconds(EVENT_START_SECONDS);
+        let msg = ExecuteMsg::MintWithSignature {
+            event_id: EVENT_ID.into(),
+            signature: sign_attestation(&signing_key, &env, USER, &expiration, 1),
+            expiration: expiration.clone(),
+            nonce: 1u64.into(),
+        };
+        let result = execute(deps.as_mut(), env.clone(), mock_info(USER, &vec![]), msg);
         assert_eq!(
-            ContractError::StartTimeBeforeCurrentTime {
+            ContractError::SignatureExpired {
                 current_time: env.block.time,
-                start_time: Timestamp::from_seconds(start)
+                expiration,
             },
-            init_result.unwrap_err()
+            result.unwrap_err()
         );
     }
 
     #[test]
-    fn instantiate_with_event_start_equal_current_time_error() {
+    fn mint_with_signature_of_other_user_error() {
         let mut deps = mock_desmos_dependencies();
-        let env = mock_env();
-        let info = mock_info(ADMIN, &vec![]);
-        let mut init_msg = get_valid_init_msg(1);
+        let mut env = mock_env();
 
-        let start = env.block.time.nanos();
-        init_msg.event_info.start_time = Timestamp::from_nanos(start);
-        init_msg.event_info.end_time = Timestamp::from_nanos(start + 600);
+        do_instantiate(deps.as_mut());
+        let signing_key = set_attestor(deps.as_mut());
 
-        let init_result = instantiate(deps.as_mut(), env, info, init_msg);
-        assert_eq!(
-            ContractError::StartTimeBeforeCurrentTime {
-                current_time: Timestamp::from_nanos(start),
-                start_time: Timestamp::from_nanos(start)
-            },
-            init_result.unwrap_err()
-        );
+        // Change current time to event start
+        env.block.time = Timestamp::from_seconds(EVENT_START_SECONDS);
+
+        let expiration = Timestamp::from_seconds(EVENT_END_SECONDS);
+        let msg = ExecuteMsg::MintWithSignature {
+            event_id: EVENT_ID.into(),
+            signature: sign_attestation(&signing_key, &env, USER, &expiration, 1),
+            expiration,
+            nonce: 1u64.into(),
+        };
+        // The attestation has been signed for another user
+        let result = execute(deps.as_mut(), env, mock_info(MINTER, &vec![]), msg);
+        assert_eq!(ContractError::InvalidSignature {}, result.unwrap_err());
     }
 
     #[test]
-    fn instantiate_with_event_end_before_current_time_error() {
+    fn mint_with_signature_properly() {
         let mut deps = mock_desmos_dependencies();
-        let env = mock_env();
-        let info = mock_info(ADMIN, &vec![]);
-        let mut init_msg = get_valid_init_msg(1);
+        let mut env = mock_env();
 
-        // Create a start time 200 seconds before the current block time
-        let start = env.block.time.seconds() - 200;
-        let end = env.block.time.seconds() - 100;
-        init_msg.event_info.start_time = Timestamp::from_seconds(start);
-        // Start time 100 seconds before the current block time
-        init_msg.event_info.end_time = Timestamp::from_seconds(end);
+        do_instantiate(deps.as_mut());
+        let signing_key = set_attestor(deps.as_mut());
 
-        let init_result = instantiate(deps.as_mut(), env.clone(), info, init_msg);
+        // Change current time to event start
+        env.block.time = Timestamp::from_seconds(EVENT_START_SECONDS);
+
+        let expiration = Timestamp::from_seconds(EVENT_END_SECONDS);
+        let msg = ExecuteMsg::MintWithSignature {
+            event_id: EVENT_ID.into(),
+            signature: sign_attestation(&signing_key, &env, USER, &expiration, 1),
+            expiration,
+            nonce: 1u64.into(),
+        };
+        execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info(USER, &vec![]),
+            msg.clone(),
+        )
+        .unwrap();
+
+        // The same nonce can't be used twice
+        let result = execute(deps.as_mut(), env, mock_info(USER, &vec![]), msg);
         assert_eq!(
-            ContractError::EndTimeBeforeCurrentTime {
-                current_time: env.block.time,
-                end_time: Timestamp::from_seconds(end)
-            },
-            init_result.unwrap_err()
+            ContractError::NonceAlreadyUsed { nonce: 1 },
+            result.unwrap_err()
         );
     }
 
-    #[test]
-    fn instantiate_with_event_end_equal_current_time_error() {
-        let mut deps = mock_desmos_dependencies();
-        let env = mock_env();
-        let info = mock_info(ADMIN, &vec![]);
-        let mut init_msg = get_valid_init_msg(1);
-
-        let start = env.block.time.seconds() - 200;
-        let end = env.block.time.nanos();
-        init_msg.event_info.start_time = Timestamp::from_seconds(start);
-        init_msg.event_info.end_time = Timestamp::from_nanos(end);
+    fn mint_to(
+        deps: DepsMut<DesmosQuery>,
+        recipient: &str,
+    ) -> Result<Response<DesmosMsg>, ContractError> {
+        let mut env = mock_env();
+        env.block.time = Timestamp::from_seconds(EVENT_START_SECONDS);
+        execute(
+            deps,
+            env,
+            mock_info(MINTER, &vec![]),
+            ExecuteMsg::MintTo {
+                event_id: EVENT_ID.into(),
+                recipient: recipient.to_string(),
+                tier: None,
+            },
+        )
+    }
 
-        let init_result = instantiate(deps.as_mut(), env, info, init_msg);
-        assert_eq!(
-            ContractError::EndTimeBeforeCurrentTime {
-                current_time: Timestamp::from_nanos(end),
-                end_time: Timestamp::from_nanos(end),
+    fn update_max_supply(
+        deps: DepsMut<DesmosQuery>,
+        max_supply: Option<u32>,
+    ) -> Result<Response<DesmosMsg>, ContractError> {
+        let mut env = mock_env();
+        env.block.time = Timestamp::from_seconds(EVENT_START_SECONDS);
+        execute(
+            deps,
+            env,
+            mock_info(CREATOR, &vec![]),
+            ExecuteMsg::UpdateMaxSupply {
+                event_id: EVENT_ID.into(),
+                max_supply,
             },
-            init_result.unwrap_err()
-        );
+        )
     }
 
     #[test]
-    fn instantiate_with_event_start_after_end_error() {
+    fn mint_with_max_supply_reached_error() {
         let mut deps = mock_desmos_dependencies();
-        let env = mock_env();
-        let info = mock_info(ADMIN, &vec![]);
-        let mut init_msg = get_valid_init_msg(1);
 
-        // Create a start time 200 seconds after the current block time
-        let start = env.block.time.seconds() + 200;
-        let end = env.block.time.seconds() + 100;
-        init_msg.event_info.start_time = Timestamp::from_seconds(start);
-        // Start time 100 seconds before the event start time
-        init_msg.event_info.end_time = Timestamp::from_seconds(end);
+        do_instantiate(deps.as_mut());
+        update_max_supply(deps.as_mut(), Some(2)).unwrap();
+
+        mint_to(deps.as_mut(), USER).unwrap();
+        mint_to(deps.as_mut(), ADMIN).unwrap();
 
-        let init_result = instantiate(deps.as_mut(), env, info, init_msg);
         assert_eq!(
-            ContractError::StartTimeAfterEndTime {
-                start: Timestamp::from_seconds(start),
-                end: Timestamp::from_seconds(end),
-            },
-            init_result.unwrap_err()
+            ContractError::MaxSupplyReached { event_id: EVENT_ID },
+            mint_to(deps.as_mut(), CREATOR).unwrap_err()
         );
     }
 
     #[test]
-    fn instantiate_with_event_start_equal_end_error() {
+    fn update_max_supply_below_minted_error() {
         let mut deps = mock_desmos_dependencies();
-        let env = mock_env();
-        let info = mock_info(ADMIN, &vec![]);
-        let mut init_msg = get_valid_init_msg(1);
 
-        // Create a start time 200 seconds after the current block time
-        let start = env.block.time.seconds() + 200;
-        init_msg.event_info.start_time = Timestamp::from_seconds(start);
-        init_msg.event_info.end_time = Timestamp::from_seconds(start);
+        do_instantiate(deps.as_mut());
+
+        mint_to(deps.as_mut(), USER).unwrap();
+        mint_to(deps.as_mut(), ADMIN).unwrap();
 
-        let init_result = instantiate(deps.as_mut(), env, info, init_msg);
         assert_eq!(
-            ContractError::StartTimeAfterEndTime {
-                start: Timestamp::from_seconds(start),
-                end: Timestamp::from_seconds(start),
+            ContractError::MaxSupplyBelowMinted {
+                max_supply: 1,
+                minted: 2
             },
-            init_result.unwrap_err()
+            update_max_supply(deps.as_mut(), Some(1)).unwrap_err()
         );
     }
 
     #[test]
-    fn instantiate_with_invalid_poap_uri_error() {
+    fn update_max_supply_without_permission_error() {
         let mut deps = mock_desmos_dependencies();
-        let env = mock_env();
-        let info = mock_info(ADMIN, &vec![]);
-        let mut init_msg = get_valid_init_msg(1);
 
-        // Invalid uri
-        init_msg.event_info.poap_uri = "invalid_uri".to_string();
+        do_instantiate(deps.as_mut());
 
-        let init_result = instantiate(deps.as_mut(), env, info, init_msg);
-        assert!(init_result.is_err());
+        let result = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info(ADMIN, &vec![]),
+            ExecuteMsg::UpdateMaxSupply {
+                event_id: EVENT_ID.into(),
+                max_supply: Some(10),
+            },
+        );
+        assert_eq!(Unauthorized {}, result.unwrap_err());
     }
 
     #[test]
-    fn instantiate_with_non_ipfs_poap_uri_error() {
+    fn query_stats_properly() {
         let mut deps = mock_desmos_dependencies();
-        let env = mock_env();
-        let info = mock_info(ADMIN, &vec![]);
-        let mut init_msg = get_valid_init_msg(1);
-
-        init_msg.event_info.poap_uri = "https://random_domain.com".to_string();
 
-        let init_result = instantiate(deps.as_mut(), env, info, init_msg);
-        assert_eq!(ContractError::InvalidPoapUri {}, init_result.unwrap_err());
-    }
+        do_instantiate(deps.as_mut());
+        update_max_supply(deps.as_mut(), Some(500)).unwrap();
 
-    #[test]
-    fn enable_mint_properly() {
-        let mut deps = mock_desmos_dependencies();
-        let env = mock_env();
-        let info = mock_info(ADMIN, &vec![]);
+        mint_to(deps.as_mut(), USER).unwrap();
+        mint_to(deps.as_mut(), USER).unwrap();
+        mint_to(deps.as_mut(), ADMIN).unwrap();
 
-        do_instantiate(deps.as_mut());
+        assert_eq!(
+            QueryStatsResponse {
+                minted: 3,
+                unique_holders: 2,
+                revoked: 0,
+                remaining_supply: Some(497),
+            },
+            query_stats(deps.as_ref(), EVENT_ID).unwrap()
+        );
+    }
 
-        let msg = ExecuteMsg::EnableMint {};
-        execute(deps.as_mut(), env, info, msg).unwrap();
+    fn set_mint_price(deps: DepsMut<DesmosQuery>, mint_price: Vec<Coin>) {
+        EVENTS
+            .update(deps.storage, EVENT_ID, |event_info| -> StdResult<_> {
+                let mut event_info = event_info.unwrap();
+                event_info.mint_price = mint_price;
+                Ok(event_info)
+            })
+            .unwrap();
+    }
 
-        let config = CONFIG.load(&deps.storage).unwrap();
-        assert_eq!(true, config.mint_enabled);
+    fn mint_to_with_funds(
+        deps: DepsMut<DesmosQuery>,
+        funds: &[Coin],
+    ) -> Result<Response<DesmosMsg>, ContractError> {
+        let mut env = mock_env();
+        env.block.time = Timestamp::from_seconds(EVENT_START_SECONDS);
+        execute(
+            deps,
+            env,
+            mock_info(MINTER, funds),
+            ExecuteMsg::MintTo {
+                event_id: EVENT_ID.into(),
+                recipient: USER.to_string(),
+                tier: None,
+            },
+        )
     }
 
     #[test]
-    fn enable_mint_without_permission_error() {
+    fn mint_without_paying_price_error() {
         let mut deps = mock_desmos_dependencies();
-        let env = mock_env();
-        let info = mock_info(USER, &vec![]);
 
         do_instantiate(deps.as_mut());
+        set_mint_price(deps.as_mut(), vec![Coin::new(100, "udsm")]);
 
-        let msg = ExecuteMsg::EnableMint {};
-        let execute_result = execute(deps.as_mut(), env, info, msg);
-        assert_eq!(Unauthorized {}, execute_result.unwrap_err());
+        assert_eq!(
+            ContractError::MintPriceNotPaid {
+                mint_price: "100udsm".to_string()
+            },
+            mint_to_with_funds(deps.as_mut(), &[Coin::new(50, "udsm")]).unwrap_err()
+        );
     }
 
     #[test]
-    fn disable_mint_properly() {
+    fn mint_with_price_refunds_excess_properly() {
         let mut deps = mock_desmos_dependencies();
-        let env = mock_env();
-        let info = mock_info(ADMIN, &vec![]);
 
         do_instantiate(deps.as_mut());
+        set_mint_price(deps.as_mut(), vec![Coin::new(100, "udsm")]);
 
-        let msg = ExecuteMsg::DisableMint {};
-        execute(deps.as_mut(), env, info, msg).unwrap();
+        let response = mint_to_with_funds(
+            deps.as_mut(),
+            &[Coin::new(150, "udsm"), Coin::new(10, "uatom")],
+        )
+        .unwrap();
 
-        let config = CONFIG.load(&deps.storage).unwrap();
-        assert_eq!(false, config.mint_enabled);
+        assert_eq!(
+            SubMsg::new(BankMsg::Send {
+                to_address: MINTER.to_string(),
+                amount: vec![Coin::new(50, "udsm"), Coin::new(10, "uatom")],
+            }),
+            response.messages[1]
+        );
+        assert_eq!(
+            QueryProceedsResponse {
+                amount: vec![Coin::new(100, "udsm")],
+            },
+            query_proceeds(deps.as_ref(), EVENT_ID).unwrap()
+        );
     }
 
     #[test]
-    fn normal_user_can_not_disable_mint_error() {
+    fn claim_funds_without_permission_error() {
         let mut deps = mock_desmos_dependencies();
-        let env = mock_env();
-        let info = mock_info(USER, &vec![]);
 
         do_instantiate(deps.as_mut());
 
-        let msg = ExecuteMsg::DisableMint {};
-        let execute_result = execute(deps.as_mut(), env, info, msg);
-        assert_eq!(Unauthorized {}, execute_result.unwrap_err());
+        let result = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info(USER, &vec![]),
+            ExecuteMsg::ClaimFunds {
+                event_id: EVENT_ID.into(),
+                receiver: USER.to_string(),
+            },
+        );
+        assert_eq!(Unauthorized {}, result.unwrap_err());
     }
 
     #[test]
-    fn creator_change_event_info_properly() {
+    fn claim_funds_without_proceeds_error() {
         let mut deps = mock_desmos_dependencies();
-        let mut env = mock_env();
-        let info = mock_info(CREATOR, &vec![]);
-        let new_start_time = Timestamp::from_seconds(env.block.time.seconds() + 100);
-        let new_end_time = Timestamp::from_seconds(env.block.time.seconds() + 400);
 
         do_instantiate(deps.as_mut());
 
-        env.block.time = Timestamp::from_seconds(INITIAL_BLOCK_TIME_SECONDS);
-
-        let msg = ExecuteMsg::UpdateEventInfo {
-            start_time: new_start_time.clone(),
-            end_time: new_end_time.clone(),
-        };
-
-        execute(deps.as_mut(), env, info, msg).unwrap();
-
-        let event_info = EVENT_INFO.load(&deps.storage).unwrap();
-        assert_eq!(new_start_time, event_info.start_time);
-        assert_eq!(new_end_time, event_info.end_time)
+        let result = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info(CREATOR, &vec![]),
+            ExecuteMsg::ClaimFunds {
+                event_id: EVENT_ID.into(),
+                receiver: CREATOR.to_string(),
+            },
+        );
+        assert_eq!(
+            ContractError::NoFundsToClaim { event_id: EVENT_ID },
+            result.unwrap_err()
+        );
     }
 
     #[test]
-    fn non_creator_change_event_info_error() {
+    fn claim_funds_properly() {
         let mut deps = mock_desmos_dependencies();
-        let env = mock_env();
-        let info = mock_info(USER, &vec![]);
-        let new_start_time = Timestamp::from_seconds(env.block.time.seconds() + 100);
-        let new_end_time = Timestamp::from_seconds(env.block.time.seconds() + 400);
-        let msg = ExecuteMsg::UpdateEventInfo {
-            start_time: new_start_time.clone(),
-            end_time: new_end_time.clone(),
-        };
 
         do_instantiate(deps.as_mut());
+        set_mint_price(deps.as_mut(), vec![Coin::new(100, "udsm")]);
 
-        let result = execute(deps.as_mut(), env, info, msg.clone());
-        // User should not be authorized to update the event info
-        assert_eq!(Unauthorized {}, result.unwrap_err());
+        mint_to_with_funds(deps.as_mut(), &[Coin::new(100, "udsm")]).unwrap();
+        mint_to_with_funds(deps.as_mut(), &[Coin::new(100, "udsm")]).unwrap();
 
-        let env = mock_env();
-        let info = mock_info(ADMIN, &vec![]);
+        let response = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info(CREATOR, &vec![]),
+            ExecuteMsg::ClaimFunds {
+                event_id: EVENT_ID.into(),
+                receiver: CREATOR.to_string(),
+            },
+        )
+        .unwrap();
 
-        let result = execute(deps.as_mut(), env, info, msg);
-        // Admin should not be authorized to update the event info
-        assert_eq!(Unauthorized {}, result.unwrap_err());
+        assert_eq!(
+            vec![SubMsg::new(BankMsg::Send {
+                to_address: CREATOR.to_string(),
+                amount: vec![Coin::new(200, "udsm")],
+            })],
+            response.messages
+        );
+        assert!(query_proceeds(deps.as_ref(), EVENT_ID)
+            .unwrap()
+            .amount
+            .is_empty());
     }
 
     #[test]
-    fn event_info_update_after_event_started_error() {
+    fn mint_to_with_tier_properly() {
         let mut deps = mock_desmos_dependencies();
         let mut env = mock_env();
-        let info = mock_info(CREATOR, &vec![]);
+        env.block.time = Timestamp::from_seconds(EVENT_START_SECONDS);
 
         do_instantiate(deps.as_mut());
+        EVENTS
+            .update(&mut deps.storage, EVENT_ID, |event_info| -> StdResult<_> {
+                let mut event_info = event_info.unwrap();
+                event_info.tier_uris = vec![TierUri {
+                    tier: "speaker".to_string(),
+                    uri: "ipfs://speaker-uri".to_string(),
+                }];
+                Ok(event_info)
+            })
+            .unwrap();
 
-        let msg = ExecuteMsg::UpdateEventInfo {
-            start_time: Timestamp::from_seconds(EVENT_START_SECONDS),
-            end_time: Timestamp::from_seconds(EVENT_END_SECONDS),
-        };
-
-        // Fake current time to event in progress
-        env.block.time = Timestamp::from_seconds(EVENT_START_SECONDS + 100);
-
-        let result = execute(deps.as_mut(), env.clone(), info.clone(), msg.clone());
-        assert_eq!(
-            ContractError::EventStarted {
-                start_time: Timestamp::from_seconds(EVENT_START_SECONDS),
-                current_time: env.block.time.clone(),
+        let response = execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info(MINTER, &vec![]),
+            ExecuteMsg::MintTo {
+                event_id: EVENT_ID.into(),
+                recipient: USER.to_string(),
+                tier: Some("speaker".to_string()),
             },
-            result.unwrap_err()
-        );
-
-        // Edge case current time is event start
-        env.block.time = Timestamp::from_seconds(EVENT_START_SECONDS);
+        )
+        .unwrap();
 
-        let result = execute(deps.as_mut(), env.clone(), info.clone(), msg.clone());
-        assert_eq!(
-            ContractError::EventStarted {
-                start_time: Timestamp::from_seconds(EVENT_START_SECONDS),
-                current_time: env.block.time.clone(),
+        let mint_msg = Cw721ExecuteMsg::<Metadata, Empty>::Mint(MintMsg::<Metadata> {
+            token_id: "1".to_string(),
+            owner: USER.to_string(),
+            token_uri: Some("ipfs://speaker-uri".to_string()),
+            extension: Metadata {
+                claimer: Addr::unchecked(USER),
+                event_id: EVENT_ID,
+                mint_time: env.block.time,
+                minter: Addr::unchecked(MINTER),
+                tier: Some("speaker".to_string()),
             },
-            result.unwrap_err()
+        });
+        assert_eq!(
+            SubMsg::new(wasm_execute(FAKE_CW721_ADDRESS, &mint_msg, vec![]).unwrap()),
+            response.messages[0]
         );
     }
 
     #[test]
-    fn event_info_update_after_event_terminated_error() {
+    fn mint_to_with_unknown_tier_error() {
         let mut deps = mock_desmos_dependencies();
         let mut env = mock_env();
-        let info = mock_info(CREATOR, &vec![]);
+        env.block.time = Timestamp::from_seconds(EVENT_START_SECONDS);
 
         do_instantiate(deps.as_mut());
+        EVENTS
+            .update(&mut deps.storage, EVENT_ID, |event_info| -> StdResult<_> {
+                let mut event_info = event_info.unwrap();
+                event_info.tier_uris = vec![TierUri {
+                    tier: "speaker".to_string(),
+                    uri: "ipfs://speaker-uri".to_string(),
+                }];
+                Ok(event_info)
+            })
+            .unwrap();
 
-        let msg = ExecuteMsg::UpdateEventInfo {
-            start_time: Timestamp::from_seconds(EVENT_START_SECONDS),
-            // Add 300 seconds to prevent end time to be already passed
-            end_time: Timestamp::from_seconds(EVENT_END_SECONDS + 300),
-        };
-
-        // Fake current time to event ended
-        env.block.time = Timestamp::from_seconds(EVENT_END_SECONDS + 100);
+        let error = execute(
+            deps.as_mut(),
+            env,
+            mock_info(MINTER, &vec![]),
+            ExecuteMsg::MintTo {
+                event_id: EVENT_ID.into(),
+                recipient: USER.to_string(),
+                tier: Some("speakr".to_string()),
+            },
+        )
+        .unwrap_err();
 
-        let result = execute(deps.as_mut(), env.clone(), info.clone(), msg.clone());
         assert_eq!(
-            ContractError::EventTerminated {
-                end_time: Timestamp::from_seconds(EVENT_END_SECONDS),
-                current_time: env.block.time.clone(),
+            ContractError::TierNotFound {
+                event_id: EVENT_ID,
+                tier: "speakr".to_string(),
             },
-            result.unwrap_err()
+            error
         );
+    }
 
-        // Edge case current time is event end
-        env.block.time = Timestamp::from_seconds(EVENT_END_SECONDS);
+    fn set_phases(deps: DepsMut<DesmosQuery>, phases: Vec<MintPhase>) {
+        EVENTS
+            .update(deps.storage, EVENT_ID, |event_info| -> StdResult<_> {
+                let mut event_info = event_info.unwrap();
+                event_info.phases = phases;
+                Ok(event_info)
+            })
+            .unwrap();
+    }
 
-        let result = execute(deps.as_mut(), env.clone(), info.clone(), msg.clone());
-        assert_eq!(
-            ContractError::EventTerminated {
-                end_time: Timestamp::from_seconds(EVENT_END_SECONDS),
-                current_time: env.block.time.clone(),
-            },
-            result.unwrap_err()
-        );
+    fn mint_phase(start: u64, end: u64, per_address_limit: u32, mint_path: MintPath) -> MintPhase {
+        MintPhase {
+            start_time: Timestamp::from_seconds(start),
+            end_time: Timestamp::from_seconds(end),
+            per_address_limit,
+            mint_path,
+        }
     }
 
     #[test]
-    fn event_info_update_properly() {
+    fn mint_without_active_phase_error() {
         let mut deps = mock_desmos_dependencies();
-        let mut env = mock_env();
-        let info = mock_info(CREATOR, &vec![]);
 
         do_instantiate(deps.as_mut());
+        set_phases(
+            deps.as_mut(),
+            vec![mint_phase(
+                EVENT_START_SECONDS + 600,
+                EVENT_END_SECONDS,
+                1,
+                MintPath::Public,
+            )],
+        );
 
-        let msg = ExecuteMsg::UpdateEventInfo {
-            start_time: Timestamp::from_seconds(EVENT_START_SECONDS),
-            end_time: Timestamp::from_seconds(EVENT_END_SECONDS + 300),
-        };
-
-        // Current time is before event started
-        env.block.time = Timestamp::from_seconds(EVENT_START_SECONDS - 100);
-
-        execute(deps.as_mut(), env, info, msg).unwrap();
+        assert_eq!(
+            ContractError::NoActiveMintPhase { event_id: EVENT_ID },
+            mint_to(deps.as_mut(), USER).unwrap_err()
+        );
     }
 
     #[test]
-    fn event_info_start_time_equal_end_time_error() {
+    fn mint_with_not_allowed_path_error() {
         let mut deps = mock_desmos_dependencies();
-        let mut env = mock_env();
-        let info = mock_info(CREATOR, &vec![]);
 
         do_instantiate(deps.as_mut());
-        env.block.time = Timestamp::from_seconds(INITIAL_BLOCK_TIME_SECONDS);
-
-        // Start time eq end time
-        let msg = ExecuteMsg::UpdateEventInfo {
-            start_time: Timestamp::from_seconds(EVENT_START_SECONDS),
-            end_time: Timestamp::from_seconds(EVENT_START_SECONDS),
-        };
+        set_phases(
+            deps.as_mut(),
+            vec![mint_phase(
+                EVENT_START_SECONDS,
+                EVENT_END_SECONDS,
+                1,
+                MintPath::CodeOnly,
+            )],
+        );
 
-        let result = execute(deps.as_mut(), env.clone(), info.clone(), msg);
         assert_eq!(
-            ContractError::StartTimeAfterEndTime {
-                start: Timestamp::from_seconds(EVENT_START_SECONDS),
-                end: Timestamp::from_seconds(EVENT_START_SECONDS)
-            },
-            result.unwrap_err()
+            ContractError::MintPathNotAllowed { phase: 0 },
+            mint_to(deps.as_mut(), USER).unwrap_err()
         );
     }
 
     #[test]
-    fn event_info_start_time_after_end_time_error() {
+    fn mint_out_of_phase_limit_error() {
         let mut deps = mock_desmos_dependencies();
-        let mut env = mock_env();
-        let info = mock_info(CREATOR, &vec![]);
 
         do_instantiate(deps.as_mut());
-        env.block.time = Timestamp::from_seconds(INITIAL_BLOCK_TIME_SECONDS);
-
-        let msg = ExecuteMsg::UpdateEventInfo {
-            start_time: Timestamp::from_seconds(EVENT_START_SECONDS + 100),
-            end_time: Timestamp::from_seconds(EVENT_START_SECONDS),
-        };
+        set_phases(
+            deps.as_mut(),
+            vec![mint_phase(
+                EVENT_START_SECONDS,
+                EVENT_END_SECONDS,
+                1,
+                MintPath::MintToOnly,
+            )],
+        );
 
-        let result = execute(deps.as_mut(), env.clone(), info.clone(), msg);
+        mint_to(deps.as_mut(), USER).unwrap();
         assert_eq!(
-            ContractError::StartTimeAfterEndTime {
-                start: Timestamp::from_seconds(EVENT_START_SECONDS + 100),
-                end: Timestamp::from_seconds(EVENT_START_SECONDS)
+            ContractError::MaxPerAddressLimitExceeded {
+                recipient_addr: USER.to_string()
             },
-            result.unwrap_err()
+            mint_to(deps.as_mut(), USER).unwrap_err()
         );
     }
 
     #[test]
-    fn event_info_start_time_before_current_time_error() {
+    fn extend_event_before_start_error() {
         let mut deps = mock_desmos_dependencies();
         let mut env = mock_env();
-        let info = mock_info(CREATOR, &vec![]);
-
-        do_instantiate(deps.as_mut());
         env.block.time = Timestamp::from_seconds(INITIAL_BLOCK_TIME_SECONDS);
 
-        let msg = ExecuteMsg::UpdateEventInfo {
-            start_time: Timestamp::from_seconds(INITIAL_BLOCK_TIME_SECONDS - 1),
-            end_time: Timestamp::from_seconds(EVENT_END_SECONDS),
-        };
+        do_instantiate(deps.as_mut());
 
-        let result = execute(deps.as_mut(), env.clone(), info.clone(), msg);
+        let result = execute(
+            deps.as_mut(),
+            env,
+            mock_info(CREATOR, &vec![]),
+            ExecuteMsg::ExtendEvent {
+                event_id: EVENT_ID.into(),
+                new_end_time: Timestamp::from_seconds(EVENT_END_SECONDS + 3600),
+            },
+        );
         assert_eq!(
-            ContractError::StartTimeBeforeCurrentTime {
-                start_time: Timestamp::from_seconds(INITIAL_BLOCK_TIME_SECONDS - 1),
+            ContractError::EventNotStarted {
                 current_time: Timestamp::from_seconds(INITIAL_BLOCK_TIME_SECONDS),
+                start_time: Timestamp::from_seconds(EVENT_START_SECONDS),
             },
             result.unwrap_err()
         );
     }
 
     #[test]
-    fn event_info_start_time_equal_current_time_error() {
+    fn extend_event_with_earlier_end_time_error() {
         let mut deps = mock_desmos_dependencies();
         let mut env = mock_env();
-        let info = mock_info(CREATOR, &vec![]);
+        env.block.time = Timestamp::from_seconds(EVENT_START_SECONDS);
 
         do_instantiate(deps.as_mut());
-        env.block.time = Timestamp::from_seconds(INITIAL_BLOCK_TIME_SECONDS);
-
-        let msg = ExecuteMsg::UpdateEventInfo {
-            start_time: Timestamp::from_seconds(INITIAL_BLOCK_TIME_SECONDS),
-            end_time: Timestamp::from_seconds(EVENT_END_SECONDS),
-        };
 
-        let result = execute(deps.as_mut(), env.clone(), info.clone(), msg);
+        let result = execute(
+            deps.as_mut(),
+            env,
+            mock_info(CREATOR, &vec![]),
+            ExecuteMsg::ExtendEvent {
+                event_id: EVENT_ID.into(),
+                new_end_time: Timestamp::from_seconds(EVENT_END_SECONDS - 600),
+            },
+        );
         assert_eq!(
-            ContractError::StartTimeBeforeCurrentTime {
-                start_time: Timestamp::from_seconds(INITIAL_BLOCK_TIME_SECONDS),
-                current_time: Timestamp::from_seconds(INITIAL_BLOCK_TIME_SECONDS),
+            ContractError::InvalidEndTimeExtension {
+                end_time: Timestamp::from_seconds(EVENT_END_SECONDS),
+                new_end_time: Timestamp::from_seconds(EVENT_END_SECONDS - 600),
             },
             result.unwrap_err()
         );
     }
 
     #[test]
-    fn event_info_end_time_before_current_time_error() {
+    fn extend_event_properly() {
         let mut deps = mock_desmos_dependencies();
         let mut env = mock_env();
-        let info = mock_info(CREATOR, &vec![]);
+        env.block.time = Timestamp::from_seconds(EVENT_START_SECONDS);
 
         do_instantiate(deps.as_mut());
-        env.block.time = Timestamp::from_seconds(INITIAL_BLOCK_TIME_SECONDS);
+        set_phases(
+            deps.as_mut(),
+            vec![
+                mint_phase(
+                    EVENT_START_SECONDS,
+                    EVENT_START_SECONDS + 600,
+                    1,
+                    MintPath::MintToOnly,
+                ),
+                mint_phase(
+                    EVENT_START_SECONDS + 600,
+                    EVENT_END_SECONDS,
+                    1,
+                    MintPath::Public,
+                ),
+            ],
+        );
 
-        let msg = ExecuteMsg::UpdateEventInfo {
-            start_time: Timestamp::from_seconds(INITIAL_BLOCK_TIME_SECONDS + 2),
-            end_time: Timestamp::from_seconds(INITIAL_BLOCK_TIME_SECONDS - 1),
-        };
+        execute(
+            deps.as_mut(),
+            env,
+            mock_info(CREATOR, &vec![]),
+            ExecuteMsg::ExtendEvent {
+                event_id: EVENT_ID.into(),
+                new_end_time: Timestamp::from_seconds(EVENT_END_SECONDS + 3600),
+            },
+        )
+        .unwrap();
 
-        let result = execute(deps.as_mut(), env.clone(), info.clone(), msg);
+        let event_info = EVENTS.load(&deps.storage, EVENT_ID).unwrap();
         assert_eq!(
-            ContractError::StartTimeAfterEndTime {
-                start: Timestamp::from_seconds(INITIAL_BLOCK_TIME_SECONDS + 2),
-                end: Timestamp::from_seconds(INITIAL_BLOCK_TIME_SECONDS - 1),
-            },
-            result.unwrap_err()
+            Timestamp::from_seconds(EVENT_END_SECONDS + 3600),
+            event_info.end_time
+        );
+        assert_eq!(
+            vec![
+                mint_phase(
+                    EVENT_START_SECONDS,
+                    EVENT_START_SECONDS + 600,
+                    1,
+                    MintPath::MintToOnly,
+                ),
+                mint_phase(
+                    EVENT_START_SECONDS + 600,
+                    EVENT_END_SECONDS + 3600,
+                    1,
+                    MintPath::Public,
+                ),
+            ],
+            event_info.phases
         );
     }
 
+    fn mint_to_many(
+        deps: DepsMut<DesmosQuery>,
+        sender: &str,
+        recipients: Vec<&str>,
+        skip_ineligible: bool,
+    ) -> Result<Response<DesmosMsg>, ContractError> {
+        let mut env = mock_env();
+        env.block.time = Timestamp::from_seconds(EVENT_START_SECONDS);
+        execute(
+            deps,
+            env,
+            mock_info(sender, &vec![]),
+            ExecuteMsg::MintToMany {
+                event_id: EVENT_ID.into(),
+                recipients: recipients.into_iter().map(String::from).collect(),
+                skip_ineligible,
+            },
+        )
+    }
+
     #[test]
-    fn event_info_end_time_equal_current_time_error() {
+    fn mint_to_many_without_permission_error() {
         let mut deps = mock_desmos_dependencies();
-        let mut env = mock_env();
-        let info = mock_info(CREATOR, &vec![]);
 
         do_instantiate(deps.as_mut());
-        env.block.time = Timestamp::from_seconds(INITIAL_BLOCK_TIME_SECONDS);
-
-        let msg = ExecuteMsg::UpdateEventInfo {
-            start_time: Timestamp::from_seconds(INITIAL_BLOCK_TIME_SECONDS + 2),
-            end_time: Timestamp::from_seconds(INITIAL_BLOCK_TIME_SECONDS),
-        };
 
-        let result = execute(deps.as_mut(), env.clone(), info.clone(), msg);
         assert_eq!(
-            ContractError::StartTimeAfterEndTime {
-                start: Timestamp::from_seconds(INITIAL_BLOCK_TIME_SECONDS + 2),
-                end: Timestamp::from_seconds(INITIAL_BLOCK_TIME_SECONDS),
-            },
-            result.unwrap_err()
+            Unauthorized {},
+            mint_to_many(deps.as_mut(), USER, vec![USER], false).unwrap_err()
         );
     }
 
     #[test]
-    fn update_admin_without_permission_error() {
+    fn mint_to_many_with_invalid_recipient_error() {
         let mut deps = mock_desmos_dependencies();
-        let env = mock_env();
-        const NEW_ADMIN: &str = "admin2";
 
         do_instantiate(deps.as_mut());
 
-        let msg = ExecuteMsg::UpdateAdmin {
-            new_admin: NEW_ADMIN.to_string(),
-        };
-
-        let result = execute(
-            deps.as_mut(),
-            env.clone(),
-            mock_info(USER, &vec![]),
-            msg.clone(),
-        );
-        assert_eq!(ContractError::Unauthorized {}, result.unwrap_err());
-
-        let result = execute(
-            deps.as_mut(),
-            env.clone(),
-            mock_info(CREATOR, &vec![]),
-            msg.clone(),
-        );
-        assert_eq!(ContractError::Unauthorized {}, result.unwrap_err());
+        assert!(mint_to_many(deps.as_mut(), MINTER, vec![USER, "a"], false).is_err());
+        // No poap should be minted since the whole batch failed
+        assert!(MINTER_ADDRESS
+            .may_load(&deps.storage, (EVENT_ID, Addr::unchecked(USER)))
+            .unwrap()
+            .is_none());
     }
 
     #[test]
-    fn update_admin_with_permission_properly() {
+    fn mint_to_many_out_of_max_amount_error() {
         let mut deps = mock_desmos_dependencies();
-        let env = mock_env();
-        const NEW_ADMIN: &str = "admin2";
 
         do_instantiate(deps.as_mut());
 
-        let msg = ExecuteMsg::UpdateAdmin {
-            new_admin: NEW_ADMIN.to_string(),
-        };
-
-        execute(deps.as_mut(), env.clone(), mock_info(ADMIN, &vec![]), msg).unwrap();
-
-        let config = CONFIG.load(&deps.storage).unwrap();
-        assert_eq!(NEW_ADMIN, config.admin.as_str());
+        assert_eq!(
+            ContractError::MaxPerAddressLimitExceeded {
+                recipient_addr: USER.to_string()
+            },
+            mint_to_many(deps.as_mut(), MINTER, vec![USER, USER, USER], false).unwrap_err()
+        );
     }
 
     #[test]
-    fn update_minter_permission_error() {
+    fn mint_to_many_skipping_ineligible_properly() {
         let mut deps = mock_desmos_dependencies();
-        let env = mock_env();
-        const NEW_MINTER: &str = "minter2";
 
         do_instantiate(deps.as_mut());
 
-        let msg = ExecuteMsg::UpdateMinter {
-            new_minter: NEW_MINTER.to_string(),
-        };
+        let response =
+            mint_to_many(deps.as_mut(), MINTER, vec![USER, USER, USER, ADMIN], true).unwrap();
 
-        let result = execute(
-            deps.as_mut(),
-            env.clone(),
-            mock_info(USER, &vec![]),
-            msg.clone(),
+        assert_eq!(3, response.messages.len());
+        assert!(response
+            .attributes
+            .contains(&Attribute::new("poap_ids", "1,2,3")));
+        assert!(response
+            .attributes
+            .contains(&Attribute::new("skipped_recipients", USER)));
+        assert_eq!(
+            2,
+            MINTER_ADDRESS
+                .load(&deps.storage, (EVENT_ID, Addr::unchecked(USER)))
+                .unwrap()
         );
-        assert_eq!(ContractError::Unauthorized {}, result.unwrap_err());
-
-        let result = execute(
-            deps.as_mut(),
-            env.clone(),
-            mock_info(CREATOR, &vec![]),
-            msg.clone(),
+        assert_eq!(
+            1,
+            MINTER_ADDRESS
+                .load(&deps.storage, (EVENT_ID, Addr::unchecked(ADMIN)))
+                .unwrap()
         );
-        assert_eq!(ContractError::Unauthorized {}, result.unwrap_err());
-
-        let result = execute(deps.as_mut(), env.clone(), mock_info(MINTER, &vec![]), msg);
-        assert_eq!(ContractError::Unauthorized {}, result.unwrap_err());
+        assert_eq!(4, NEXT_POAP_ID.load(&deps.storage).unwrap());
     }
 
     #[test]
-    fn update_minter_with_permission_properly() {
+    fn query_holders_properly() {
         let mut deps = mock_desmos_dependencies();
-        let env = mock_env();
-        const NEW_MINTER: &str = "minter2";
-
         do_instantiate(deps.as_mut());
+        mint_to(deps.as_mut(), USER).unwrap();
+        mint_to(deps.as_mut(), USER).unwrap();
+        mint_to(deps.as_mut(), ADMIN).unwrap();
 
-        let msg = ExecuteMsg::UpdateMinter {
-            new_minter: NEW_MINTER.to_string(),
-        };
+        let response = query_holders(deps.as_ref(), EVENT_ID, None, None).unwrap();
+        assert_eq!(
+            vec![
+                QueryMintedAmountResponse {
+                    user: Addr::unchecked(ADMIN),
+                    amount: 1,
+                },
+                QueryMintedAmountResponse {
+                    user: Addr::unchecked(USER),
+                    amount: 2,
+                },
+            ],
+            response.holders
+        );
 
-        execute(deps.as_mut(), env.clone(), mock_info(ADMIN, &vec![]), msg).unwrap();
+        let response =
+            query_holders(deps.as_ref(), EVENT_ID, Some(ADMIN.to_string()), Some(1)).unwrap();
+        assert_eq!(
+            vec![QueryMintedAmountResponse {
+                user: Addr::unchecked(USER),
+                amount: 2,
+            }],
+            response.holders
+        );
 
-        let config = CONFIG.load(&deps.storage).unwrap();
-        assert_eq!(NEW_MINTER, config.minter.as_str());
+        let response = query_holders(deps.as_ref(), EVENT_ID + 1, None, None).unwrap();
+        assert!(response.holders.is_empty());
     }
 
     #[test]
-    fn mint_with_event_not_started_error() {
+    fn query_has_attended_properly() {
         let mut deps = mock_desmos_dependencies();
-        let mut env = mock_env();
-        let info = mock_info(ADMIN, &vec![]);
-
         do_instantiate(deps.as_mut());
 
-        env.block.time = Timestamp::from_seconds(INITIAL_BLOCK_TIME_SECONDS);
-
-        // Enable mint since is disable by default.
-        let msg = ExecuteMsg::EnableMint {};
-        execute(deps.as_mut(), env.clone(), info, msg).unwrap();
-
-        let msg = ExecuteMsg::Mint {};
-        let info = mock_info(USER, &vec![]);
-        let result = execute(deps.as_mut(), env.clone(), info, msg);
+        let response = query_has_attended(deps.as_ref(), EVENT_ID, USER.to_string()).unwrap();
+        assert_eq!(
+            QueryHasAttendedResponse {
+                attended: false,
+                amount: 0,
+            },
+            response
+        );
 
-        // Event is not started
+        mint_to(deps.as_mut(), USER).unwrap();
+        let response = query_has_attended(deps.as_ref(), EVENT_ID, USER.to_string()).unwrap();
         assert_eq!(
-            ContractError::EventNotStarted {
-                current_time: env.block.time,
-                start_time: Timestamp::from_seconds(EVENT_START_SECONDS),
+            QueryHasAttendedResponse {
+                attended: true,
+                amount: 1,
             },
-            result.unwrap_err()
+            response
         );
     }
 
     #[test]
-    fn mint_with_event_terminated_error() {
+    fn query_minted_amounts_properly() {
         let mut deps = mock_desmos_dependencies();
-        let mut env = mock_env();
-        let info = mock_info(ADMIN, &vec![]);
-
         do_instantiate(deps.as_mut());
+        mint_to(deps.as_mut(), USER).unwrap();
 
-        // Enable mint since is disable by default.
-        let msg = ExecuteMsg::EnableMint {};
-        execute(deps.as_mut(), env.clone(), info, msg).unwrap();
-
-        env.block.time = Timestamp::from_seconds(EVENT_END_SECONDS);
-
-        let msg = ExecuteMsg::Mint {};
-        let info = mock_info(USER, &vec![]);
-        let result = execute(deps.as_mut(), env.clone(), info, msg);
-
-        // Event is not started
+        let response = query_minted_amounts(
+            deps.as_ref(),
+            EVENT_ID,
+            vec![USER.to_string(), ADMIN.to_string()],
+        )
+        .unwrap();
         assert_eq!(
-            ContractError::EventTerminated {
-                current_time: env.block.time,
-                end_time: Timestamp::from_seconds(EVENT_END_SECONDS),
-            },
-            result.unwrap_err()
+            vec![
+                QueryMintedAmountResponse {
+                    user: Addr::unchecked(USER),
+                    amount: 1,
+                },
+                QueryMintedAmountResponse {
+                    user: Addr::unchecked(ADMIN),
+                    amount: 0,
+                },
+            ],
+            response.amounts
         );
     }
 
     #[test]
-    fn mint_without_permissions_error() {
+    fn execute_before_cw721_instantiation_error() {
         let mut deps = mock_desmos_dependencies();
         let mut env = mock_env();
-        let info = mock_info(USER, &vec![]);
+        env.block.time = Timestamp::from_seconds(INITIAL_BLOCK_TIME_SECONDS);
+        instantiate(
+            deps.as_mut(),
+            env,
+            mock_info(CREATOR, &vec![]),
+            get_valid_init_msg(1),
+        )
+        .unwrap();
 
-        do_instantiate(deps.as_mut());
+        let result = mint_to(deps.as_mut(), USER);
+        assert_eq!(ContractError::ContractNotReady {}, result.unwrap_err());
 
-        // Change current time to event start
+        let mut env = mock_env();
         env.block.time = Timestamp::from_seconds(EVENT_START_SECONDS);
-
-        let msg = ExecuteMsg::Mint {};
-        let result = execute(deps.as_mut(), env.clone(), info, msg);
-
-        // Event is not started
-        assert_eq!(ContractError::MintDisabled {}, result.unwrap_err());
+        let status = query_status(deps.as_ref(), env, EVENT_ID).unwrap();
+        assert_eq!(Status::Initializing, status.status);
     }
 
     #[test]
-    fn mint_out_of_max_amount_error() {
+    fn close_event_without_permission_error() {
         let mut deps = mock_desmos_dependencies();
-        let mut env = mock_env();
-        let info = mock_info(ADMIN, &vec![]);
-
         do_instantiate(deps.as_mut());
 
-        // Change current time to event start
+        let mut env = mock_env();
         env.block.time = Timestamp::from_seconds(EVENT_START_SECONDS);
-
-        execute(deps.as_mut(), env.clone(), info, ExecuteMsg::EnableMint {}).unwrap();
-
-        let info = mock_info(USER, &vec![]);
-        // Mint the first poap
-        execute(
-            deps.as_mut(),
-            env.clone(),
-            info.clone(),
-            ExecuteMsg::Mint {},
-        )
-        .unwrap();
-        // Mint the second poap
-        execute(
-            deps.as_mut(),
-            env.clone(),
-            info.clone(),
-            ExecuteMsg::Mint {},
-        )
-        .unwrap();
-
-        let response = execute(
-            deps.as_mut(),
-            env.clone(),
-            info.clone(),
-            ExecuteMsg::Mint {},
-        );
-        assert_eq!(
-            ContractError::MaxPerAddressLimitExceeded {
-                recipient_addr: USER.to_string()
-            },
-            response.unwrap_err()
-        );
-
-        // Ensure that mint to also fails when minting for the user
-        let info = mock_info(ADMIN, &vec![]);
-        let response = execute(
+        let result = execute(
             deps.as_mut(),
-            env.clone(),
-            info,
-            ExecuteMsg::MintTo {
-                recipient: USER.to_string(),
-            },
-        );
-
-        assert_eq!(
-            ContractError::MaxPerAddressLimitExceeded {
-                recipient_addr: USER.to_string()
+            env,
+            mock_info(USER, &vec![]),
+            ExecuteMsg::CloseEvent {
+                event_id: EVENT_ID.into(),
             },
-            response.unwrap_err()
         );
+        assert_eq!(Unauthorized {}, result.unwrap_err());
     }
 
     #[test]
-    fn mint_to_out_of_max_amount_error() {
+    fn close_event_properly() {
         let mut deps = mock_desmos_dependencies();
-        let mut env = mock_env();
-        let info = mock_info(ADMIN, &vec![]);
-
         do_instantiate(deps.as_mut());
 
-        // Change current time to event start
+        let mut env = mock_env();
         env.block.time = Timestamp::from_seconds(EVENT_START_SECONDS);
+        let status = query_status(deps.as_ref(), env.clone(), EVENT_ID).unwrap();
+        assert_eq!(Status::Ready, status.status);
 
+        let msg = ExecuteMsg::CloseEvent {
+            event_id: EVENT_ID.into(),
+        };
         execute(
             deps.as_mut(),
             env.clone(),
-            info.clone(),
-            ExecuteMsg::EnableMint {},
+            mock_info(CREATOR, &vec![]),
+            msg.clone(),
         )
         .unwrap();
 
-        // Mint the first poap
-        execute(
-            deps.as_mut(),
-            env.clone(),
-            info.clone(),
-            ExecuteMsg::MintTo {
-                recipient: USER.to_string(),
-            },
-        )
-        .unwrap();
-        // Mint the second and last allowed poap
-        execute(
-            deps.as_mut(),
-            env.clone(),
-            info.clone(),
-            ExecuteMsg::MintTo {
-                recipient: USER.to_string(),
-            },
-        )
-        .unwrap();
+        let status = query_status(deps.as_ref(), env.clone(), EVENT_ID).unwrap();
+        assert_eq!(Status::Closed, status.status);
 
-        let response = execute(
-            deps.as_mut(),
-            env.clone(),
-            info.clone(),
-            ExecuteMsg::MintTo {
-                recipient: USER.to_string(),
-            },
-        );
-        // Should fail since the user have already received the max allowed poaps.
+        // The event can't be closed twice
+        let result = execute(deps.as_mut(), env, mock_info(ADMIN, &vec![]), msg);
         assert_eq!(
-            ContractError::MaxPerAddressLimitExceeded {
-                recipient_addr: USER.to_string()
-            },
-            response.unwrap_err()
+            ContractError::EventClosed { event_id: EVENT_ID },
+            result.unwrap_err()
         );
 
-        // Test also with Mint from use
-        let info = mock_info(USER, &vec![]);
-        let response = execute(deps.as_mut(), env.clone(), info, ExecuteMsg::Mint {});
+        // The poaps of a closed event can't be minted
+        let result = mint_to(deps.as_mut(), USER);
         assert_eq!(
-            ContractError::MaxPerAddressLimitExceeded {
-                recipient_addr: USER.to_string()
-            },
-            response.unwrap_err()
+            ContractError::EventClosed { event_id: EVENT_ID },
+            result.unwrap_err()
         );
     }
 
+    #[test]
+    fn query_status_after_end_time_properly() {
+        let mut deps = mock_desmos_dependencies();
+        do_instantiate(deps.as_mut());
+
+        let mut env = mock_env();
+        env.block.time = Timestamp::from_seconds(EVENT_END_SECONDS);
+        let status = query_status(deps.as_ref(), env, EVENT_ID).unwrap();
+        assert_eq!(Status::Closed, status.status);
+    }
+
     #[test]
     fn mint_to_without_permission_error() {
         let mut deps = mock_desmos_dependencies();
@@ -1368,7 +4137,9 @@ mod tests {
             env,
             mock_info(USER, &vec![]),
             ExecuteMsg::MintTo {
+                event_id: EVENT_ID.into(),
                 recipient: USER.to_string(),
+                tier: None,
             },
         );
         // User should not be authorized to use the mint to action
@@ -1391,7 +4162,9 @@ mod tests {
             env.clone(),
             mock_info(MINTER, &vec![]),
             ExecuteMsg::MintTo {
+                event_id: EVENT_ID.into(),
                 recipient: USER.to_string(),
+                tier: None,
             },
         )
         .unwrap();
@@ -1413,7 +4186,9 @@ mod tests {
             env.clone(),
             mock_info(ADMIN, &vec![]),
             ExecuteMsg::MintTo {
+                event_id: EVENT_ID.into(),
                 recipient: USER.to_string(),
+                tier: None,
             },
         )
         .unwrap();
@@ -1,9 +1,8 @@
-use cosmwasm_std::{Binary, Deps, DepsMut, Empty, Env, MessageInfo, Response, StdError, StdResult};
-use cw721_base::{
-    ContractError as Cw721ContractError, Cw721Contract, ExecuteMsg as Cw721ExecuteMsg,
+use cosmwasm_std::{Binary, Deps, DepsMut, Env, MessageInfo, Response, StdError, StdResult};
+use cw721_poap::{
+    entry, ContractError as Cw721ContractError, ExecuteMsg as Cw721ExecuteMsg,
     InstantiateMsg as Cw721InstantiateMsg, QueryMsg as Cw721QueryMsg,
 };
-use cw721_poap::Metadata;
 use cw_multi_test::{Contract, ContractWrapper};
 use desmos_bindings::{msg::DesmosMsg, query::DesmosQuery};
 
@@ -11,10 +10,9 @@ fn cw721_execute(
     deps: DepsMut<DesmosQuery>,
     env: Env,
     info: MessageInfo,
-    msg: Cw721ExecuteMsg<Metadata, Empty>,
+    msg: Cw721ExecuteMsg,
 ) -> Result<Response<DesmosMsg>, Cw721ContractError> {
-    Cw721Contract::<'static, Metadata, Empty, Empty, DesmosMsg, DesmosQuery>::default()
-        .execute(deps, env, info, msg)
+    entry::execute(deps, env, info, msg)
 }
 
 fn cw721_instantiate(
@@ -22,9 +20,8 @@ fn cw721_instantiate(
     env: Env,
     info: MessageInfo,
     msg: Cw721InstantiateMsg,
-) -> Result<Response<DesmosMsg>, StdError> {
-    Cw721Contract::<'static, Metadata, Empty, Empty, DesmosMsg, DesmosQuery>::default()
-        .instantiate(deps, env, info, msg)
+) -> Result<Response<DesmosMsg>, Cw721ContractError> {
+    entry::instantiate(deps, env, info, msg)
 }
 
 fn failing_cw721_instantiate(
@@ -32,13 +29,12 @@ fn failing_cw721_instantiate(
     _env: Env,
     _info: MessageInfo,
     _msg: Cw721InstantiateMsg,
-) -> Result<Response<DesmosMsg>, StdError> {
-    Err(StdError::generic_err("cw721 initialization failed"))
+) -> Result<Response<DesmosMsg>, Cw721ContractError> {
+    Err(StdError::generic_err("cw721 initialization failed").into())
 }
 
-fn cw721_query(deps: Deps<DesmosQuery>, env: Env, msg: Cw721QueryMsg<Empty>) -> StdResult<Binary> {
-    Cw721Contract::<'static, Metadata, Empty, Empty, DesmosMsg, DesmosQuery>::default()
-        .query(deps, env, msg)
+fn cw721_query(deps: Deps<DesmosQuery>, env: Env, msg: Cw721QueryMsg) -> StdResult<Binary> {
+    entry::query(deps, env, msg)
 }
 
 /// Provides an instance of a cw721 contract.
@@ -12,6 +12,9 @@ pub enum ContractError {
     #[error("Invalid reply ID")]
     InvalidReplyID {},
 
+    #[error("Event {event_id} not found")]
+    EventNotFound { event_id: u64 },
+
     #[error("Instantiate cw721 error")]
     InstantiateCw721Error {},
 
@@ -59,4 +62,103 @@ pub enum ContractError {
         current_time: Timestamp,
         end_time: Timestamp,
     },
+
+    #[error("Invalid claim code hash (must be an hex encoded sha256 hash): {hash}")]
+    InvalidClaimCodeHash { hash: String },
+
+    #[error("Empty claim codes")]
+    EmptyClaimCodes {},
+
+    #[error("Claim code already registered: {hash}")]
+    ClaimCodeAlreadyRegistered { hash: String },
+
+    #[error("Invalid claim code")]
+    InvalidClaimCode {},
+
+    #[error("Claim code already used")]
+    ClaimCodeAlreadyUsed {},
+
+    #[error("Invalid merkle root (must be an hex encoded sha256 hash): {root}")]
+    InvalidMerkleRoot { root: String },
+
+    #[error("Invalid merkle proof")]
+    InvalidMerkleProof {},
+
+    #[error("Merkle root not set for event {event_id}")]
+    MerkleRootNotSet { event_id: u64 },
+
+    #[error("Event {event_id} has an allowlist, the poaps can only be minted with a Merkle proof")]
+    MerkleProofRequired { event_id: u64 },
+
+    #[error("Attestor public key not set")]
+    AttestorNotSet {},
+
+    #[error("Invalid attestor signature")]
+    InvalidSignature {},
+
+    #[error("Signature expired, current time: {current_time} expiration: {expiration}")]
+    SignatureExpired {
+        current_time: Timestamp,
+        expiration: Timestamp,
+    },
+
+    #[error("Nonce already used: {nonce}")]
+    NonceAlreadyUsed { nonce: u64 },
+
+    #[error("Invalid max supply value")]
+    InvalidMaxSupply {},
+
+    #[error("Max supply ({max_supply}) lower than the minted amount ({minted})")]
+    MaxSupplyBelowMinted { max_supply: u32, minted: u32 },
+
+    #[error("Max supply reached for event {event_id}")]
+    MaxSupplyReached { event_id: u64 },
+
+    #[error("Invalid mint price")]
+    InvalidMintPrice {},
+
+    #[error("Mint price not paid, required: {mint_price}")]
+    MintPriceNotPaid { mint_price: String },
+
+    #[error("No funds to claim for event {event_id}")]
+    NoFundsToClaim { event_id: u64 },
+
+    #[error("Invalid tier: {tier}")]
+    InvalidTier { tier: String },
+
+    #[error("Duplicated tier: {tier}")]
+    DuplicatedTier { tier: String },
+
+    #[error("Tier {tier} not found in event {event_id}")]
+    TierNotFound { event_id: u64, tier: String },
+
+    #[error("Recipients list can't be empty")]
+    EmptyRecipients {},
+
+    #[error("Recipients list can't have more than {max} addresses, got: {value}")]
+    TooManyRecipients { value: usize, max: usize },
+
+    #[error("Invalid mint phase at index {index}")]
+    InvalidMintPhase { index: u32 },
+
+    #[error("No active mint phase for event {event_id}")]
+    NoActiveMintPhase { event_id: u64 },
+
+    #[error("Mint path not allowed during phase {phase}")]
+    MintPathNotAllowed { phase: u32 },
+
+    #[error("New end time ({new_end_time}) must be after the current end time ({end_time})")]
+    InvalidEndTimeExtension {
+        end_time: Timestamp,
+        new_end_time: Timestamp,
+    },
+
+    #[error("Revocation reason can't be empty")]
+    InvalidRevocationReason {},
+
+    #[error("Contract not ready, the cw721 contract has not been instantiated yet")]
+    ContractNotReady {},
+
+    #[error("Event {event_id} is closed")]
+    EventClosed { event_id: u64 },
 }
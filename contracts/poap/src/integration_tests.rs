@@ -2,8 +2,8 @@
 mod tests {
     use crate::cw721_test_utils;
     use crate::msg::{
-        ExecuteMsg, QueryConfigResponse, QueryEventInfoResponse, QueryMintedAmountResponse,
-        QueryMsg,
+        ContractStatus, ExecuteMsg, QueryConfigResponse, QueryEventInfoResponse,
+        QueryMintedAmountResponse, QueryMsg, QueryRevocationResponse, QueryStatsResponse,
     };
     use crate::test_utils::{
         get_valid_init_msg, ADMIN, CREATOR, EVENT_END_SECONDS, EVENT_START_SECONDS,
@@ -12,7 +12,7 @@ mod tests {
     use cosmwasm_std::{Addr, Empty, Timestamp, Uint64};
     use cw721::{AllNftInfoResponse, NftInfoResponse, OwnerOfResponse, TokensResponse};
     use cw721_base::{MinterResponse, QueryMsg as Cw721QueryMsg};
-    use cw721_poap::Metadata;
+    use cw721_poap::{ExecuteMsg as Cw721PoapExecuteMsg, Metadata};
 
     use cw_multi_test::{Contract, ContractWrapper, Executor};
     use desmos_bindings::{
@@ -21,6 +21,8 @@ mod tests {
         query::DesmosQuery,
     };
 
+    const EVENT_ID: u64 = 1;
+
     fn contract_poap() -> Box<dyn Contract<DesmosMsg, DesmosQuery>> {
         let contract = ContractWrapper::new(
             crate::contract::execute,
@@ -118,12 +120,18 @@ mod tests {
 
         assert_eq!(Addr::unchecked(ADMIN), poap_config.admin);
         assert_eq!(Addr::unchecked(MINTER), poap_config.minter);
-        assert_eq!(false, poap_config.mint_enabled);
         // 1 since is the first uploaded.
         assert_eq!(Uint64::new(1), poap_config.cw721_contract_code);
+        // The reply has wired the cw721 contract
+        assert_eq!(ContractStatus::Ready, poap_config.status);
 
         let poap_event_info: QueryEventInfoResponse = querier
-            .query_wasm_smart(&poap_contract_addr, &QueryMsg::EventInfo {})
+            .query_wasm_smart(
+                &poap_contract_addr,
+                &QueryMsg::EventInfo {
+                    event_id: EVENT_ID.into(),
+                },
+            )
             .unwrap();
 
         assert_eq!(Addr::unchecked(CREATOR), poap_event_info.creator);
@@ -135,7 +143,9 @@ mod tests {
             Timestamp::from_seconds(EVENT_END_SECONDS),
             poap_event_info.end_time
         );
+        assert_eq!(2, poap_event_info.per_address_limit);
         assert_eq!(POAP_URI, poap_event_info.poap_uri.as_str());
+        assert_eq!(false, poap_event_info.mint_enabled);
 
         let cw721_minter_response: MinterResponse = querier
             .query_wasm_smart(
@@ -158,7 +168,9 @@ mod tests {
         });
 
         // Enable mint
-        let msg = ExecuteMsg::EnableMint {};
+        let msg = ExecuteMsg::EnableMint {
+            event_id: EVENT_ID.into(),
+        };
         app.execute_contract(
             Addr::unchecked(ADMIN),
             poap_contract_addr.clone(),
@@ -168,7 +180,9 @@ mod tests {
         .unwrap();
 
         // Mint should work since the event is started and the user is allowed to mint
-        let msg = ExecuteMsg::Mint {};
+        let msg = ExecuteMsg::Mint {
+            event_id: EVENT_ID.into(),
+        };
         app.execute_contract(
             Addr::unchecked(USER),
             poap_contract_addr.clone(),
@@ -182,6 +196,7 @@ mod tests {
             .query_wasm_smart(
                 &poap_contract_addr,
                 &QueryMsg::MintedAmount {
+                    event_id: EVENT_ID.into(),
                     user: USER.to_string(),
                 },
             )
@@ -218,7 +233,11 @@ mod tests {
             .unwrap();
         assert_eq!(
             Metadata {
-                claimer: Addr::unchecked(USER)
+                claimer: Addr::unchecked(USER),
+                event_id: EVENT_ID,
+                mint_time: Timestamp::from_seconds(EVENT_START_SECONDS),
+                minter: Addr::unchecked(USER),
+                tier: None,
             },
             minted_nft_info.extension
         )
@@ -234,7 +253,9 @@ mod tests {
         });
 
         // Enable mint
-        let msg = ExecuteMsg::EnableMint {};
+        let msg = ExecuteMsg::EnableMint {
+            event_id: EVENT_ID.into(),
+        };
         app.execute_contract(
             Addr::unchecked(ADMIN),
             poap_contract_addr.clone(),
@@ -245,7 +266,9 @@ mod tests {
 
         // Mint should work since the event is started and the user is allowed to mint
         let msg = ExecuteMsg::MintTo {
+            event_id: EVENT_ID.into(),
             recipient: USER.to_string(),
+            tier: None,
         };
         app.execute_contract(
             Addr::unchecked(ADMIN),
@@ -260,6 +283,7 @@ mod tests {
             .query_wasm_smart(
                 &poap_contract_addr,
                 &QueryMsg::MintedAmount {
+                    event_id: EVENT_ID.into(),
                     user: USER.to_string(),
                 },
             )
@@ -296,12 +320,189 @@ mod tests {
             .unwrap();
         assert_eq!(
             Metadata {
-                claimer: Addr::unchecked(USER)
+                claimer: Addr::unchecked(USER),
+                event_id: EVENT_ID,
+                mint_time: Timestamp::from_seconds(EVENT_START_SECONDS),
+                minter: Addr::unchecked(ADMIN),
+                tier: None,
             },
             minted_nft_info.extension
         )
     }
 
+    #[test]
+    fn transfer_soulbound_poap_error() {
+        let mut app = mock_app();
+        app.update_block(|block_info| {
+            block_info.time = Timestamp::from_seconds(INITIAL_BLOCK_TIME_SECONDS)
+        });
+        let (cw721_code_id, poap_code_id) = store_contracts(&mut app);
+        let mut msg = get_valid_init_msg(cw721_code_id);
+        msg.soulbound = true;
+
+        let poap_contract_addr = app
+            .instantiate_contract(
+                poap_code_id,
+                Addr::unchecked(ADMIN),
+                &msg,
+                &[],
+                "poap_contract",
+                None,
+            )
+            .unwrap();
+
+        // Change the chain time so that the event is started
+        app.update_block(|block_info| {
+            block_info.time = Timestamp::from_seconds(EVENT_START_SECONDS)
+        });
+
+        let msg = ExecuteMsg::MintTo {
+            event_id: EVENT_ID.into(),
+            recipient: USER.to_string(),
+            tier: None,
+        };
+        app.execute_contract(
+            Addr::unchecked(MINTER),
+            poap_contract_addr.clone(),
+            &msg,
+            &vec![],
+        )
+        .unwrap();
+
+        let config: QueryConfigResponse = app
+            .wrap()
+            .query_wasm_smart(&poap_contract_addr, &QueryMsg::Config {})
+            .unwrap();
+
+        // The minted POAP can't be transferred
+        let transfer_result = app.execute_contract(
+            Addr::unchecked(USER),
+            config.cw721_contract.clone(),
+            &Cw721PoapExecuteMsg::TransferNft {
+                recipient: ADMIN.to_string(),
+                token_id: "1".to_string(),
+            },
+            &vec![],
+        );
+        assert!(transfer_result.is_err());
+
+        // The owner can still burn the POAP
+        app.execute_contract(
+            Addr::unchecked(USER),
+            config.cw721_contract,
+            &Cw721PoapExecuteMsg::Burn {
+                token_id: "1".to_string(),
+            },
+            &vec![],
+        )
+        .unwrap();
+    }
+
+    #[test]
+    fn revoke_poap_properly() {
+        let (mut app, poap_contract_addr) = proper_instantiate();
+
+        // Change the chain time so that the event is started
+        app.update_block(|block_info| {
+            block_info.time = Timestamp::from_seconds(EVENT_START_SECONDS)
+        });
+
+        let msg = ExecuteMsg::MintTo {
+            event_id: EVENT_ID.into(),
+            recipient: USER.to_string(),
+            tier: None,
+        };
+        app.execute_contract(
+            Addr::unchecked(MINTER),
+            poap_contract_addr.clone(),
+            &msg,
+            &vec![],
+        )
+        .unwrap();
+
+        let msg = ExecuteMsg::Revoke {
+            token_id: "1".to_string(),
+            reason: "Minted to the wrong user".to_string(),
+        };
+
+        // Only the event creator or the admin can revoke a poap
+        let revoke_result = app.execute_contract(
+            Addr::unchecked(USER),
+            poap_contract_addr.clone(),
+            &msg,
+            &vec![],
+        );
+        assert!(revoke_result.is_err());
+
+        app.execute_contract(
+            Addr::unchecked(CREATOR),
+            poap_contract_addr.clone(),
+            &msg,
+            &vec![],
+        )
+        .unwrap();
+
+        let querier = app.wrap();
+
+        // The poap has been burned
+        let tokens: TokensResponse = querier
+            .query_wasm_smart(
+                &poap_contract_addr,
+                &QueryMsg::Tokens {
+                    owner: USER.to_string(),
+                    start_after: None,
+                    limit: None,
+                },
+            )
+            .unwrap();
+        assert!(tokens.tokens.is_empty());
+
+        // The user mint count has been decremented
+        let minted_amount: QueryMintedAmountResponse = querier
+            .query_wasm_smart(
+                &poap_contract_addr,
+                &QueryMsg::MintedAmount {
+                    event_id: EVENT_ID.into(),
+                    user: USER.to_string(),
+                },
+            )
+            .unwrap();
+        assert_eq!(0, minted_amount.amount);
+
+        // The revoked poap is still counted as minted
+        let stats: QueryStatsResponse = querier
+            .query_wasm_smart(
+                &poap_contract_addr,
+                &QueryMsg::Stats {
+                    event_id: EVENT_ID.into(),
+                },
+            )
+            .unwrap();
+        assert_eq!(1, stats.minted);
+        assert_eq!(1, stats.revoked);
+        assert_eq!(0, stats.unique_holders);
+
+        let revocation: QueryRevocationResponse = querier
+            .query_wasm_smart(
+                &poap_contract_addr,
+                &QueryMsg::Revocation {
+                    token_id: "1".to_string(),
+                },
+            )
+            .unwrap();
+        assert_eq!(
+            QueryRevocationResponse {
+                token_id: "1".to_string(),
+                event_id: EVENT_ID.into(),
+                holder: Addr::unchecked(USER),
+                revoked_by: Addr::unchecked(CREATOR),
+                revoked_at: Timestamp::from_seconds(EVENT_START_SECONDS),
+                reason: "Minted to the wrong user".to_string(),
+            },
+            revocation
+        );
+    }
+
     #[test]
     fn query_tokens() {
         let (mut app, poap_contract_addr) = proper_instantiate();
@@ -312,7 +513,9 @@ mod tests {
         });
 
         // Enable mint
-        let msg = ExecuteMsg::EnableMint {};
+        let msg = ExecuteMsg::EnableMint {
+            event_id: EVENT_ID.into(),
+        };
         app.execute_contract(
             Addr::unchecked(ADMIN),
             poap_contract_addr.clone(),
@@ -323,7 +526,9 @@ mod tests {
 
         // Mint should work since the event is started and the user is allowed to mint
         let msg = ExecuteMsg::MintTo {
+            event_id: EVENT_ID.into(),
             recipient: USER.to_string(),
+            tier: None,
         };
         app.execute_contract(
             Addr::unchecked(ADMIN),
@@ -338,6 +543,7 @@ mod tests {
             .query_wasm_smart(
                 &poap_contract_addr,
                 &QueryMsg::MintedAmount {
+                    event_id: EVENT_ID.into(),
                     user: USER.to_string(),
                 },
             )
@@ -385,7 +591,9 @@ mod tests {
         });
 
         // Enable mint
-        let msg = ExecuteMsg::EnableMint {};
+        let msg = ExecuteMsg::EnableMint {
+            event_id: EVENT_ID.into(),
+        };
         app.execute_contract(
             Addr::unchecked(ADMIN),
             poap_contract_addr.clone(),
@@ -396,7 +604,9 @@ mod tests {
 
         // Mint should work since the event is started and the user is allowed to mint
         let msg = ExecuteMsg::MintTo {
+            event_id: EVENT_ID.into(),
             recipient: USER.to_string(),
+            tier: None,
         };
         app.execute_contract(
             Addr::unchecked(ADMIN),
@@ -411,6 +621,7 @@ mod tests {
             .query_wasm_smart(
                 &poap_contract_addr,
                 &QueryMsg::MintedAmount {
+                    event_id: EVENT_ID.into(),
                     user: USER.to_string(),
                 },
             )
@@ -463,7 +674,11 @@ mod tests {
                 info: NftInfoResponse {
                     token_uri: Some(POAP_URI.to_string()),
                     extension: Metadata {
-                        claimer: Addr::unchecked(USER)
+                        claimer: Addr::unchecked(USER),
+                        event_id: EVENT_ID,
+                        mint_time: Timestamp::from_seconds(EVENT_START_SECONDS),
+                        minter: Addr::unchecked(ADMIN),
+                        tier: None,
                     },
                 }
             },
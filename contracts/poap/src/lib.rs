@@ -3,6 +3,7 @@ pub mod contract;
 mod cw721_test_utils;
 mod error;
 mod integration_tests;
+pub mod merkle;
 pub mod msg;
 pub mod state;
 #[cfg(test)]
@@ -0,0 +1,139 @@
+use crate::ContractError;
+use sha2::{Digest, Sha256};
+
+/// Hash of a node of the Merkle tree.
+pub type Hash = [u8; 32];
+
+/// Byte prepended to the leaves before hashing them.
+const LEAF_PREFIX: u8 = 0x00;
+/// Byte prepended to the children of a parent node before hashing them,
+/// so that a parent node can't be used as a leaf.
+const NODE_PREFIX: u8 = 0x01;
+
+/// Computes the hash of the leaf that allows `address` to mint up to `amount` POAPs.
+/// The leaf is encoded as the [`LEAF_PREFIX`], followed by the big endian `amount` and by the `address`.
+pub fn leaf_hash(address: &str, amount: u32) -> Hash {
+    let mut hasher = Sha256::new();
+    hasher.update([LEAF_PREFIX]);
+    hasher.update(amount.to_be_bytes());
+    hasher.update(address.as_bytes());
+    hasher.finalize().into()
+}
+
+/// Computes the hash of the parent of two nodes.
+/// The nodes are sorted before being hashed so that the proofs don't need
+/// to specify the position of each sibling.
+fn parent_hash(a: &Hash, b: &Hash) -> Hash {
+    let (left, right) = if a <= b { (a, b) } else { (b, a) };
+    let mut hasher = Sha256::new();
+    hasher.update([NODE_PREFIX]);
+    hasher.update(left);
+    hasher.update(right);
+    hasher.finalize().into()
+}
+
+/// Checks that `leaf` is included in the tree having the given hex encoded `root`.
+/// * `root` - Hex encoded root of the Merkle tree.
+/// * `leaf` - Hash of the leaf to verify.
+/// * `proof` - Hex encoded hashes of the siblings from the leaf up to the root.
+pub fn verify_proof(root: &str, leaf: Hash, proof: &[String]) -> Result<bool, ContractError> {
+    let mut computed = leaf;
+    for sibling in proof {
+        let sibling: Hash = hex::decode(sibling)
+            .ok()
+            .and_then(|bytes| bytes.try_into().ok())
+            .ok_or(ContractError::InvalidMerkleProof {})?;
+        computed = parent_hash(&computed, &sibling);
+    }
+    Ok(hex::encode(computed) == root)
+}
+
+/// Builds a Merkle tree from the given leaves.
+/// Returns the levels of the tree, from the leaves up to the root.
+/// This is meant to be used off-chain to generate the root and the proofs.
+pub fn build_tree(leaves: Vec<Hash>) -> Vec<Vec<Hash>> {
+    let mut levels = vec![leaves];
+    while levels[levels.len() - 1].len() > 1 {
+        let next_level = levels[levels.len() - 1]
+            .chunks(2)
+            .map(|pair| match pair {
+                [left, right] => parent_hash(left, right),
+                // An odd node is promoted to the next level as is
+                [single] => *single,
+                _ => unreachable!(),
+            })
+            .collect();
+        levels.push(next_level);
+    }
+    levels
+}
+
+/// Returns the hex encoded root of a tree built with [`build_tree`].
+pub fn tree_root(tree: &[Vec<Hash>]) -> Option<String> {
+    tree.last().and_then(|level| level.first()).map(hex::encode)
+}
+
+/// Returns the hex encoded proof of the leaf at `index` of a tree built with [`build_tree`].
+pub fn tree_proof(tree: &[Vec<Hash>], mut index: usize) -> Vec<String> {
+    let mut proof = vec![];
+    for level in tree.iter().take(tree.len().saturating_sub(1)) {
+        let sibling = index ^ 1;
+        if let Some(hash) = level.get(sibling) {
+            proof.push(hex::encode(hash));
+        }
+        index /= 2;
+    }
+    proof
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn mock_tree() -> Vec<Vec<Hash>> {
+        build_tree(vec![
+            leaf_hash("user1", 1),
+            leaf_hash("user2", 2),
+            leaf_hash("user3", 1),
+        ])
+    }
+
+    #[test]
+    fn verify_proof_properly() {
+        let tree = mock_tree();
+        let root = tree_root(&tree).unwrap();
+
+        for (index, (user, amount)) in [("user1", 1), ("user2", 2), ("user3", 1)]
+            .into_iter()
+            .enumerate()
+        {
+            let proof = tree_proof(&tree, index);
+            assert!(verify_proof(&root, leaf_hash(user, amount), &proof).unwrap());
+        }
+    }
+
+    #[test]
+    fn verify_proof_with_wrong_amount_fails() {
+        let tree = mock_tree();
+        let root = tree_root(&tree).unwrap();
+
+        let proof = tree_proof(&tree, 1);
+        assert!(!verify_proof(&root, leaf_hash("user2", 3), &proof).unwrap());
+    }
+
+    #[test]
+    fn leaf_hash_is_unambiguous() {
+        assert_ne!(leaf_hash("user1", 12), leaf_hash("user11", 2));
+    }
+
+    #[test]
+    fn verify_proof_with_invalid_hex_error() {
+        let tree = mock_tree();
+        let root = tree_root(&tree).unwrap();
+
+        assert_eq!(
+            ContractError::InvalidMerkleProof {},
+            verify_proof(&root, leaf_hash("user1", 1), &["invalid".to_string()]).unwrap_err()
+        );
+    }
+}
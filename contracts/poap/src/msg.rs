@@ -1,11 +1,14 @@
 use crate::ContractError;
 use cosmwasm_schema::{cw_serde, QueryResponses};
-use cosmwasm_std::{Addr, Timestamp, Uint64};
+use cosmwasm_std::{Addr, Binary, Coin, Timestamp, Uint64};
 use cw721::{AllNftInfoResponse, TokensResponse};
 use cw721_base::InstantiateMsg as Cw721InstantiateMsg;
 use cw721_poap::Metadata;
 use url::Url;
 
+/// Maximum amount of recipients of a [`ExecuteMsg::MintToMany`], so that a batch can't run out of gas.
+pub const MAX_MINT_TO_MANY_RECIPIENTS: usize = 50;
+
 #[cw_serde]
 #[schemars(rename = "PoapInstantiateMsg", title = "InstantiateMsg")]
 pub struct InstantiateMsg {
@@ -13,11 +16,16 @@ pub struct InstantiateMsg {
     pub admin: String,
     /// Address of who can call the [`ExecuteMsg::MintTo`] other then the admin.
     pub minter: String,
+    /// Optional compressed secp256k1 public key of the off-chain attestor allowed to sign
+    /// the [`ExecuteMsg::MintWithSignature`] attestations.
+    pub attestor_pubkey: Option<Binary>,
     /// Id of the CW721 contract to initialize together with this contract.
     pub cw721_code_id: Uint64,
     /// Initialization message that will be sent to the CW721 contract.
     pub cw721_instantiate_msg: Cw721InstantiateMsg,
-    /// Information about the event.
+    /// If true the minted POAPs can't be transferred, they can only be burned by their owner.
+    pub soulbound: bool,
+    /// Information about the first event, that will be created with id 1.
     pub event_info: EventInfo,
 }
 
@@ -33,26 +41,167 @@ pub struct EventInfo {
     pub per_address_limit: u32,
     /// Identifies a valid IPFS URI corresponding to where the assets and metadata of the POAPs are stored.
     pub poap_uri: String,
+    /// Optional hex encoded root of the Merkle tree of the users allowed to
+    /// use the [`ExecuteMsg::MintWithProof`].
+    pub merkle_root: Option<String>,
+    /// Optional max amount of poaps that can be minted for the event.
+    pub max_supply: Option<u32>,
+    /// Price that has to be paid to mint a poap of the event, if empty the mint is free.
+    pub mint_price: Vec<Coin>,
+    /// URIs used in place of the `poap_uri` for the poaps minted with a specific tier.
+    pub tier_uris: Vec<TierUri>,
+    /// Optional minting phases of the event ordered by time, if empty the
+    /// mint is allowed for the whole event duration.
+    pub phases: Vec<MintPhase>,
+}
+
+#[cw_serde]
+pub struct TierUri {
+    /// Tier of the poap, e.g. speaker, attendee or organizer.
+    pub tier: String,
+    /// Identifies a valid IPFS URI corresponding to where the assets and metadata of the tier's POAPs are stored.
+    pub uri: String,
+}
+
+#[cw_serde]
+pub struct MintPhase {
+    /// Time at which the phase begins.
+    pub start_time: Timestamp,
+    /// Time at which the phase ends.
+    pub end_time: Timestamp,
+    /// Max amount of poap that a single user can mint during the phase.
+    pub per_address_limit: u32,
+    /// Mint messages allowed during the phase.
+    pub mint_path: MintPath,
+}
+
+#[cw_serde]
+pub enum MintPath {
+    /// All the mint messages are allowed.
+    Public,
+    /// Only the [`ExecuteMsg::MintTo`] is allowed.
+    MintToOnly,
+    /// Only the [`ExecuteMsg::MintWithCode`] is allowed.
+    CodeOnly,
+}
+
+/// Lifecycle status of the contract.
+#[cw_serde]
+pub enum ContractStatus {
+    /// The cw721 contract has not been instantiated yet, no action can be performed.
+    Initializing,
+    /// The contract is ready to be used.
+    Ready,
+}
+
+/// Lifecycle status of an event.
+#[cw_serde]
+pub enum Status {
+    /// The cw721 contract has not been instantiated yet, no action can be performed.
+    Initializing,
+    /// The event can be used.
+    Ready,
+    /// The event is ended or has been closed with [`ExecuteMsg::CloseEvent`].
+    Closed,
 }
 
 #[cw_serde]
 pub enum ExecuteMsg {
-    /// Allows the contract's admin to enable the [`ExecuteMsg::Mint`].
-    EnableMint {},
-    /// Allows the contract's admin to disable the [`ExecuteMsg::Mint`].
-    DisableMint {},
-    /// If the mint is enabled, allow the user to mint the poap by themself.
+    /// Allows the contract's admin to enable the [`ExecuteMsg::Mint`] of an event.
+    EnableMint { event_id: Uint64 },
+    /// Allows the contract's admin to disable the [`ExecuteMsg::Mint`] of an event.
+    DisableMint { event_id: Uint64 },
+    /// Allows the contract's admin to create a new event.
+    CreateEvent { event_info: EventInfo },
+    /// If the event's mint is enabled, allow the user to mint the poap of an event by themself.
+    /// It's disabled before the start of the event, after the event's end and for the events
+    /// having a Merkle root.
+    Mint { event_id: Uint64 },
+    /// Allows the contract's admin or the minter to mint a POAP of an event for a specific recipient,
+    /// optionally assigning it a tier that must be one of the event's tiers if it defines any.
     /// It's disabled before the start of the event and after the event's end.
-    Mint {},
-    /// Allows the contract's admin or the minter to mint a POAP for a specific recipient.
+    MintTo {
+        event_id: Uint64,
+        recipient: String,
+        tier: Option<String>,
+    },
+    /// Allows the contract's admin or the minter to mint a POAP of an event for each recipient.
+    /// If `skip_ineligible` is true the recipients that reached their mint limit are skipped
+    /// instead of failing the whole batch. At most [`MAX_MINT_TO_MANY_RECIPIENTS`] recipients are allowed.
     /// It's disabled before the start of the event and after the event's end.
-    MintTo { recipient: String },
+    MintToMany {
+        event_id: Uint64,
+        recipients: Vec<String>,
+        skip_ineligible: bool,
+    },
     /// Message that allows the event's creator to change the time frame of the event
     /// if it's not started or finished.
     UpdateEventInfo {
+        event_id: Uint64,
         start_time: Timestamp,
         end_time: Timestamp,
     },
+    /// Allows the event's creator to postpone the end of an event while it's in progress.
+    ExtendEvent {
+        event_id: Uint64,
+        new_end_time: Timestamp,
+    },
+    /// Allows the event's creator to register a set of claim codes, each one
+    /// identified by its hex encoded sha256 hash.
+    AddClaimCodes {
+        event_id: Uint64,
+        code_hashes: Vec<String>,
+    },
+    /// Allows the event's creator to set, or remove, the hex encoded sha256 hash of
+    /// a secret that can be shared among all the attendees.
+    UpdateClaimSecret {
+        event_id: Uint64,
+        secret_hash: Option<String>,
+    },
+    /// Allows the user to mint the poap of an event by providing a registered claim code
+    /// or the event's shared secret.
+    /// It's disabled before the start of the event and after the event's end.
+    MintWithCode { event_id: Uint64, code: String },
+    /// Allows an allowlisted user to mint the poap of an event by providing the proof
+    /// of their inclusion in the event's Merkle tree.
+    /// It's disabled before the start of the event and after the event's end.
+    MintWithProof {
+        event_id: Uint64,
+        amount: u32,
+        proof: Vec<String>,
+    },
+    /// Allows the contract's admin to set, or remove, the Merkle root of an event
+    /// if it's not started.
+    UpdateMerkleRoot {
+        event_id: Uint64,
+        merkle_root: Option<String>,
+    },
+    /// Allows the user to mint the poap of an event by providing an attestation signed
+    /// by the attestor.
+    /// It's disabled before the start of the event and after the event's end.
+    MintWithSignature {
+        event_id: Uint64,
+        signature: Binary,
+        expiration: Timestamp,
+        nonce: Uint64,
+    },
+    /// Allows the event's creator to update, or remove, the max supply of an event
+    /// if it's not ended.
+    UpdateMaxSupply {
+        event_id: Uint64,
+        max_supply: Option<u32>,
+    },
+    /// Allows the event's creator or the contract's admin to send the mint proceeds
+    /// of an event to `receiver`.
+    ClaimFunds { event_id: Uint64, receiver: String },
+    /// Allows the event's creator or the contract's admin to close an event before its end time.
+    /// Once closed, the event's poaps can't be minted and the event can't be updated.
+    CloseEvent { event_id: Uint64 },
+    /// Allows the event's creator or the contract's admin to revoke a poap,
+    /// burning it and recording the revocation reason.
+    Revoke { token_id: String, reason: String },
+    /// Allows the contract's admin to set, or remove, the attestor public key.
+    UpdateAttestor { attestor_pubkey: Option<Binary> },
     /// Allows the contract's admin to transfer the admin rights to another user.
     UpdateAdmin { new_admin: String },
     /// Allows the contract's admin to transfer the minting rights to another user.
@@ -65,12 +214,51 @@ pub enum QueryMsg {
     /// Returns the configuration info as a [`QueryConfigResponse`].
     #[returns(QueryConfigResponse)]
     Config {},
-    /// Returns the event info as a [`QueryEventInfoResponse`].
+    /// Returns the info of an event as a [`QueryEventInfoResponse`].
     #[returns(QueryEventInfoResponse)]
-    EventInfo {},
-    /// Returns the amount of poaps minted from `user` as [`QueryMintedAmountResponse`].
+    EventInfo { event_id: Uint64 },
+    /// Returns the events ordered by id as a [`QueryEventsResponse`].
+    #[returns(QueryEventsResponse)]
+    Events {
+        start_after: Option<Uint64>,
+        limit: Option<u32>,
+    },
+    /// Returns the claim codes info of an event as a [`QueryClaimCodesResponse`].
+    #[returns(QueryClaimCodesResponse)]
+    ClaimCodes { event_id: Uint64 },
+    /// Returns the mint statistics of an event as a [`QueryStatsResponse`].
+    #[returns(QueryStatsResponse)]
+    Stats { event_id: Uint64 },
+    /// Returns the lifecycle status of an event as a [`QueryStatusResponse`].
+    #[returns(QueryStatusResponse)]
+    Status { event_id: Uint64 },
+    /// Returns the mint proceeds of an event that have not been claimed yet
+    /// as a [`QueryProceedsResponse`].
+    #[returns(QueryProceedsResponse)]
+    Proceeds { event_id: Uint64 },
+    /// Returns the amount of poaps of an event minted from `user` as [`QueryMintedAmountResponse`].
     #[returns(QueryMintedAmountResponse)]
-    MintedAmount { user: String },
+    MintedAmount { event_id: Uint64, user: String },
+    /// Returns the amount of poaps of an event minted from each user as [`QueryMintedAmountsResponse`].
+    #[returns(QueryMintedAmountsResponse)]
+    MintedAmounts {
+        event_id: Uint64,
+        users: Vec<String>,
+    },
+    /// Returns the holders of the poaps of an event ordered by address as [`QueryHoldersResponse`].
+    #[returns(QueryHoldersResponse)]
+    Holders {
+        event_id: Uint64,
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
+    /// Tells if `user` has attended an event, that is if they minted at least a poap of the event,
+    /// as [`QueryHasAttendedResponse`].
+    #[returns(QueryHasAttendedResponse)]
+    HasAttended { event_id: Uint64, user: String },
+    /// Returns the info of a revoked poap as [`QueryRevocationResponse`].
+    #[returns(QueryRevocationResponse)]
+    Revocation { token_id: String },
     /// Returns the nft info with approvals from cw721 contract as a [`AllNftInfoResponse`]
     #[returns(AllNftInfoResponse<Metadata>)]
     AllNftInfo {
@@ -93,28 +281,90 @@ pub struct QueryConfigResponse {
     pub admin: Addr,
     /// Address of the entity that is allowed to use [`ExecuteMsg::MintTo`].
     pub minter: Addr,
-    /// Tells if the users can execute the [`ExecuteMsg::Mint`].
-    pub mint_enabled: bool,
-    /// The maximus number of poap that an user can request.
-    pub per_address_limit: u32,
+    /// Public key of the attestor allowed to sign the [`ExecuteMsg::MintWithSignature`] attestations.
+    pub attestor_pubkey: Option<Binary>,
     /// Id of the cw721 contract that this contract has initialized.
     pub cw721_contract_code: Uint64,
     /// Address of the cw721 contract that this contract is using to
     /// mint the poaps.
     pub cw721_contract: Addr,
+    /// Lifecycle status of the contract.
+    pub status: ContractStatus,
 }
 
 /// Response to [`QueryMsg::EventInfo`].
 #[cw_serde]
 pub struct QueryEventInfoResponse {
+    /// Id of the event.
+    pub event_id: Uint64,
     /// Address of who created the event.
     pub creator: Addr,
     /// Time at which the event starts.
     pub start_time: Timestamp,
     /// Time at which the event ends.
     pub end_time: Timestamp,
+    /// The maximus number of poap that an user can request.
+    pub per_address_limit: u32,
     /// IPFS uri where the event's metadata are stored
     pub poap_uri: String,
+    /// Root of the Merkle tree of the allowlisted users.
+    pub merkle_root: Option<String>,
+    /// Max amount of poaps that can be minted for the event.
+    pub max_supply: Option<u32>,
+    /// Price that has to be paid to mint a poap of the event.
+    pub mint_price: Vec<Coin>,
+    /// URIs of the poaps minted with a specific tier.
+    pub tier_uris: Vec<TierUri>,
+    /// Minting phases of the event.
+    pub phases: Vec<MintPhase>,
+    /// Tells if the users can execute the [`ExecuteMsg::Mint`] for the event.
+    pub mint_enabled: bool,
+}
+
+/// Response to [`QueryMsg::Events`].
+#[cw_serde]
+pub struct QueryEventsResponse {
+    /// List of events ordered by id.
+    pub events: Vec<QueryEventInfoResponse>,
+}
+
+/// Response to [`QueryMsg::ClaimCodes`].
+#[cw_serde]
+pub struct QueryClaimCodesResponse {
+    /// Amount of claim codes registered for the event.
+    pub total_codes: u32,
+    /// Amount of claim codes that can still be used.
+    pub remaining_codes: u32,
+    /// Tells if the event has a shared secret that can be used to mint.
+    pub secret_enabled: bool,
+}
+
+/// Response to [`QueryMsg::Status`].
+#[cw_serde]
+pub struct QueryStatusResponse {
+    /// Lifecycle status of the event.
+    pub status: Status,
+}
+
+/// Response to [`QueryMsg::Stats`].
+#[cw_serde]
+pub struct QueryStatsResponse {
+    /// Amount of poaps minted for the event, including the revoked ones.
+    pub minted: u32,
+    /// Amount of users that received at least a poap of the event.
+    pub unique_holders: u32,
+    /// Amount of poaps of the event that have been revoked.
+    pub revoked: u32,
+    /// Amount of poaps that can still be minted, if the event has a max supply.
+    /// Revoked poaps still count against the max supply.
+    pub remaining_supply: Option<u32>,
+}
+
+/// Response to [`QueryMsg::Proceeds`].
+#[cw_serde]
+pub struct QueryProceedsResponse {
+    /// Mint proceeds of the event that can be claimed.
+    pub amount: Vec<Coin>,
 }
 
 /// Response to [`QueryMsg::MintedAmount`].
@@ -126,30 +376,106 @@ pub struct QueryMintedAmountResponse {
     pub amount: u32,
 }
 
+/// Response to [`QueryMsg::MintedAmounts`].
+#[cw_serde]
+pub struct QueryMintedAmountsResponse {
+    /// Amount of poaps minted from each of the requested users.
+    pub amounts: Vec<QueryMintedAmountResponse>,
+}
+
+/// Response to [`QueryMsg::Holders`].
+#[cw_serde]
+pub struct QueryHoldersResponse {
+    /// Holders of the event's poaps with the amount minted by each one.
+    pub holders: Vec<QueryMintedAmountResponse>,
+}
+
+/// Response to [`QueryMsg::HasAttended`].
+#[cw_serde]
+pub struct QueryHasAttendedResponse {
+    /// Tells if the user minted at least a poap of the event.
+    pub attended: bool,
+    /// Amount of poaps of the event minted from the user.
+    pub amount: u32,
+}
+
+/// Response to [`QueryMsg::Revocation`].
+#[cw_serde]
+pub struct QueryRevocationResponse {
+    /// Id of the revoked poap.
+    pub token_id: String,
+    /// Id of the event the poap belonged to.
+    pub event_id: Uint64,
+    /// Address that received the poap.
+    pub holder: Addr,
+    /// Address that revoked the poap.
+    pub revoked_by: Addr,
+    /// Time at which the poap has been revoked.
+    pub revoked_at: Timestamp,
+    /// Reason of the revocation.
+    pub reason: String,
+}
+
 impl InstantiateMsg {
     /// Checks that the data inside the message are coherent.
     /// NOTE: This function don't checks if the address are valid.
+    pub fn validate(&self) -> Result<(), ContractError> {
+        self.event_info.validate()
+    }
+}
+
+impl EventInfo {
+    /// Checks that the data inside the event info are coherent.
+    /// NOTE: This function don't checks if the creator address is valid.
     pub fn validate(&self) -> Result<(), ContractError> {
         // Check that the end time is after the start time
-        if self.event_info.start_time >= self.event_info.end_time {
+        if self.start_time >= self.end_time {
             return Err(ContractError::StartTimeAfterEndTime {
-                start: self.event_info.start_time.to_owned(),
-                end: self.event_info.end_time.to_owned(),
+                start: self.start_time.to_owned(),
+                end: self.end_time.to_owned(),
             });
         }
 
         // Check per address limit
-        if self.event_info.per_address_limit == 0 {
+        if self.per_address_limit == 0 {
             return Err(ContractError::InvalidPerAddressLimit {});
         }
 
         // Check that the poap uri is a valid IPFS url
-        let poap_uri = Url::parse(&self.event_info.poap_uri)
-            .map_err(|_err| ContractError::InvalidPoapUri {})?;
-        if poap_uri.scheme() != "ipfs" {
-            return Err(ContractError::InvalidPoapUri {});
+        validate_ipfs_uri(&self.poap_uri)?;
+
+        // Check that each tier is unique and has a valid IPFS url
+        for (index, tier_uri) in self.tier_uris.iter().enumerate() {
+            validate_tier(&tier_uri.tier)?;
+            if self.tier_uris[..index]
+                .iter()
+                .any(|other| other.tier == tier_uri.tier)
+            {
+                return Err(ContractError::DuplicatedTier {
+                    tier: tier_uri.tier.clone(),
+                });
+            }
+            validate_ipfs_uri(&tier_uri.uri)?;
+        }
+
+        // Check max supply
+        if self.max_supply == Some(0) {
+            return Err(ContractError::InvalidMaxSupply {});
+        }
+
+        // Check that the mint price doesn't contain zero amounts
+        if self.mint_price.iter().any(|coin| coin.amount.is_zero()) {
+            return Err(ContractError::InvalidMintPrice {});
+        }
+
+        // Check that the merkle root is a valid sha256 hash
+        if let Some(merkle_root) = &self.merkle_root {
+            validate_merkle_root(merkle_root)?;
         }
 
+        // Check the minting phases
+        validate_mint_phases(&self.phases, &self.start_time, &self.end_time)?;
+
         Ok(())
     }
 }
@@ -159,9 +485,11 @@ impl ExecuteMsg {
     /// NOTE: This function don't checks if the address are valid.
     pub fn validate(&self) -> Result<(), ContractError> {
         match &self {
+            ExecuteMsg::CreateEvent { event_info } => event_info.validate(),
             ExecuteMsg::UpdateEventInfo {
                 start_time,
                 end_time,
+                ..
             } => {
                 if start_time >= end_time {
                     Err(ContractError::StartTimeAfterEndTime {
@@ -172,16 +500,130 @@ impl ExecuteMsg {
                     Ok(())
                 }
             }
+            ExecuteMsg::AddClaimCodes { code_hashes, .. } => {
+                if code_hashes.is_empty() {
+                    return Err(ContractError::EmptyClaimCodes {});
+                }
+                code_hashes
+                    .iter()
+                    .try_for_each(|hash| validate_sha256_hash(hash))
+            }
+            ExecuteMsg::UpdateClaimSecret {
+                secret_hash: Some(secret_hash),
+                ..
+            } => validate_sha256_hash(secret_hash),
+            ExecuteMsg::UpdateMerkleRoot {
+                merkle_root: Some(merkle_root),
+                ..
+            } => validate_merkle_root(merkle_root),
+            ExecuteMsg::UpdateMaxSupply {
+                max_supply: Some(0),
+                ..
+            } => Err(ContractError::InvalidMaxSupply {}),
+            ExecuteMsg::MintTo {
+                tier: Some(tier), ..
+            } => validate_tier(tier),
+            ExecuteMsg::MintToMany { recipients, .. } => {
+                if recipients.is_empty() {
+                    return Err(ContractError::EmptyRecipients {});
+                }
+                if recipients.len() > MAX_MINT_TO_MANY_RECIPIENTS {
+                    return Err(ContractError::TooManyRecipients {
+                        value: recipients.len(),
+                        max: MAX_MINT_TO_MANY_RECIPIENTS,
+                    });
+                }
+                Ok(())
+            }
+            ExecuteMsg::Revoke { reason, .. } => {
+                if reason.trim().is_empty() {
+                    return Err(ContractError::InvalidRevocationReason {});
+                }
+                Ok(())
+            }
             _ => Ok(()),
         }
     }
 }
 
+/// Checks that the given string is a valid IPFS url.
+fn validate_ipfs_uri(uri: &str) -> Result<(), ContractError> {
+    let uri = Url::parse(uri).map_err(|_err| ContractError::InvalidPoapUri {})?;
+    if uri.scheme() != "ipfs" {
+        return Err(ContractError::InvalidPoapUri {});
+    }
+    Ok(())
+}
+
+/// Checks that the minting phases are ordered, don't overlap and are
+/// within the event time frame.
+pub(crate) fn validate_mint_phases(
+    phases: &[MintPhase],
+    start_time: &Timestamp,
+    end_time: &Timestamp,
+) -> Result<(), ContractError> {
+    let mut previous_end = start_time;
+    for (index, phase) in phases.iter().enumerate() {
+        if phase.start_time < *previous_end
+            || phase.start_time >= phase.end_time
+            || phase.end_time > *end_time
+            || phase.per_address_limit == 0
+        {
+            return Err(ContractError::InvalidMintPhase {
+                index: index as u32,
+            });
+        }
+        previous_end = &phase.end_time;
+    }
+    Ok(())
+}
+
+/// Checks that the given tier is not empty.
+fn validate_tier(tier: &str) -> Result<(), ContractError> {
+    if tier.trim().is_empty() {
+        return Err(ContractError::InvalidTier {
+            tier: tier.to_string(),
+        });
+    }
+    Ok(())
+}
+
+/// Tells if the given string is a lower case hex encoded sha256 hash.
+fn is_sha256_hash(hash: &str) -> bool {
+    hash.len() == 64
+        && hash
+            .chars()
+            .all(|c| c.is_ascii_digit() || ('a'..='f').contains(&c))
+}
+
+/// Checks that the given string is a valid claim code hash.
+fn validate_sha256_hash(hash: &str) -> Result<(), ContractError> {
+    if !is_sha256_hash(hash) {
+        return Err(ContractError::InvalidClaimCodeHash {
+            hash: hash.to_string(),
+        });
+    }
+    Ok(())
+}
+
+/// Checks that the given string is a valid Merkle root.
+fn validate_merkle_root(root: &str) -> Result<(), ContractError> {
+    if !is_sha256_hash(root) {
+        return Err(ContractError::InvalidMerkleRoot {
+            root: root.to_string(),
+        });
+    }
+    Ok(())
+}
+
 #[cfg(test)]
 mod tests {
-    use crate::msg::{EventInfo, ExecuteMsg, InstantiateMsg};
+    use crate::msg::{
+        EventInfo, ExecuteMsg, InstantiateMsg, MintPath, MintPhase, TierUri,
+        MAX_MINT_TO_MANY_RECIPIENTS,
+    };
     use crate::ContractError;
-    use cosmwasm_std::Timestamp;
+    use cosmwasm_std::{Coin, Timestamp};
     use cw721_base::InstantiateMsg as Cw721InstantiateMsg;
 
     #[test]
@@ -191,18 +633,25 @@ mod tests {
         let msg = InstantiateMsg {
             admin: "".to_string(),
             minter: "".to_string(),
+            attestor_pubkey: None,
             cw721_code_id: 0u64.into(),
             cw721_instantiate_msg: Cw721InstantiateMsg {
                 name: "".to_string(),
                 minter: "".to_string(),
                 symbol: "".to_string(),
             },
+            soulbound: false,
             event_info: EventInfo {
                 creator: "".to_string(),
                 start_time: start.clone(),
                 end_time: end.clone(),
                 per_address_limit: 1,
                 poap_uri: "ipfs://domain.com".to_string(),
+                merkle_root: None,
+                max_supply: None,
+                mint_price: vec![],
+                tier_uris: vec![],
+                phases: vec![],
             },
         };
 
@@ -219,18 +668,25 @@ mod tests {
         let msg = InstantiateMsg {
             admin: "".to_string(),
             minter: "".to_string(),
+            attestor_pubkey: None,
             cw721_code_id: 0u64.into(),
             cw721_instantiate_msg: Cw721InstantiateMsg {
                 name: "".to_string(),
                 minter: "".to_string(),
                 symbol: "".to_string(),
             },
+            soulbound: false,
             event_info: EventInfo {
                 creator: "".to_string(),
                 start_time: start.clone(),
                 end_time: end.clone(),
                 per_address_limit: 1,
                 poap_uri: "ipfs://domain.com".to_string(),
+                merkle_root: None,
+                max_supply: None,
+                mint_price: vec![],
+                tier_uris: vec![],
+                phases: vec![],
             },
         };
 
@@ -245,18 +701,25 @@ mod tests {
         let msg = InstantiateMsg {
             admin: "".to_string(),
             minter: "".to_string(),
+            attestor_pubkey: None,
             cw721_code_id: 0u64.into(),
             cw721_instantiate_msg: Cw721InstantiateMsg {
                 name: "".to_string(),
                 minter: "".to_string(),
                 symbol: "".to_string(),
             },
+            soulbound: false,
             event_info: EventInfo {
                 creator: "".to_string(),
                 start_time: Timestamp::from_seconds(1),
                 end_time: Timestamp::from_seconds(2),
                 per_address_limit: 0,
                 poap_uri: "ipfs://domain.com".to_string(),
+                merkle_root: None,
+                max_supply: None,
+                mint_price: vec![],
+                tier_uris: vec![],
+                phases: vec![],
             },
         };
 
@@ -271,18 +734,25 @@ mod tests {
         let msg = InstantiateMsg {
             admin: "".to_string(),
             minter: "".to_string(),
+            attestor_pubkey: None,
             cw721_code_id: 0u64.into(),
             cw721_instantiate_msg: Cw721InstantiateMsg {
                 name: "".to_string(),
                 minter: "".to_string(),
                 symbol: "".to_string(),
             },
+            soulbound: false,
             event_info: EventInfo {
                 creator: "".to_string(),
                 start_time: Timestamp::from_seconds(1),
                 end_time: Timestamp::from_seconds(2),
                 per_address_limit: 1,
                 poap_uri: "invalid_base_poap_uri".to_string(),
+                merkle_root: None,
+                max_supply: None,
+                mint_price: vec![],
+                tier_uris: vec![],
+                phases: vec![],
             },
         };
 
@@ -297,18 +767,25 @@ mod tests {
         let msg = InstantiateMsg {
             admin: "".to_string(),
             minter: "".to_string(),
+            attestor_pubkey: None,
             cw721_code_id: 0u64.into(),
             cw721_instantiate_msg: Cw721InstantiateMsg {
                 name: "".to_string(),
                 minter: "".to_string(),
                 symbol: "".to_string(),
             },
+            soulbound: false,
             event_info: EventInfo {
                 creator: "".to_string(),
                 start_time: Timestamp::from_seconds(1),
                 end_time: Timestamp::from_seconds(2),
                 per_address_limit: 1,
                 poap_uri: "https://domain.com".to_string(),
+                merkle_root: None,
+                max_supply: None,
+                mint_price: vec![],
+                tier_uris: vec![],
+                phases: vec![],
             },
         };
 
@@ -318,11 +795,334 @@ mod tests {
         );
     }
 
+    #[test]
+    fn create_event_with_invalid_per_address_limit_error() {
+        let msg = ExecuteMsg::CreateEvent {
+            event_info: EventInfo {
+                creator: "".to_string(),
+                start_time: Timestamp::from_seconds(1),
+                end_time: Timestamp::from_seconds(2),
+                per_address_limit: 0,
+                poap_uri: "ipfs://domain.com".to_string(),
+                merkle_root: None,
+                max_supply: None,
+                mint_price: vec![],
+                tier_uris: vec![],
+                phases: vec![],
+            },
+        };
+
+        assert_eq!(
+            ContractError::InvalidPerAddressLimit {},
+            msg.validate().unwrap_err()
+        );
+    }
+
+    #[test]
+    fn create_event_with_start_time_after_end_time_error() {
+        let start = Timestamp::from_seconds(2);
+        let end = Timestamp::from_seconds(1);
+        let msg = ExecuteMsg::CreateEvent {
+            event_info: EventInfo {
+                creator: "".to_string(),
+                start_time: start.clone(),
+                end_time: end.clone(),
+                per_address_limit: 1,
+                poap_uri: "ipfs://domain.com".to_string(),
+                merkle_root: None,
+                max_supply: None,
+                mint_price: vec![],
+                tier_uris: vec![],
+                phases: vec![],
+            },
+        };
+
+        assert_eq!(
+            ContractError::StartTimeAfterEndTime { start, end },
+            msg.validate().unwrap_err()
+        );
+    }
+
+    #[test]
+    fn add_claim_codes_with_empty_codes_error() {
+        let msg = ExecuteMsg::AddClaimCodes {
+            event_id: 1u64.into(),
+            code_hashes: vec![],
+        };
+
+        assert_eq!(
+            ContractError::EmptyClaimCodes {},
+            msg.validate().unwrap_err()
+        );
+    }
+
+    #[test]
+    fn add_claim_codes_with_invalid_hash_error() {
+        let msg = ExecuteMsg::AddClaimCodes {
+            event_id: 1u64.into(),
+            code_hashes: vec!["not_an_hash".to_string()],
+        };
+
+        assert_eq!(
+            ContractError::InvalidClaimCodeHash {
+                hash: "not_an_hash".to_string()
+            },
+            msg.validate().unwrap_err()
+        );
+    }
+
+    #[test]
+    fn update_claim_secret_with_upper_case_hash_error() {
+        let hash = "2CF24DBA5FB0A30E26E83B2AC5B9E29E1B161E5C1FA7425E73043362938B9824".to_string();
+        let msg = ExecuteMsg::UpdateClaimSecret {
+            event_id: 1u64.into(),
+            secret_hash: Some(hash.clone()),
+        };
+
+        assert_eq!(
+            ContractError::InvalidClaimCodeHash { hash },
+            msg.validate().unwrap_err()
+        );
+    }
+
+    #[test]
+    fn create_event_with_invalid_merkle_root_error() {
+        let msg = ExecuteMsg::CreateEvent {
+            event_info: EventInfo {
+                creator: "".to_string(),
+                start_time: Timestamp::from_seconds(1),
+                end_time: Timestamp::from_seconds(2),
+                per_address_limit: 1,
+                poap_uri: "ipfs://domain.com".to_string(),
+                merkle_root: Some("root".to_string()),
+                max_supply: None,
+                mint_price: vec![],
+                tier_uris: vec![],
+                phases: vec![],
+            },
+        };
+
+        assert_eq!(
+            ContractError::InvalidMerkleRoot {
+                root: "root".to_string()
+            },
+            msg.validate().unwrap_err()
+        );
+    }
+
+    #[test]
+    fn create_event_with_invalid_max_supply_error() {
+        let msg = ExecuteMsg::CreateEvent {
+            event_info: EventInfo {
+                creator: "".to_string(),
+                start_time: Timestamp::from_seconds(1),
+                end_time: Timestamp::from_seconds(2),
+                per_address_limit: 1,
+                poap_uri: "ipfs://domain.com".to_string(),
+                merkle_root: None,
+                max_supply: Some(0),
+                mint_price: vec![],
+                tier_uris: vec![],
+                phases: vec![],
+            },
+        };
+
+        assert_eq!(
+            ContractError::InvalidMaxSupply {},
+            msg.validate().unwrap_err()
+        );
+    }
+
+    #[test]
+    fn create_event_with_invalid_mint_price_error() {
+        let msg = ExecuteMsg::CreateEvent {
+            event_info: EventInfo {
+                creator: "".to_string(),
+                start_time: Timestamp::from_seconds(1),
+                end_time: Timestamp::from_seconds(2),
+                per_address_limit: 1,
+                poap_uri: "ipfs://domain.com".to_string(),
+                merkle_root: None,
+                max_supply: None,
+                mint_price: vec![Coin::new(0, "udsm")],
+                tier_uris: vec![],
+                phases: vec![],
+                tier_uris: vec![],
+            },
+        };
+
+        assert_eq!(
+            ContractError::InvalidMintPrice {},
+            msg.validate().unwrap_err()
+        );
+    }
+
+    #[test]
+    fn create_event_with_duplicated_tier_error() {
+        let tier_uri = TierUri {
+            tier: "speaker".to_string(),
+            uri: "ipfs://speaker.com".to_string(),
+        };
+        let msg = ExecuteMsg::CreateEvent {
+            event_info: EventInfo {
+                creator: "".to_string(),
+                start_time: Timestamp::from_seconds(1),
+                end_time: Timestamp::from_seconds(2),
+                per_address_limit: 1,
+                poap_uri: "ipfs://domain.com".to_string(),
+                merkle_root: None,
+                max_supply: None,
+                mint_price: vec![],
+                tier_uris: vec![tier_uri.clone(), tier_uri],
+                phases: vec![],
+            },
+        };
+
+        assert_eq!(
+            ContractError::DuplicatedTier {
+                tier: "speaker".to_string()
+            },
+            msg.validate().unwrap_err()
+        );
+    }
+
+    #[test]
+    fn create_event_with_overlapping_phases_error() {
+        let phase = MintPhase {
+            start_time: Timestamp::from_seconds(1),
+            end_time: Timestamp::from_seconds(3),
+            per_address_limit: 1,
+            mint_path: MintPath::Public,
+        };
+        let msg = ExecuteMsg::CreateEvent {
+            event_info: EventInfo {
+                creator: "".to_string(),
+                start_time: Timestamp::from_seconds(1),
+                end_time: Timestamp::from_seconds(5),
+                per_address_limit: 1,
+                poap_uri: "ipfs://domain.com".to_string(),
+                merkle_root: None,
+                max_supply: None,
+                mint_price: vec![],
+                tier_uris: vec![],
+                phases: vec![
+                    phase.clone(),
+                    MintPhase {
+                        start_time: Timestamp::from_seconds(2),
+                        end_time: Timestamp::from_seconds(5),
+                        ..phase
+                    },
+                ],
+            },
+        };
+
+        assert_eq!(
+            ContractError::InvalidMintPhase { index: 1 },
+            msg.validate().unwrap_err()
+        );
+    }
+
+    #[test]
+    fn mint_to_many_with_too_many_recipients_error() {
+        let msg = ExecuteMsg::MintToMany {
+            event_id: 1u64.into(),
+            recipients: vec!["recipient".to_string(); MAX_MINT_TO_MANY_RECIPIENTS + 1],
+            skip_ineligible: false,
+        };
+
+        assert_eq!(
+            ContractError::TooManyRecipients {
+                value: MAX_MINT_TO_MANY_RECIPIENTS + 1,
+                max: MAX_MINT_TO_MANY_RECIPIENTS,
+            },
+            msg.validate().unwrap_err()
+        );
+
+        let msg = ExecuteMsg::MintToMany {
+            event_id: 1u64.into(),
+            recipients: vec!["recipient".to_string(); MAX_MINT_TO_MANY_RECIPIENTS],
+            skip_ineligible: false,
+        };
+        msg.validate().unwrap();
+    }
+
+    #[test]
+    fn mint_to_many_without_recipients_error() {
+        let msg = ExecuteMsg::MintToMany {
+            event_id: 1u64.into(),
+            recipients: vec![],
+            skip_ineligible: false,
+        };
+
+        assert_eq!(
+            ContractError::EmptyRecipients {},
+            msg.validate().unwrap_err()
+        );
+    }
+
+    #[test]
+    fn revoke_with_empty_reason_error() {
+        let msg = ExecuteMsg::Revoke {
+            token_id: "1".to_string(),
+            reason: " ".to_string(),
+        };
+
+        assert_eq!(
+            ContractError::InvalidRevocationReason {},
+            msg.validate().unwrap_err()
+        );
+    }
+
+    #[test]
+    fn mint_to_with_invalid_tier_error() {
+        let msg = ExecuteMsg::MintTo {
+            event_id: 1u64.into(),
+            recipient: "recipient".to_string(),
+            tier: Some(" ".to_string()),
+        };
+
+        assert_eq!(
+            ContractError::InvalidTier {
+                tier: " ".to_string()
+            },
+            msg.validate().unwrap_err()
+        );
+    }
+
+    #[test]
+    fn update_max_supply_with_invalid_value_error() {
+        let msg = ExecuteMsg::UpdateMaxSupply {
+            event_id: 1u64.into(),
+            max_supply: Some(0),
+        };
+
+        assert_eq!(
+            ContractError::InvalidMaxSupply {},
+            msg.validate().unwrap_err()
+        );
+    }
+
+    #[test]
+    fn update_merkle_root_with_invalid_root_error() {
+        let msg = ExecuteMsg::UpdateMerkleRoot {
+            event_id: 1u64.into(),
+            merkle_root: Some("root".to_string()),
+        };
+
+        assert_eq!(
+            ContractError::InvalidMerkleRoot {
+                root: "root".to_string()
+            },
+            msg.validate().unwrap_err()
+        );
+    }
+
     #[test]
     fn update_event_info_start_time_after_end_time_error() {
         let start = Timestamp::from_seconds(2);
         let end = Timestamp::from_seconds(1);
         let msg = ExecuteMsg::UpdateEventInfo {
+            event_id: 1u64.into(),
             start_time: start.clone(),
             end_time: end.clone(),
         };
@@ -338,6 +1138,7 @@ mod tests {
         let start = Timestamp::from_seconds(1);
         let end = Timestamp::from_seconds(1);
         let msg = ExecuteMsg::UpdateEventInfo {
+            event_id: 1u64.into(),
             start_time: start.clone(),
             end_time: end.clone(),
         };
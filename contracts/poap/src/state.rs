@@ -1,15 +1,16 @@
+use crate::msg::{ContractStatus, MintPhase, TierUri};
 use cosmwasm_schema::cw_serde;
 
-use cosmwasm_std::{Addr, Timestamp};
+use cosmwasm_std::{Addr, Binary, Coin, Empty, Timestamp};
 use cw_storage_plus::{Item, Map};
 
 #[cw_serde]
 pub struct Config {
     pub admin: Addr,
     pub minter: Addr,
-    pub mint_enabled: bool,
-    pub per_address_limit: u32,
     pub cw721_code_id: u64,
+    pub attestor_pubkey: Option<Binary>,
+    pub status: ContractStatus,
 }
 
 #[cw_serde]
@@ -18,7 +19,42 @@ pub struct EventInfo {
     pub creator: Addr,
     pub start_time: Timestamp,
     pub end_time: Timestamp,
+    pub per_address_limit: u32,
     pub poap_uri: String,
+    pub merkle_root: Option<String>,
+    pub max_supply: Option<u32>,
+    pub mint_price: Vec<Coin>,
+    pub tier_uris: Vec<TierUri>,
+    pub phases: Vec<MintPhase>,
+    pub mint_enabled: bool,
+    pub closed: bool,
+}
+
+#[cw_serde]
+#[derive(Default)]
+pub struct EventStats {
+    /// Amount of poaps ever minted, it's not decremented when a poap is revoked.
+    pub minted: u32,
+    pub unique_holders: u32,
+    #[serde(default)]
+    pub revoked: u32,
+}
+
+#[cw_serde]
+#[derive(Default)]
+pub struct ClaimCodesInfo {
+    pub total: u32,
+    pub used: u32,
+    pub secret_hash: Option<String>,
+}
+
+#[cw_serde]
+pub struct Revocation {
+    pub event_id: u64,
+    pub holder: Addr,
+    pub revoked_by: Addr,
+    pub revoked_at: Timestamp,
+    pub reason: String,
 }
 
 #[cw_serde]
@@ -27,10 +63,23 @@ pub struct Metadata {
 }
 
 pub const CONFIG: Item<Config> = Item::new("config");
-pub const EVENT_INFO: Item<EventInfo> = Item::new("event_info");
+pub const EVENTS: Map<u64, EventInfo> = Map::new("events");
+pub const NEXT_EVENT_ID: Item<u64> = Item::new("next_event_id");
 pub const CW721_ADDRESS: Item<Addr> = Item::new("cw721_address");
 pub const NEXT_POAP_ID: Item<u64> = Item::new("nex_poap_id");
-pub const MINTER_ADDRESS: Map<Addr, u32> = Map::new("minter_address");
+pub const MINTER_ADDRESS: Map<(u64, Addr), u32> = Map::new("minter_address");
+/// Map of the amount of poaps minted by each address during a phase of an event.
+pub const PHASE_MINTER_ADDRESS: Map<(u64, u32, Addr), u32> = Map::new("phase_minter_address");
+pub const EVENT_STATS: Map<u64, EventStats> = Map::new("event_stats");
+pub const CLAIM_CODES_INFO: Map<u64, ClaimCodesInfo> = Map::new("claim_codes_info");
+/// Map of the mint proceeds of each event that have not been claimed yet.
+pub const EVENT_PROCEEDS: Map<u64, Vec<Coin>> = Map::new("event_proceeds");
+/// Set of the nonces already used to mint with an attestor signature.
+pub const USED_NONCES: Map<u64, Empty> = Map::new("used_nonces");
+/// Map of the registered claim codes hashes of each event, the value tells if the code has been used.
+pub const CLAIM_CODES: Map<(u64, &str), bool> = Map::new("claim_codes");
+/// Map of the revoked poaps ids to their revocation info.
+pub const REVOCATIONS: Map<&str, Revocation> = Map::new("revocations");
 
 impl EventInfo {
     /// Checks if the event has already started.
@@ -45,6 +94,22 @@ impl EventInfo {
         timestamp >= &self.end_time
     }
 
+    /// Returns the index and the info of the minting phase active at the given time.
+    /// * `timestamp` - Reference time used to find the active phase.
+    pub fn active_phase(&self, timestamp: &Timestamp) -> Option<(u32, &MintPhase)> {
+        self.phases
+            .iter()
+            .enumerate()
+            .find(|(_, phase)| &phase.start_time <= timestamp && timestamp < &phase.end_time)
+            .map(|(index, phase)| (index as u32, phase))
+    }
+
+    /// Checks if the event has been closed, either explicitly or because it's ended.
+    /// * `timestamp` - Reference time used to check if the event is ended.
+    pub fn is_closed(&self, timestamp: &Timestamp) -> bool {
+        self.closed || self.is_ended(timestamp)
+    }
+
     /// Checks if the event is in progress.
     /// * `timestamp` - Reference time used to check if the event is in progress.
     pub fn in_progress(&self, timestamp: &Timestamp) -> bool {
@@ -54,6 +119,7 @@ impl EventInfo {
 
 #[cfg(test)]
 mod tests {
+    use crate::msg::{MintPath, MintPhase};
     use crate::state::EventInfo;
     use cosmwasm_std::{Addr, Timestamp};
 
@@ -62,10 +128,29 @@ mod tests {
             creator: Addr::unchecked(""),
             start_time: Timestamp::from_seconds(start),
             end_time: Timestamp::from_seconds(end),
+            per_address_limit: 1,
             poap_uri: "".to_string(),
+            merkle_root: None,
+            max_supply: None,
+            mint_price: vec![],
+            tier_uris: vec![],
+            phases: vec![],
+            mint_enabled: false,
+            closed: false,
         }
     }
 
+    #[test]
+    fn event_is_closed() {
+        let mut event = mock_event_info(10, 20);
+
+        assert!(!event.is_closed(&Timestamp::from_seconds(15)));
+        assert!(event.is_closed(&Timestamp::from_seconds(20)));
+
+        event.closed = true;
+        assert!(event.is_closed(&Timestamp::from_seconds(15)));
+    }
+
     #[test]
     fn event_is_started() {
         let current_time = Timestamp::from_seconds(300);
@@ -123,4 +208,35 @@ mod tests {
         // Test edge case current time = end time
         assert!(!event_info.in_progress(&event_info.end_time));
     }
+    #[test]
+    fn event_active_phase() {
+        let mut event_info = mock_event_info(100, 400);
+        let phase = MintPhase {
+            start_time: Timestamp::from_seconds(100),
+            end_time: Timestamp::from_seconds(200),
+            per_address_limit: 1,
+            mint_path: MintPath::Public,
+        };
+        event_info.phases = vec![
+            phase.clone(),
+            MintPhase {
+                start_time: Timestamp::from_seconds(300),
+                end_time: Timestamp::from_seconds(400),
+                ..phase.clone()
+            },
+        ];
+
+        assert_eq!(
+            Some((0, &phase)),
+            event_info.active_phase(&Timestamp::from_seconds(100))
+        );
+        // Test edge case current time = phase end time
+        assert_eq!(None, event_info.active_phase(&Timestamp::from_seconds(200)));
+        assert_eq!(
+            Some(1),
+            event_info
+                .active_phase(&Timestamp::from_seconds(350))
+                .map(|(index, _)| index)
+        );
+    }
 }
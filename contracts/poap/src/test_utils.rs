@@ -18,18 +18,25 @@ pub fn get_valid_init_msg(cw721_code_id: u64) -> InstantiateMsg {
     InstantiateMsg {
         admin: ADMIN.to_string(),
         minter: MINTER.to_string(),
+        attestor_pubkey: None,
         cw721_code_id: cw721_code_id.into(),
         cw721_instantiate_msg: Cw721InstantiateMsg {
             name: "test-poap".to_string(),
             symbol: "poap".to_string(),
             minter: "".to_string(),
         },
+        soulbound: false,
         event_info: EventInfo {
             creator: CREATOR.to_string(),
             start_time,
             end_time,
             per_address_limit: 2,
             poap_uri: POAP_URI.to_string(),
+            merkle_root: None,
+            max_supply: None,
+            mint_price: vec![],
+            tier_uris: vec![],
+            phases: vec![],
         },
     }
 }
@@ -2,7 +2,8 @@
 use cosmwasm_std::entry_point;
 use cosmwasm_std::{
     has_coins, to_binary, wasm_execute, wasm_instantiate, Addr, BankMsg, Binary, Coin, Deps,
-    DepsMut, Empty, Env, MessageInfo, Querier, Reply, Response, StdResult, Storage, SubMsg, Uint64,
+    DepsMut, Empty, Env, MessageInfo, Order, Querier, QuerierWrapper, Reply, Response, StdError,
+    StdResult, Storage, SubMsg, Uint64,
 };
 use cw2::set_contract_version;
 use cw721::{AllNftInfoResponse, TokensResponse};
@@ -13,28 +14,53 @@ use cw721_base::{
 use cw721_remarkables::Metadata;
 use cw_utils::parse_reply_instantiate_data;
 use desmos_bindings::{
-    msg::DesmosMsg, posts::querier::PostsQuerier, query::DesmosQuery,
-    reactions::querier::ReactionsQuerier, subspaces::querier::SubspacesQuerier, types::PageRequest,
+    msg::DesmosMsg,
+    posts::{
+        models::{Post, PostReferenceType},
+        querier::PostsQuerier,
+    },
+    query::DesmosQuery,
+    reactions::querier::ReactionsQuerier,
+    subspaces::querier::SubspacesQuerier,
+    types::PageRequest,
 };
+use std::collections::BTreeSet;
 use std::ops::Deref;
+use tips::msg::{QueryMsg as TipsQueryMsg, TipsResponse};
 
 use crate::error::ContractError;
 use crate::msg::{
-    ExecuteMsg, InstantiateMsg, QueryConfigResponse, QueryMsg, QueryRaritiesResponse, Rarity,
+    validate_rarities, EngagementMetric, EngagementWeight, ExecuteMsg, InstantiateMsg, MigrateMsg,
+    QueryConfigResponse, QueryGiftsAcceptedResponse, QueryMsg, QueryOperatorsResponse,
+    QueryPostEngagementResponse, QueryRaritiesResponse, Rarity, RarityEngagementScore,
+    REACTIONS_SCAN_LIMIT, REFERENCES_SCAN_WINDOW,
+};
+use crate::state::{
+    ConfigState, CONFIG, CW721_ADDRESS, GIFTS_ACCEPTED, MINTED_TOKEN, OPERATORS, RARITIES,
 };
-use crate::state::{ConfigState, CONFIG, CW721_ADDRESS, MINTED_TOKEN, RARITIES};
 
 // version info for migration info
 const CONTRACT_NAME: &str = "crates.io:remarkables";
 const CONTRACT_VERSION: &str = env!("CARGO_PKG_VERSION");
 const INSTANTIATE_CW721_REPLY_ID: u64 = 1;
+const REACTIONS_PAGE_SIZE: u64 = 50;
 
 // actions for executing messages
 const ACTION_INSTANTIATE: &str = "instantiate";
 const ACTION_INSTANTIATE_CW721_REPLY: &str = "instantiate_cw721_reply";
+const ACTION_MIGRATE: &str = "migrate";
 const ACTION_MINT: &str = "mint";
+const ACTION_APPROVE_OPERATOR: &str = "approve_operator";
+const ACTION_REVOKE_OPERATOR: &str = "revoke_operator";
+const ACTION_ACCEPT_GIFTS: &str = "accept_gifts";
+const ACTION_REJECT_GIFTS: &str = "reject_gifts";
 const ACTION_UPDATE_ADMIN: &str = "update_admin";
 const ACTION_UPDATE_RARITY_MINT_FEES: &str = "update_rarity_mint_fees";
+const ACTION_ADD_RARITY: &str = "add_rarity";
+const ACTION_UPDATE_RARITY_THRESHOLD: &str = "update_rarity_threshold";
+const ACTION_DEPRECATE_RARITY: &str = "deprecate_rarity";
+const ACTION_UPDATE_RARITY_ENGAGEMENT_FORMULA: &str = "update_rarity_engagement_formula";
+const ACTION_UPDATE_TIPS_CONTRACT: &str = "update_tips_contract";
 const ACTION_CLAIM_FEES: &str = "claim_fees";
 
 // attributes for executing messages
@@ -44,7 +70,12 @@ const ATTRIBUTE_ADMIN: &str = "admin";
 const ATTRIBUTE_CW721_CODE_ID: &str = "cw721_code_id";
 const ATTRIBUTE_NEW_ADMIN: &str = "new_admin";
 const ATTRIBUTE_RARITY_LEVEL: &str = "rarity_level";
+const ATTRIBUTE_RARITY_NAME: &str = "rarity_name";
+const ATTRIBUTE_NEW_THRESHOLD: &str = "new_threshold";
+const ATTRIBUTE_TIPS_CONTRACT: &str = "tips_contract";
 const ATTRIBUTE_RECIPIENT: &str = "recipient";
+const ATTRIBUTE_AUTHOR: &str = "author";
+const ATTRIBUTE_OPERATOR: &str = "operator";
 const ATTRIBUTE_TOKEN_ID: &str = "token_id";
 const ATTRIBUTE_TOKEN_URI: &str = "token_uri";
 const ATTRIBUTE_RECEIVER: &str = "receiver";
@@ -59,12 +90,17 @@ pub fn instantiate(
     msg.validate()?;
     // Save the config
     let admin_addr = deps.api.addr_validate(&msg.admin)?;
+    let tips_contract = msg
+        .tips_contract
+        .map(|address| deps.api.addr_validate(&address))
+        .transpose()?;
     CONFIG.save(
         deps.storage,
         &ConfigState {
             admin: admin_addr,
             subspace_id: msg.subspace_id.into(),
             cw721_code_id: msg.cw721_code_id.into(),
+            tips_contract,
         },
     )?;
     // Save the info of rarities
@@ -116,12 +152,39 @@ pub fn execute(
             post_id,
             remarkables_uri,
             rarity_level,
-        } => execute_mint(deps, info, rarity_level, post_id.into(), remarkables_uri),
+            recipient,
+        } => execute_mint(
+            deps,
+            info,
+            rarity_level,
+            post_id.into(),
+            remarkables_uri,
+            recipient,
+        ),
+        ExecuteMsg::ApproveOperator { operator } => execute_approve_operator(deps, info, operator),
+        ExecuteMsg::RevokeOperator { operator } => execute_revoke_operator(deps, info, operator),
+        ExecuteMsg::AcceptGifts {} => execute_accept_gifts(deps, info),
+        ExecuteMsg::RejectGifts {} => execute_reject_gifts(deps, info),
         ExecuteMsg::UpdateAdmin { new_admin } => execute_update_admin(deps, info, new_admin),
         ExecuteMsg::UpdateRarityMintFees {
             rarity_level,
             new_fees,
         } => execute_update_rarity_mint_fees(deps, info, rarity_level, new_fees),
+        ExecuteMsg::AddRarity { rarity } => execute_add_rarity(deps, info, rarity),
+        ExecuteMsg::UpdateRarityThreshold {
+            rarity_level,
+            new_threshold,
+        } => execute_update_rarity_threshold(deps, info, rarity_level, new_threshold),
+        ExecuteMsg::UpdateRarityEngagementFormula {
+            rarity_level,
+            engagement_formula,
+        } => execute_update_rarity_engagement_formula(deps, info, rarity_level, engagement_formula),
+        ExecuteMsg::UpdateTipsContract { tips_contract } => {
+            execute_update_tips_contract(deps, info, tips_contract)
+        }
+        ExecuteMsg::DeprecateRarity { rarity_level } => {
+            execute_deprecate_rarity(deps, info, rarity_level)
+        }
         ExecuteMsg::ClaimFees { receiver } => execute_claim_fees(deps, env, info, receiver),
     }
 }
@@ -131,7 +194,8 @@ fn execute_mint(
     info: MessageInfo,
     rarity_level: u32,
     post_id: u64,
-    remarkables_uri: String,
+    remarkables_uri: Option<String>,
+    recipient: Option<String>,
 ) -> Result<Response<DesmosMsg>, ContractError> {
     let rarities = RARITIES.load(deps.storage)?;
     let rarity = rarities
@@ -139,18 +203,51 @@ fn execute_mint(
         .ok_or(ContractError::RarityNotExists {
             level: rarity_level,
         })?;
+    if rarity.deprecated {
+        return Err(ContractError::RarityDeprecated {
+            level: rarity_level,
+        });
+    }
     // Check if rarity mint fees is enough
     if !is_enough_fees(info.funds, &rarity.mint_fees) {
         return Err(ContractError::MintFeesNotEnough {});
     }
+    // Check if the post exists
+    let config = CONFIG.load(deps.storage)?;
+    let post = load_post(deps.querier.deref(), config.subspace_id, post_id)?;
+    // Only the author and their operators can choose the recipient,
+    // anyone else can only gift the Remarkables to the author.
+    let recipient = match recipient {
+        Some(recipient) => deps.api.addr_validate(&recipient)?,
+        None => post.author.clone(),
+    };
+    let authorized_minter = is_authorized_minter(deps.storage, &post.author, &info.sender);
+    if recipient != post.author && !authorized_minter {
+        return Err(ContractError::MinterNotAuthorized {
+            minter: info.sender.into(),
+            author: post.author.into(),
+        });
+    }
+    // Gifts are opt-in, otherwise anyone could mint the token of a rarity before the author.
+    if !authorized_minter && !GIFTS_ACCEPTED.has(deps.storage, &post.author) {
+        return Err(ContractError::GiftsNotAccepted {
+            author: post.author.into(),
+        });
+    }
+    // Gifts always use the rarity's uri template, so that the gifter can't
+    // attach arbitrary metadata to a token owned by the author.
+    if !authorized_minter && remarkables_uri.is_some() {
+        return Err(ContractError::GiftRemarkablesUriNotAllowed {
+            minter: info.sender.into(),
+        });
+    }
+    let remarkables_uri = remarkables_uri
+        .or_else(|| rarity.render_uri(post_id))
+        .ok_or(ContractError::RemarkablesUriNotProvided {
+            level: rarity_level,
+        })?;
     // Check if post reaches the eligible threshold
-    check_eligibility(
-        deps.storage,
-        deps.querier.deref(),
-        info.sender.clone(),
-        post_id,
-        rarity.engagement_threshold,
-    )?;
+    check_eligibility(deps.querier.deref(), &config, &post, rarity)?;
     // Check if token has been minted or not
     let token_id = convert_post_id_to_token_id(post_id, rarity_level);
     if MINTED_TOKEN
@@ -163,12 +260,13 @@ fn execute_mint(
     // Create the cw721 message to send to mint the remarkables
     let mint_msg = Cw721ExecuteMsg::<Metadata, Empty>::Mint(MintMsg::<Metadata> {
         token_id: token_id.clone(),
-        owner: info.sender.clone().into(),
+        owner: recipient.clone().into(),
         token_uri: Some(remarkables_uri.clone()),
         extension: Metadata {
             rarity_level,
-            subspace_id: CONFIG.load(deps.storage)?.subspace_id,
+            subspace_id: config.subspace_id,
             post_id,
+            author: Some(post.author.clone()),
         },
     });
     let wasm_execute_mint_msg = wasm_execute(CW721_ADDRESS.load(deps.storage)?, &mint_msg, vec![])?;
@@ -177,7 +275,8 @@ fn execute_mint(
         .add_attribute(ATTRIBUTE_SENDER, &info.sender)
         .add_attribute(ATTRIBUTE_RARITY_LEVEL, rarity_level.to_string())
         .add_attribute(ATTRIBUTE_TOKEN_ID, token_id)
-        .add_attribute(ATTRIBUTE_RECIPIENT, &info.sender)
+        .add_attribute(ATTRIBUTE_AUTHOR, &post.author)
+        .add_attribute(ATTRIBUTE_RECIPIENT, &recipient)
         .add_attribute(ATTRIBUTE_TOKEN_URI, remarkables_uri)
         .add_message(wasm_execute_mint_msg))
 }
@@ -201,49 +300,154 @@ fn is_enough_fees(funds: Vec<Coin>, requireds: &Vec<Coin>) -> bool {
     true
 }
 
-/// Checks that the post reaches the engagement threshold.
+/// Checks if the minter is the author or an operator approved by them.
+fn is_authorized_minter(storage: &dyn Storage, author: &Addr, minter: &Addr) -> bool {
+    author == minter || OPERATORS.has(storage, (author, minter))
+}
+
+/// Checks that the post reaches the engagement threshold of the rarity.
 fn check_eligibility(
-    storage: &dyn Storage,
     querier: &dyn Querier,
-    sender: Addr,
-    post_id: u64,
-    engagement_threshold: u32,
+    config: &ConfigState,
+    post: &Post,
+    rarity: &Rarity,
 ) -> Result<(), ContractError> {
-    let subspace_id = CONFIG.load(storage)?.subspace_id;
-    // Check if the post exists and it is owned by sender.
-    let post = PostsQuerier::new(querier)
+    let score = PostEngagement::new(querier, config, post).score(rarity)?;
+    if rarity.engagement_threshold as u64 > score {
+        return Err(ContractError::NoEligibilityError {});
+    }
+    Ok(())
+}
+
+/// Returns the post with the given id inside the subspace.
+fn load_post(querier: &dyn Querier, subspace_id: u64, post_id: u64) -> Result<Post, ContractError> {
+    Ok(PostsQuerier::new(querier)
         .query_post(subspace_id, post_id)
         .map_err(|_| ContractError::PostNotFound { id: post_id })?
-        .post;
-    if post.author != sender {
-        return Err(ContractError::MinterNotPostAuthor {
-            minter: sender.into(),
-            author: post.author.into(),
-        });
+        .post)
+}
+
+/// Computes the engagement score of a post, caching the metric values
+/// so that they are queried only once when scoring the post for many rarities.
+struct PostEngagement<'a> {
+    querier: &'a dyn Querier,
+    config: &'a ConfigState,
+    post: &'a Post,
+    values: Vec<(EngagementMetric, u64)>,
+}
+
+impl<'a> PostEngagement<'a> {
+    fn new(querier: &'a dyn Querier, config: &'a ConfigState, post: &'a Post) -> Self {
+        PostEngagement {
+            querier,
+            config,
+            post,
+            values: vec![],
+        }
     }
-    // Check if the reactions of the post is larger than the threshold.
-    let total_reactions_count = ReactionsQuerier::new(querier)
-        .query_reactions(
-            subspace_id,
-            post_id,
-            None,
-            Some(PageRequest {
-                key: None,
-                offset: None,
-                limit: Uint64::new(1),
-                count_total: true,
-                reverse: false,
-            }),
-        )?
-        .pagination
-        .unwrap_or_default()
-        .total
-        .unwrap_or(Uint64::zero());
-    let self_reactions_count = ReactionsQuerier::new(querier)
+
+    /// Returns the engagement score of the post computed with the formula of the rarity.
+    fn score(&mut self, rarity: &Rarity) -> Result<u64, ContractError> {
+        let mut score = Uint64::zero();
+        for weight in rarity.engagement_formula() {
+            let value = self.metric_value(&weight.metric)?;
+            score = score.checked_add(Uint64::new(value).checked_mul(weight.weight.into())?)?;
+        }
+        Ok(score.u64())
+    }
+
+    /// Returns the value of the given metric for the post.
+    fn metric_value(&mut self, metric: &EngagementMetric) -> Result<u64, ContractError> {
+        if let Some((_, value)) = self.values.iter().find(|(cached, _)| cached == metric) {
+            return Ok(*value);
+        }
+        let subspace_id = self.config.subspace_id;
+        let post_id = self.post.id.u64();
+        let value = match metric {
+            EngagementMetric::Reactions => {
+                let total_reactions_count =
+                    count_reactions(self.querier, subspace_id, post_id, None)?;
+                let self_reactions_count = count_reactions(
+                    self.querier,
+                    subspace_id,
+                    post_id,
+                    Some(self.post.author.clone()),
+                )?;
+                total_reactions_count
+                    .checked_sub(self_reactions_count)?
+                    .u64()
+            }
+            EngagementMetric::UniqueReactors => {
+                let mut reactors = BTreeSet::new();
+                // The scanned reactions are capped so that the gas used doesn't grow with the post popularity
+                for reaction in ReactionsQuerier::new(self.querier)
+                    .iterate_reactions(subspace_id, post_id, None, REACTIONS_PAGE_SIZE)
+                    .take(REACTIONS_SCAN_LIMIT)
+                {
+                    let reaction = reaction?;
+                    if reaction.author != self.post.author {
+                        reactors.insert(reaction.author);
+                    }
+                }
+                reactors.len() as u64
+            }
+            EngagementMetric::Replies => self.count_references(PostReferenceType::Replay),
+            EngagementMetric::Quotes => self.count_references(PostReferenceType::Quote),
+            EngagementMetric::Tips => {
+                let tips_contract = self
+                    .config
+                    .tips_contract
+                    .as_ref()
+                    .ok_or(ContractError::TipsContractNotConfigured {})?;
+                let response: TipsResponse = QuerierWrapper::<DesmosQuery>::new(self.querier)
+                    .query_wasm_smart(
+                        tips_contract,
+                        &TipsQueryMsg::PostReceivedTips {
+                            post_id: post_id.into(),
+                        },
+                    )?;
+                response
+                    .tips
+                    .iter()
+                    .filter(|tip| tip.sender != self.post.author)
+                    .count() as u64
+            }
+        };
+        self.values.push((metric.clone(), value));
+        Ok(value)
+    }
+
+    /// Returns the amount of posts referencing the post with the given type, excluding the ones created by its author.
+    /// Since a post can only reference older posts, only the [`REFERENCES_SCAN_WINDOW`] posts
+    /// created right after it are scanned, so that the gas used doesn't grow with the subspace.
+    fn count_references(&self, ref_type: PostReferenceType) -> u64 {
+        let querier = PostsQuerier::new(self.querier);
+        let first_post_id = self.post.id.u64().saturating_add(1);
+        (first_post_id..first_post_id.saturating_add(REFERENCES_SCAN_WINDOW))
+            // Deleted and not yet created posts can't be found, so they are skipped
+            .filter_map(|post_id| querier.query_post(self.config.subspace_id, post_id).ok())
+            .filter(|response| {
+                response.post.author != self.post.author
+                    && response.post.referenced_posts.iter().any(|reference| {
+                        reference.ref_type == ref_type && reference.post_id == self.post.id
+                    })
+            })
+            .count() as u64
+    }
+}
+
+/// Returns the amount of reactions to the post, optionally only the ones added by the given user.
+fn count_reactions(
+    querier: &dyn Querier,
+    subspace_id: u64,
+    post_id: u64,
+    user: Option<Addr>,
+) -> StdResult<Uint64> {
+    Ok(ReactionsQuerier::new(querier)
         .query_reactions(
             subspace_id,
             post_id,
-            Some(sender),
+            user,
             Some(PageRequest {
                 key: None,
                 offset: None,
@@ -255,15 +459,79 @@ fn check_eligibility(
         .pagination
         .unwrap_or_default()
         .total
-        .unwrap_or(Uint64::zero());
-    if engagement_threshold as u64
-        > (total_reactions_count.checked_sub(self_reactions_count)?).into()
-    {
-        return Err(ContractError::NoEligibilityError {});
+        .unwrap_or(Uint64::zero()))
+}
+
+/// Checks that a tips contract is configured if any of the rarities counts the tips.
+fn check_tips_contract(config: &ConfigState, rarities: &[Rarity]) -> Result<(), ContractError> {
+    if config.tips_contract.is_none() && rarities.iter().any(Rarity::uses_tips) {
+        return Err(ContractError::TipsContractNotConfigured {});
     }
     Ok(())
 }
 
+fn execute_approve_operator(
+    deps: DepsMut<DesmosQuery>,
+    info: MessageInfo,
+    operator: String,
+) -> Result<Response<DesmosMsg>, ContractError> {
+    let operator = deps.api.addr_validate(&operator)?;
+    if OPERATORS.has(deps.storage, (&info.sender, &operator)) {
+        return Err(ContractError::OperatorAlreadyApproved { operator });
+    }
+    OPERATORS.save(deps.storage, (&info.sender, &operator), &true)?;
+    Ok(Response::new()
+        .add_attribute(ATTRIBUTE_ACTION, ACTION_APPROVE_OPERATOR)
+        .add_attribute(ATTRIBUTE_SENDER, &info.sender)
+        .add_attribute(ATTRIBUTE_OPERATOR, &operator))
+}
+
+fn execute_revoke_operator(
+    deps: DepsMut<DesmosQuery>,
+    info: MessageInfo,
+    operator: String,
+) -> Result<Response<DesmosMsg>, ContractError> {
+    let operator = deps.api.addr_validate(&operator)?;
+    if !OPERATORS.has(deps.storage, (&info.sender, &operator)) {
+        return Err(ContractError::OperatorNotApproved { operator });
+    }
+    OPERATORS.remove(deps.storage, (&info.sender, &operator));
+    Ok(Response::new()
+        .add_attribute(ATTRIBUTE_ACTION, ACTION_REVOKE_OPERATOR)
+        .add_attribute(ATTRIBUTE_SENDER, &info.sender)
+        .add_attribute(ATTRIBUTE_OPERATOR, &operator))
+}
+
+fn execute_accept_gifts(
+    deps: DepsMut<DesmosQuery>,
+    info: MessageInfo,
+) -> Result<Response<DesmosMsg>, ContractError> {
+    if GIFTS_ACCEPTED.has(deps.storage, &info.sender) {
+        return Err(ContractError::GiftsAlreadyAccepted {
+            author: info.sender.into(),
+        });
+    }
+    GIFTS_ACCEPTED.save(deps.storage, &info.sender, &true)?;
+    Ok(Response::new()
+        .add_attribute(ATTRIBUTE_ACTION, ACTION_ACCEPT_GIFTS)
+        .add_attribute(ATTRIBUTE_SENDER, &info.sender))
+}
+
+fn execute_reject_gifts(
+    deps: DepsMut<DesmosQuery>,
+    info: MessageInfo,
+) -> Result<Response<DesmosMsg>, ContractError> {
+    if !GIFTS_ACCEPTED.has(deps.storage, &info.sender) {
+        return Err(ContractError::GiftsNotAccepted {
+            author: info.sender.into(),
+        });
+    }
+    GIFTS_ACCEPTED.remove(deps.storage, &info.sender);
+    Ok(Response::new()
+        .add_attribute(ATTRIBUTE_ACTION, ACTION_REJECT_GIFTS)
+        .add_attribute(ATTRIBUTE_SENDER, &info.sender))
+}
+
 fn execute_update_admin(
     deps: DepsMut<DesmosQuery>,
     info: MessageInfo,
@@ -306,6 +574,114 @@ fn execute_update_rarity_mint_fees(
         .add_attribute(ATTRIBUTE_RARITY_LEVEL, level.to_string()))
 }
 
+fn execute_add_rarity(
+    deps: DepsMut<DesmosQuery>,
+    info: MessageInfo,
+    rarity: Rarity,
+) -> Result<Response<DesmosMsg>, ContractError> {
+    check_admin(deps.storage, &info)?;
+    let name = rarity.name.clone();
+    let mut rarities = RARITIES.load(deps.storage)?;
+    rarities.push(rarity);
+    validate_rarities(&rarities)?;
+    check_tips_contract(&CONFIG.load(deps.storage)?, &rarities)?;
+    RARITIES.save(deps.storage, &rarities)?;
+    Ok(Response::new()
+        .add_attribute(ATTRIBUTE_ACTION, ACTION_ADD_RARITY)
+        .add_attribute(ATTRIBUTE_SENDER, &info.sender)
+        .add_attribute(ATTRIBUTE_RARITY_LEVEL, (rarities.len() - 1).to_string())
+        .add_attribute(ATTRIBUTE_RARITY_NAME, name))
+}
+
+fn execute_update_rarity_threshold(
+    deps: DepsMut<DesmosQuery>,
+    info: MessageInfo,
+    level: u32,
+    new_threshold: u32,
+) -> Result<Response<DesmosMsg>, ContractError> {
+    check_admin(deps.storage, &info)?;
+    RARITIES.update(deps.storage, |mut rarities| -> Result<_, ContractError> {
+        rarities
+            .get_mut(level as usize)
+            .ok_or(ContractError::RarityNotExists { level })?
+            .engagement_threshold = new_threshold;
+        validate_rarities(&rarities)?;
+        Ok(rarities)
+    })?;
+    Ok(Response::new()
+        .add_attribute(ATTRIBUTE_ACTION, ACTION_UPDATE_RARITY_THRESHOLD)
+        .add_attribute(ATTRIBUTE_SENDER, &info.sender)
+        .add_attribute(ATTRIBUTE_RARITY_LEVEL, level.to_string())
+        .add_attribute(ATTRIBUTE_NEW_THRESHOLD, new_threshold.to_string()))
+}
+
+fn execute_update_rarity_engagement_formula(
+    deps: DepsMut<DesmosQuery>,
+    info: MessageInfo,
+    level: u32,
+    engagement_formula: Option<Vec<EngagementWeight>>,
+) -> Result<Response<DesmosMsg>, ContractError> {
+    check_admin(deps.storage, &info)?;
+    let config = CONFIG.load(deps.storage)?;
+    RARITIES.update(deps.storage, |mut rarities| -> Result<_, ContractError> {
+        rarities
+            .get_mut(level as usize)
+            .ok_or(ContractError::RarityNotExists { level })?
+            .engagement_formula = engagement_formula;
+        validate_rarities(&rarities)?;
+        check_tips_contract(&config, &rarities)?;
+        Ok(rarities)
+    })?;
+    Ok(Response::new()
+        .add_attribute(ATTRIBUTE_ACTION, ACTION_UPDATE_RARITY_ENGAGEMENT_FORMULA)
+        .add_attribute(ATTRIBUTE_SENDER, &info.sender)
+        .add_attribute(ATTRIBUTE_RARITY_LEVEL, level.to_string()))
+}
+
+fn execute_update_tips_contract(
+    deps: DepsMut<DesmosQuery>,
+    info: MessageInfo,
+    tips_contract: Option<String>,
+) -> Result<Response<DesmosMsg>, ContractError> {
+    check_admin(deps.storage, &info)?;
+    let tips_contract = tips_contract
+        .map(|address| deps.api.addr_validate(&address))
+        .transpose()?;
+    let mut config = CONFIG.load(deps.storage)?;
+    config.tips_contract = tips_contract;
+    check_tips_contract(&config, &RARITIES.load(deps.storage)?)?;
+    CONFIG.save(deps.storage, &config)?;
+    Ok(Response::new()
+        .add_attribute(ATTRIBUTE_ACTION, ACTION_UPDATE_TIPS_CONTRACT)
+        .add_attribute(ATTRIBUTE_SENDER, &info.sender)
+        .add_attribute(
+            ATTRIBUTE_TIPS_CONTRACT,
+            config.tips_contract.map(String::from).unwrap_or_default(),
+        ))
+}
+
+fn execute_deprecate_rarity(
+    deps: DepsMut<DesmosQuery>,
+    info: MessageInfo,
+    level: u32,
+) -> Result<Response<DesmosMsg>, ContractError> {
+    check_admin(deps.storage, &info)?;
+    RARITIES.update(deps.storage, |mut rarities| -> Result<_, ContractError> {
+        let rarity = rarities
+            .get_mut(level as usize)
+            .ok_or(ContractError::RarityNotExists { level })?;
+        if rarity.deprecated {
+            return Err(ContractError::RarityDeprecated { level });
+        }
+        rarity.deprecated = true;
+        Ok(rarities)
+    })?;
+    Ok(Response::new()
+        .add_attribute(ATTRIBUTE_ACTION, ACTION_DEPRECATE_RARITY)
+        .add_attribute(ATTRIBUTE_SENDER, &info.sender)
+        .add_attribute(ATTRIBUTE_RARITY_LEVEL, level.to_string()))
+}
+
 fn execute_claim_fees(
     deps: DepsMut<DesmosQuery>,
     env: Env,
@@ -343,6 +719,11 @@ pub fn query(deps: Deps<DesmosQuery>, _env: Env, msg: QueryMsg) -> StdResult<Bin
     match msg {
         QueryMsg::Config {} => to_binary(&query_config(deps)?),
         QueryMsg::Rarities {} => to_binary(&query_rarities(deps)?),
+        QueryMsg::PostEngagement { post_id } => {
+            to_binary(&query_post_engagement(deps, post_id.u64())?)
+        }
+        QueryMsg::Operators { author } => to_binary(&query_operators(deps, author)?),
+        QueryMsg::GiftsAccepted { author } => to_binary(&query_gifts_accepted(deps, author)?),
         QueryMsg::AllNftInfo {
             token_id,
             include_expired,
@@ -363,6 +744,7 @@ fn query_config(deps: Deps<DesmosQuery>) -> StdResult<QueryConfigResponse> {
         cw721_code_id: config.cw721_code_id.into(),
         cw721_address,
         subspace_id: config.subspace_id.into(),
+        tips_contract: config.tips_contract,
     })
 }
 
@@ -371,6 +753,57 @@ fn query_rarities(deps: Deps<DesmosQuery>) -> StdResult<QueryRaritiesResponse> {
     Ok(QueryRaritiesResponse { rarities })
 }
 
+fn query_post_engagement(
+    deps: Deps<DesmosQuery>,
+    post_id: u64,
+) -> StdResult<QueryPostEngagementResponse> {
+    let config = CONFIG.load(deps.storage)?;
+    let rarities = RARITIES.load(deps.storage)?;
+    let post = load_post(deps.querier.deref(), config.subspace_id, post_id)
+        .map_err(|err| StdError::generic_err(err.to_string()))?;
+    let mut engagement = PostEngagement::new(deps.querier.deref(), &config, &post);
+    let mut scores = Vec::with_capacity(rarities.len());
+    for (level, rarity) in rarities.iter().enumerate() {
+        let score = engagement
+            .score(rarity)
+            .map_err(|err| StdError::generic_err(err.to_string()))?;
+        scores.push(RarityEngagementScore {
+            rarity_level: level as u32,
+            score,
+            qualified: !rarity.deprecated && score >= rarity.engagement_threshold as u64,
+        });
+    }
+    let qualified_rarity_levels = scores
+        .iter()
+        .filter(|score| score.qualified)
+        .map(|score| score.rarity_level)
+        .collect();
+    Ok(QueryPostEngagementResponse {
+        post_id: post_id.into(),
+        scores,
+        qualified_rarity_levels,
+    })
+}
+
+fn query_operators(deps: Deps<DesmosQuery>, author: String) -> StdResult<QueryOperatorsResponse> {
+    let author = deps.api.addr_validate(&author)?;
+    let operators = OPERATORS
+        .prefix(&author)
+        .keys(deps.storage, None, None, Order::Ascending)
+        .collect::<StdResult<Vec<Addr>>>()?;
+    Ok(QueryOperatorsResponse { operators })
+}
+
+fn query_gifts_accepted(
+    deps: Deps<DesmosQuery>,
+    author: String,
+) -> StdResult<QueryGiftsAcceptedResponse> {
+    let author = deps.api.addr_validate(&author)?;
+    Ok(QueryGiftsAcceptedResponse {
+        accepted: GIFTS_ACCEPTED.has(deps.storage, &author),
+    })
+}
+
 fn query_all_nft_info(
     deps: Deps<DesmosQuery>,
     token_id: String,
@@ -423,6 +856,25 @@ pub fn reply(
     }
 }
 
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn migrate(
+    deps: DepsMut<DesmosQuery>,
+    _env: Env,
+    _msg: MigrateMsg,
+) -> Result<Response<DesmosMsg>, ContractError> {
+    set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
+    // Name the rarities stored before the rarities had a name after their level
+    RARITIES.update(deps.storage, |mut rarities| -> StdResult<_> {
+        for (level, rarity) in rarities.iter_mut().enumerate() {
+            if rarity.name.trim().is_empty() {
+                rarity.name = format!("level_{}", level);
+            }
+        }
+        Ok(rarities)
+    })?;
+    Ok(Response::new().add_attribute(ATTRIBUTE_ACTION, ACTION_MIGRATE))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -431,15 +883,20 @@ mod tests {
         mock_env, mock_info, MockApi, MockQuerier, MockStorage, MOCK_CONTRACT_ADDR,
     };
     use cosmwasm_std::{
-        coins, from_binary, to_binary, ContractResult, OwnedDeps, StdError, SubMsgResponse,
-        SubMsgResult, SystemError, SystemResult,
+        coins, from_binary, to_binary, Attribute, ContractResult, OwnedDeps, StdError,
+        SubMsgResponse, SubMsgResult, SystemError, SystemResult, WasmQuery,
     };
     use cw721_base::InstantiateMsg as Cw721InstantiateMsg;
     use desmos_bindings::mocks::mock_queriers::{
         mock_desmos_dependencies, mock_desmos_dependencies_with_custom_querier, MockDesmosQuerier,
     };
     use desmos_bindings::{
-        posts::{mocks::mock_posts_query_response, query::PostsQuery},
+        posts::{
+            mocks::{mock_posts_query_response, MockPostsQueries},
+            models::PostReference,
+            models_query::QueryPostResponse,
+            query::PostsQuery,
+        },
         reactions::{
             mocks::mock_reactions_query_response, models_query::QueryReactionsResponse,
             query::ReactionsQuery,
@@ -448,6 +905,7 @@ mod tests {
         types::PageResponse,
     };
     use std::marker::PhantomData;
+    use tips::msg::Tip;
 
     const ADMIN: &str = "cosmos17qcf9sv5yk0ly5vt3ztev70nwf6c5sprkwfh8t";
     const USER: &str = "desmos1nwp8gxrnmrsrzjdhvk47vvmthzxjtphgxp5ftc";
@@ -463,8 +921,12 @@ mod tests {
 
     fn get_instantiate_rarities() -> Vec<Rarity> {
         vec![Rarity {
+            name: "common".into(),
             engagement_threshold: ENGAGEMENT_THRESHOLD,
             mint_fees: coins(MINT_FEES, DENOM),
+            uri_template: None,
+            engagement_formula: None,
+            deprecated: false,
         }]
     }
     fn get_valid_instantiate_msg() -> InstantiateMsg {
@@ -478,6 +940,7 @@ mod tests {
             },
             subspace_id: SUBSPACE_ID.into(),
             rarities: get_instantiate_rarities(),
+            tips_contract: None,
         }
     }
     fn do_instantiate(deps: DepsMut<DesmosQuery>) {
@@ -555,6 +1018,7 @@ mod tests {
                 admin: Addr::unchecked(ADMIN),
                 cw721_code_id: CW721_CODE_ID,
                 subspace_id: SUBSPACE_ID,
+                tips_contract: None,
             };
             assert_eq!(config, expected_config);
 
@@ -612,8 +1076,9 @@ mod tests {
             let info = mock_info(USER, &vec![]);
             let msg = ExecuteMsg::Mint {
                 post_id: 1u64.into(),
-                remarkables_uri: "ipfs://test.com".into(),
+                remarkables_uri: Some("ipfs://test.com".into()),
                 rarity_level: 2,
+                recipient: None,
             };
             assert_eq!(
                 execute(deps.as_mut(), env, info, msg).unwrap_err(),
@@ -628,8 +1093,9 @@ mod tests {
             let info = mock_info(USER, &vec![]);
             let msg = ExecuteMsg::Mint {
                 post_id: 1u64.into(),
-                remarkables_uri: "ipfs://test.com".into(),
+                remarkables_uri: Some("ipfs://test.com".into()),
                 rarity_level: RARITY_LEVEL,
+                recipient: None,
             };
             assert_eq!(
                 execute(deps.as_mut(), env, info, msg).unwrap_err(),
@@ -644,8 +1110,9 @@ mod tests {
             let info = mock_info(USER, &coins(100, "other"));
             let msg = ExecuteMsg::Mint {
                 post_id: 1u64.into(),
-                remarkables_uri: "ipfs://test.com".into(),
+                remarkables_uri: Some("ipfs://test.com".into()),
                 rarity_level: RARITY_LEVEL,
+                recipient: None,
             };
             assert_eq!(
                 execute(deps.as_mut(), env, info, msg).unwrap_err(),
@@ -660,8 +1127,9 @@ mod tests {
             let info = mock_info(USER, &coins(MINT_FEES - 1, DENOM));
             let msg = ExecuteMsg::Mint {
                 post_id: 1u64.into(),
-                remarkables_uri: "ipfs://test.com".into(),
+                remarkables_uri: Some("ipfs://test.com".into()),
                 rarity_level: RARITY_LEVEL,
+                recipient: None,
             };
             assert_eq!(
                 execute(deps.as_mut(), env, info, msg).unwrap_err(),
@@ -695,8 +1163,9 @@ mod tests {
             let info = mock_info(USER, &coins(MINT_FEES, DENOM));
             let msg = ExecuteMsg::Mint {
                 post_id: POST_ID.into(),
-                remarkables_uri: "ipfs://test.com".into(),
+                remarkables_uri: Some("ipfs://test.com".into()),
                 rarity_level: RARITY_LEVEL,
+                recipient: None,
             };
             assert_eq!(
                 execute(deps.as_mut(), env, info, msg).unwrap_err(),
@@ -704,19 +1173,20 @@ mod tests {
             )
         }
         #[test]
-        fn mint_from_non_author_error() {
+        fn mint_to_other_recipient_from_non_author_error() {
             let mut deps = mock_desmos_dependencies();
             do_instantiate(deps.as_mut());
             let env = mock_env();
             let info = mock_info(ADMIN, &coins(100, DENOM));
             let msg = ExecuteMsg::Mint {
                 post_id: 1u64.into(),
-                remarkables_uri: "ipfs://test.com".into(),
+                remarkables_uri: Some("ipfs://test.com".into()),
                 rarity_level: RARITY_LEVEL,
+                recipient: Some(ADMIN.into()),
             };
             assert_eq!(
                 execute(deps.as_mut(), env, info, msg).unwrap_err(),
-                ContractError::MinterNotPostAuthor {
+                ContractError::MinterNotAuthorized {
                     minter: ADMIN.into(),
                     author: USER.into()
                 },
@@ -747,8 +1217,9 @@ mod tests {
             let info = mock_info(USER, &coins(MINT_FEES, DENOM));
             let msg = ExecuteMsg::Mint {
                 post_id: POST_ID.into(),
-                remarkables_uri: "ipfs://test.com".into(),
+                remarkables_uri: Some("ipfs://test.com".into()),
                 rarity_level: RARITY_LEVEL,
+                recipient: None,
             };
             assert_eq!(
                 execute(deps.as_mut(), env, info, msg).unwrap_err(),
@@ -805,8 +1276,9 @@ mod tests {
             let info = mock_info(USER, &coins(MINT_FEES, DENOM));
             let msg = ExecuteMsg::Mint {
                 post_id: POST_ID.into(),
-                remarkables_uri: "ipfs://test.com".into(),
+                remarkables_uri: Some("ipfs://test.com".into()),
                 rarity_level: RARITY_LEVEL,
+                recipient: None,
             };
             assert_eq!(
                 execute(deps.as_mut(), env, info, msg).unwrap_err(),
@@ -851,8 +1323,9 @@ mod tests {
             let info = mock_info(USER, &coins(MINT_FEES, DENOM));
             let msg = ExecuteMsg::Mint {
                 post_id: POST_ID.into(),
-                remarkables_uri: "ipfs://test.com".into(),
+                remarkables_uri: Some("ipfs://test.com".into()),
                 rarity_level: RARITY_LEVEL,
+                recipient: None,
             };
             assert_eq!(
                 ContractError::TokenHasBeenMinted {
@@ -892,131 +1365,861 @@ mod tests {
             let info = mock_info(USER, &coins(MINT_FEES, DENOM));
             let msg = ExecuteMsg::Mint {
                 post_id: POST_ID.into(),
-                remarkables_uri: "ipfs://test.com".into(),
+                remarkables_uri: Some("ipfs://test.com".into()),
                 rarity_level: RARITY_LEVEL,
+                recipient: None,
             };
             execute(deps.as_mut(), env, info, msg).unwrap();
         }
-    }
-    mod update_admin {
-        use super::*;
-        #[test]
-        fn update_admin_without_permissions_error() {
-            let mut deps = mock_desmos_dependencies();
+        fn mock_eligible_dependencies(
+        ) -> OwnedDeps<MockStorage, MockApi, MockQuerier<DesmosQuery>, DesmosQuery> {
+            let querier = MockQuerier::<DesmosQuery>::new(&[(MOCK_CONTRACT_ADDR, &[])])
+                .with_custom_handler(|query| match query {
+                    DesmosQuery::Posts(query) => SystemResult::Ok(mock_posts_query_response(query)),
+                    DesmosQuery::Subspaces(query) => {
+                        SystemResult::Ok(mock_subspaces_query_response(query))
+                    }
+                    DesmosQuery::Reactions(query) => match query {
+                        ReactionsQuery::Reactions { user, .. } => SystemResult::Ok(
+                            ContractResult::Ok(to_binary(&get_reactions(user, true)).unwrap()),
+                        ),
+                        _ => SystemResult::Err(SystemError::Unknown {}),
+                    },
+                    #[allow(unreachable_patterns)]
+                    _ => SystemResult::Err(SystemError::Unknown {}),
+                });
+            let mut deps = OwnedDeps {
+                storage: MockStorage::default(),
+                querier,
+                api: MockApi::default(),
+                custom_query_type: PhantomData,
+            };
             do_instantiate(deps.as_mut());
-            let env = mock_env();
-            let info = mock_info(USER, &vec![]);
-            let msg = ExecuteMsg::UpdateAdmin {
-                new_admin: NEW_ADMIN.into(),
+            CW721_ADDRESS
+                .save(deps.as_mut().storage, &Addr::unchecked("cw_address"))
+                .unwrap();
+            deps
+        }
+        fn get_expected_mint_msg(owner: &str) -> SubMsg<DesmosMsg> {
+            SubMsg::new(
+                wasm_execute(
+                    "cw_address",
+                    &Cw721ExecuteMsg::<Metadata, Empty>::Mint(MintMsg::<Metadata> {
+                        token_id: convert_post_id_to_token_id(POST_ID, RARITY_LEVEL),
+                        owner: owner.into(),
+                        token_uri: Some("ipfs://test.com".into()),
+                        extension: Metadata {
+                            rarity_level: RARITY_LEVEL,
+                            subspace_id: SUBSPACE_ID,
+                            post_id: POST_ID,
+                            author: Some(Addr::unchecked(USER)),
+                        },
+                    }),
+                    vec![],
+                )
+                .unwrap(),
+            )
+        }
+        #[test]
+        fn mint_as_gift_not_accepted_error() {
+            let mut deps = mock_eligible_dependencies();
+            RARITIES
+                .update(deps.as_mut().storage, |mut rarities| -> StdResult<_> {
+                    rarities[0].uri_template = Some("ipfs://test.com".into());
+                    Ok(rarities)
+                })
+                .unwrap();
+            let info = mock_info(ADMIN, &coins(MINT_FEES, DENOM));
+            let msg = ExecuteMsg::Mint {
+                post_id: POST_ID.into(),
+                remarkables_uri: None,
+                rarity_level: RARITY_LEVEL,
+                recipient: None,
             };
             assert_eq!(
-                execute(deps.as_mut(), env, info, msg).unwrap_err(),
-                ContractError::NotAdmin {
-                    caller: Addr::unchecked(USER)
-                }
+                execute(deps.as_mut(), mock_env(), info, msg).unwrap_err(),
+                ContractError::GiftsNotAccepted {
+                    author: USER.into()
+                },
             )
         }
         #[test]
-        fn update_admin_with_invalid_address_error() {
-            let mut deps = mock_desmos_dependencies();
-            do_instantiate(deps.as_mut());
-            let env = mock_env();
-            let info = mock_info(ADMIN, &vec![]);
-            let msg = ExecuteMsg::UpdateAdmin {
-                new_admin: "a".into(),
+        fn mint_as_gift_with_uri_error() {
+            let mut deps = mock_eligible_dependencies();
+            GIFTS_ACCEPTED
+                .save(deps.as_mut().storage, &Addr::unchecked(USER), &true)
+                .unwrap();
+            let info = mock_info(ADMIN, &coins(MINT_FEES, DENOM));
+            let msg = ExecuteMsg::Mint {
+                post_id: POST_ID.into(),
+                remarkables_uri: Some("ipfs://test.com".into()),
+                rarity_level: RARITY_LEVEL,
+                recipient: None,
             };
             assert_eq!(
-                execute(deps.as_mut(), env, info, msg).unwrap_err(),
-                ContractError::Std(StdError::generic_err(
-                    "Invalid input: human address too short"
-                ))
+                execute(deps.as_mut(), mock_env(), info, msg).unwrap_err(),
+                ContractError::GiftRemarkablesUriNotAllowed {
+                    minter: ADMIN.into()
+                },
             )
         }
         #[test]
-        fn update_admin_properly() {
-            let mut deps = mock_desmos_dependencies();
+        fn mint_as_gift_without_uri_template_error() {
+            let mut deps = mock_eligible_dependencies();
+            GIFTS_ACCEPTED
+                .save(deps.as_mut().storage, &Addr::unchecked(USER), &true)
+                .unwrap();
+            let info = mock_info(ADMIN, &coins(MINT_FEES, DENOM));
+            let msg = ExecuteMsg::Mint {
+                post_id: POST_ID.into(),
+                remarkables_uri: None,
+                rarity_level: RARITY_LEVEL,
+                recipient: None,
+            };
+            assert_eq!(
+                execute(deps.as_mut(), mock_env(), info, msg).unwrap_err(),
+                ContractError::RemarkablesUriNotProvided {
+                    level: RARITY_LEVEL
+                },
+            )
+        }
+        #[test]
+        fn mint_as_gift_properly() {
+            let mut deps = mock_eligible_dependencies();
+            GIFTS_ACCEPTED
+                .save(deps.as_mut().storage, &Addr::unchecked(USER), &true)
+                .unwrap();
+            RARITIES
+                .update(deps.as_mut().storage, |mut rarities| -> StdResult<_> {
+                    rarities[0].uri_template = Some("ipfs://test.com".into());
+                    Ok(rarities)
+                })
+                .unwrap();
+            let info = mock_info(ADMIN, &coins(MINT_FEES, DENOM));
+            let msg = ExecuteMsg::Mint {
+                post_id: POST_ID.into(),
+                remarkables_uri: None,
+                rarity_level: RARITY_LEVEL,
+                recipient: None,
+            };
+            let response = execute(deps.as_mut(), mock_env(), info, msg).unwrap();
+            assert_eq!(vec![get_expected_mint_msg(USER)], response.messages)
+        }
+        #[test]
+        fn mint_to_recipient_properly() {
+            let mut deps = mock_eligible_dependencies();
+            let info = mock_info(USER, &coins(MINT_FEES, DENOM));
+            let msg = ExecuteMsg::Mint {
+                post_id: POST_ID.into(),
+                remarkables_uri: Some("ipfs://test.com".into()),
+                rarity_level: RARITY_LEVEL,
+                recipient: Some(RECEIVER.into()),
+            };
+            let response = execute(deps.as_mut(), mock_env(), info, msg).unwrap();
+            assert_eq!(vec![get_expected_mint_msg(RECEIVER)], response.messages)
+        }
+        #[test]
+        fn mint_to_recipient_from_operator_properly() {
+            let mut deps = mock_eligible_dependencies();
+            OPERATORS
+                .save(
+                    deps.as_mut().storage,
+                    (&Addr::unchecked(USER), &Addr::unchecked(ADMIN)),
+                    &true,
+                )
+                .unwrap();
+            let info = mock_info(ADMIN, &coins(MINT_FEES, DENOM));
+            let msg = ExecuteMsg::Mint {
+                post_id: POST_ID.into(),
+                remarkables_uri: Some("ipfs://test.com".into()),
+                rarity_level: RARITY_LEVEL,
+                recipient: Some(RECEIVER.into()),
+            };
+            let response = execute(deps.as_mut(), mock_env(), info, msg).unwrap();
+            assert_eq!(vec![get_expected_mint_msg(RECEIVER)], response.messages)
+        }
+        #[test]
+        fn mint_deprecated_rarity_error() {
+            let mut deps = mock_desmos_dependencies();
+            do_instantiate(deps.as_mut());
+            RARITIES
+                .update(deps.as_mut().storage, |mut rarities| -> StdResult<_> {
+                    rarities[0].deprecated = true;
+                    Ok(rarities)
+                })
+                .unwrap();
+            let info = mock_info(USER, &coins(MINT_FEES, DENOM));
+            let msg = ExecuteMsg::Mint {
+                post_id: POST_ID.into(),
+                remarkables_uri: Some("ipfs://test.com".into()),
+                rarity_level: RARITY_LEVEL,
+                recipient: None,
+            };
+            assert_eq!(
+                execute(deps.as_mut(), mock_env(), info, msg).unwrap_err(),
+                ContractError::RarityDeprecated {
+                    level: RARITY_LEVEL
+                },
+            )
+        }
+        #[test]
+        fn mint_without_uri_and_uri_template_error() {
+            let mut deps = mock_desmos_dependencies();
+            do_instantiate(deps.as_mut());
+            let info = mock_info(USER, &coins(MINT_FEES, DENOM));
+            let msg = ExecuteMsg::Mint {
+                post_id: POST_ID.into(),
+                remarkables_uri: None,
+                rarity_level: RARITY_LEVEL,
+                recipient: None,
+            };
+            assert_eq!(
+                execute(deps.as_mut(), mock_env(), info, msg).unwrap_err(),
+                ContractError::RemarkablesUriNotProvided {
+                    level: RARITY_LEVEL
+                },
+            )
+        }
+        #[test]
+        fn mint_with_uri_template_properly() {
+            let querier = MockQuerier::<DesmosQuery>::new(&[(MOCK_CONTRACT_ADDR, &[])])
+                .with_custom_handler(|query| match query {
+                    DesmosQuery::Posts(query) => SystemResult::Ok(mock_posts_query_response(query)),
+                    DesmosQuery::Subspaces(query) => {
+                        SystemResult::Ok(mock_subspaces_query_response(query))
+                    }
+                    DesmosQuery::Reactions(query) => match query {
+                        ReactionsQuery::Reactions { user, .. } => SystemResult::Ok(
+                            ContractResult::Ok(to_binary(&get_reactions(user, true)).unwrap()),
+                        ),
+                        _ => SystemResult::Err(SystemError::Unknown {}),
+                    },
+                    #[allow(unreachable_patterns)]
+                    _ => SystemResult::Err(SystemError::Unknown {}),
+                });
+            let mut deps = OwnedDeps {
+                storage: MockStorage::default(),
+                querier,
+                api: MockApi::default(),
+                custom_query_type: PhantomData,
+            };
+            do_instantiate(deps.as_mut());
+            RARITIES
+                .update(deps.as_mut().storage, |mut rarities| -> StdResult<_> {
+                    rarities[0].uri_template = Some("ipfs://remarkables/{post_id}.json".into());
+                    Ok(rarities)
+                })
+                .unwrap();
+            CW721_ADDRESS
+                .save(deps.as_mut().storage, &Addr::unchecked("cw_address"))
+                .unwrap();
+            let info = mock_info(USER, &coins(MINT_FEES, DENOM));
+            let msg = ExecuteMsg::Mint {
+                post_id: POST_ID.into(),
+                remarkables_uri: None,
+                rarity_level: RARITY_LEVEL,
+                recipient: None,
+            };
+            let response = execute(deps.as_mut(), mock_env(), info, msg).unwrap();
+            assert!(response.attributes.contains(&Attribute::new(
+                ATTRIBUTE_TOKEN_URI,
+                "ipfs://remarkables/1.json"
+            )));
+        }
+    }
+    mod approve_operator {
+        use super::*;
+        #[test]
+        fn approve_already_approved_operator_error() {
+            let mut deps = mock_desmos_dependencies();
+            do_instantiate(deps.as_mut());
+            let msg = ExecuteMsg::ApproveOperator {
+                operator: ADMIN.into(),
+            };
+            execute(
+                deps.as_mut(),
+                mock_env(),
+                mock_info(USER, &vec![]),
+                msg.clone(),
+            )
+            .unwrap();
+            assert_eq!(
+                execute(deps.as_mut(), mock_env(), mock_info(USER, &vec![]), msg).unwrap_err(),
+                ContractError::OperatorAlreadyApproved {
+                    operator: Addr::unchecked(ADMIN)
+                }
+            )
+        }
+        #[test]
+        fn approve_operator_properly() {
+            let mut deps = mock_desmos_dependencies();
+            do_instantiate(deps.as_mut());
+            let msg = ExecuteMsg::ApproveOperator {
+                operator: ADMIN.into(),
+            };
+            execute(deps.as_mut(), mock_env(), mock_info(USER, &vec![]), msg).unwrap();
+            assert!(OPERATORS.has(
+                &deps.storage,
+                (&Addr::unchecked(USER), &Addr::unchecked(ADMIN))
+            ))
+        }
+    }
+    mod revoke_operator {
+        use super::*;
+        #[test]
+        fn revoke_not_approved_operator_error() {
+            let mut deps = mock_desmos_dependencies();
+            do_instantiate(deps.as_mut());
+            let msg = ExecuteMsg::RevokeOperator {
+                operator: ADMIN.into(),
+            };
+            assert_eq!(
+                execute(deps.as_mut(), mock_env(), mock_info(USER, &vec![]), msg).unwrap_err(),
+                ContractError::OperatorNotApproved {
+                    operator: Addr::unchecked(ADMIN)
+                }
+            )
+        }
+        #[test]
+        fn revoke_operator_properly() {
+            let mut deps = mock_desmos_dependencies();
+            do_instantiate(deps.as_mut());
+            OPERATORS
+                .save(
+                    deps.as_mut().storage,
+                    (&Addr::unchecked(USER), &Addr::unchecked(ADMIN)),
+                    &true,
+                )
+                .unwrap();
+            let msg = ExecuteMsg::RevokeOperator {
+                operator: ADMIN.into(),
+            };
+            execute(deps.as_mut(), mock_env(), mock_info(USER, &vec![]), msg).unwrap();
+            assert!(!OPERATORS.has(
+                &deps.storage,
+                (&Addr::unchecked(USER), &Addr::unchecked(ADMIN))
+            ))
+        }
+    }
+    mod accept_gifts {
+        use super::*;
+        #[test]
+        fn accept_gifts_already_accepted_error() {
+            let mut deps = mock_desmos_dependencies();
+            do_instantiate(deps.as_mut());
+            execute(
+                deps.as_mut(),
+                mock_env(),
+                mock_info(USER, &vec![]),
+                ExecuteMsg::AcceptGifts {},
+            )
+            .unwrap();
+            assert_eq!(
+                execute(
+                    deps.as_mut(),
+                    mock_env(),
+                    mock_info(USER, &vec![]),
+                    ExecuteMsg::AcceptGifts {}
+                )
+                .unwrap_err(),
+                ContractError::GiftsAlreadyAccepted {
+                    author: USER.into()
+                }
+            )
+        }
+        #[test]
+        fn accept_gifts_properly() {
+            let mut deps = mock_desmos_dependencies();
+            do_instantiate(deps.as_mut());
+            execute(
+                deps.as_mut(),
+                mock_env(),
+                mock_info(USER, &vec![]),
+                ExecuteMsg::AcceptGifts {},
+            )
+            .unwrap();
+            assert!(GIFTS_ACCEPTED.has(&deps.storage, &Addr::unchecked(USER)))
+        }
+    }
+    mod reject_gifts {
+        use super::*;
+        #[test]
+        fn reject_not_accepted_gifts_error() {
+            let mut deps = mock_desmos_dependencies();
+            do_instantiate(deps.as_mut());
+            assert_eq!(
+                execute(
+                    deps.as_mut(),
+                    mock_env(),
+                    mock_info(USER, &vec![]),
+                    ExecuteMsg::RejectGifts {}
+                )
+                .unwrap_err(),
+                ContractError::GiftsNotAccepted {
+                    author: USER.into()
+                }
+            )
+        }
+        #[test]
+        fn reject_gifts_properly() {
+            let mut deps = mock_desmos_dependencies();
+            do_instantiate(deps.as_mut());
+            GIFTS_ACCEPTED
+                .save(deps.as_mut().storage, &Addr::unchecked(USER), &true)
+                .unwrap();
+            execute(
+                deps.as_mut(),
+                mock_env(),
+                mock_info(USER, &vec![]),
+                ExecuteMsg::RejectGifts {},
+            )
+            .unwrap();
+            assert!(!GIFTS_ACCEPTED.has(&deps.storage, &Addr::unchecked(USER)))
+        }
+    }
+    mod update_admin {
+        use super::*;
+        #[test]
+        fn update_admin_without_permissions_error() {
+            let mut deps = mock_desmos_dependencies();
+            do_instantiate(deps.as_mut());
+            let env = mock_env();
+            let info = mock_info(USER, &vec![]);
+            let msg = ExecuteMsg::UpdateAdmin {
+                new_admin: NEW_ADMIN.into(),
+            };
+            assert_eq!(
+                execute(deps.as_mut(), env, info, msg).unwrap_err(),
+                ContractError::NotAdmin {
+                    caller: Addr::unchecked(USER)
+                }
+            )
+        }
+        #[test]
+        fn update_admin_with_invalid_address_error() {
+            let mut deps = mock_desmos_dependencies();
+            do_instantiate(deps.as_mut());
+            let env = mock_env();
+            let info = mock_info(ADMIN, &vec![]);
+            let msg = ExecuteMsg::UpdateAdmin {
+                new_admin: "a".into(),
+            };
+            assert_eq!(
+                execute(deps.as_mut(), env, info, msg).unwrap_err(),
+                ContractError::Std(StdError::generic_err(
+                    "Invalid input: human address too short"
+                ))
+            )
+        }
+        #[test]
+        fn update_admin_properly() {
+            let mut deps = mock_desmos_dependencies();
+            do_instantiate(deps.as_mut());
+            let env = mock_env();
+            let info = mock_info(ADMIN, &vec![]);
+            let msg = ExecuteMsg::UpdateAdmin {
+                new_admin: NEW_ADMIN.into(),
+            };
+            assert!(execute(deps.as_mut(), env, info, msg).is_ok());
+            let config = CONFIG.load(&deps.storage).unwrap();
+            let expected = ConfigState {
+                admin: Addr::unchecked(NEW_ADMIN),
+                cw721_code_id: CW721_CODE_ID,
+                subspace_id: SUBSPACE_ID,
+                tips_contract: None,
+            };
+            assert_eq!(config, expected)
+        }
+    }
+    mod update_rarity_mint_fees {
+        use super::*;
+        #[test]
+        fn update_rarity_mint_fees_without_permissions_error() {
+            let mut deps = mock_desmos_dependencies();
+            do_instantiate(deps.as_mut());
+            let env = mock_env();
+            let info = mock_info(USER, &vec![]);
+            let msg = ExecuteMsg::UpdateRarityMintFees {
+                rarity_level: RARITY_LEVEL,
+                new_fees: coins(50, DENOM),
+            };
+            assert_eq!(
+                execute(deps.as_mut(), env, info, msg).unwrap_err(),
+                ContractError::NotAdmin {
+                    caller: Addr::unchecked(USER)
+                }
+            )
+        }
+        #[test]
+        fn update_no_existing_rarity_mint_fees_error() {
+            let mut deps = mock_desmos_dependencies();
+            do_instantiate(deps.as_mut());
+            let env = mock_env();
+            let info = mock_info(ADMIN, &vec![]);
+            let msg = ExecuteMsg::UpdateRarityMintFees {
+                rarity_level: 2,
+                new_fees: coins(50, DENOM),
+            };
+            assert_eq!(
+                execute(deps.as_mut(), env, info, msg).unwrap_err(),
+                ContractError::RarityNotExists { level: 2 },
+            )
+        }
+        #[test]
+        fn update_rarity_mint_fees_same_as_the_current_error() {
+            let mut deps = mock_desmos_dependencies();
+            do_instantiate(deps.as_mut());
+            let env = mock_env();
+            let info = mock_info(ADMIN, &vec![]);
+            let msg = ExecuteMsg::UpdateRarityMintFees {
+                rarity_level: RARITY_LEVEL,
+                new_fees: coins(MINT_FEES, DENOM),
+            };
+            assert_eq!(
+                execute(deps.as_mut(), env, info, msg).unwrap_err(),
+                ContractError::NewMintFeesEqualToCurrent {},
+            )
+        }
+        #[test]
+        fn update_rarity_mint_fees_properly() {
+            let mut deps = mock_desmos_dependencies();
+            do_instantiate(deps.as_mut());
+            let env = mock_env();
+            let info = mock_info(ADMIN, &vec![]);
+            let msg = ExecuteMsg::UpdateRarityMintFees {
+                rarity_level: RARITY_LEVEL,
+                new_fees: coins(50, DENOM),
+            };
+            execute(deps.as_mut(), env, info, msg).unwrap();
+            let new_rarities = RARITIES.load(&deps.storage).unwrap();
+            let expected = Rarity {
+                name: "common".into(),
+                engagement_threshold: 100,
+                mint_fees: coins(50, DENOM),
+                uri_template: None,
+                engagement_formula: None,
+                deprecated: false,
+            };
+            assert_eq!(expected, *new_rarities.get(0).unwrap())
+        }
+    }
+    mod add_rarity {
+        use super::*;
+        fn get_new_rarity(engagement_threshold: u32) -> Rarity {
+            Rarity {
+                name: "rare".into(),
+                engagement_threshold,
+                mint_fees: coins(MINT_FEES, DENOM),
+                uri_template: None,
+                engagement_formula: None,
+                deprecated: false,
+            }
+        }
+        #[test]
+        fn add_rarity_without_permissions_error() {
+            let mut deps = mock_desmos_dependencies();
+            do_instantiate(deps.as_mut());
+            let msg = ExecuteMsg::AddRarity {
+                rarity: get_new_rarity(ENGAGEMENT_THRESHOLD + 1),
+            };
+            assert_eq!(
+                execute(deps.as_mut(), mock_env(), mock_info(USER, &vec![]), msg).unwrap_err(),
+                ContractError::NotAdmin {
+                    caller: Addr::unchecked(USER)
+                }
+            )
+        }
+        #[test]
+        fn add_rarity_with_lower_threshold_error() {
+            let mut deps = mock_desmos_dependencies();
+            do_instantiate(deps.as_mut());
+            let msg = ExecuteMsg::AddRarity {
+                rarity: get_new_rarity(ENGAGEMENT_THRESHOLD),
+            };
+            assert_eq!(
+                execute(deps.as_mut(), mock_env(), mock_info(ADMIN, &vec![]), msg).unwrap_err(),
+                ContractError::InvalidRarityThresholds {}
+            )
+        }
+        #[test]
+        fn add_rarity_using_tips_without_tips_contract_error() {
+            let mut deps = mock_desmos_dependencies();
+            do_instantiate(deps.as_mut());
+            let mut rarity = get_new_rarity(ENGAGEMENT_THRESHOLD + 1);
+            rarity.engagement_formula = Some(vec![EngagementWeight {
+                metric: EngagementMetric::Tips,
+                weight: 1,
+            }]);
+            let msg = ExecuteMsg::AddRarity { rarity };
+            assert_eq!(
+                execute(deps.as_mut(), mock_env(), mock_info(ADMIN, &vec![]), msg).unwrap_err(),
+                ContractError::TipsContractNotConfigured {}
+            )
+        }
+        #[test]
+        fn add_rarity_properly() {
+            let mut deps = mock_desmos_dependencies();
+            do_instantiate(deps.as_mut());
+            let msg = ExecuteMsg::AddRarity {
+                rarity: get_new_rarity(ENGAGEMENT_THRESHOLD + 1),
+            };
+            execute(deps.as_mut(), mock_env(), mock_info(ADMIN, &vec![]), msg).unwrap();
+            let rarities = RARITIES.load(&deps.storage).unwrap();
+            assert_eq!(get_new_rarity(ENGAGEMENT_THRESHOLD + 1), rarities[1])
+        }
+    }
+    mod update_rarity_threshold {
+        use super::*;
+        #[test]
+        fn update_rarity_threshold_without_permissions_error() {
+            let mut deps = mock_desmos_dependencies();
+            do_instantiate(deps.as_mut());
+            let msg = ExecuteMsg::UpdateRarityThreshold {
+                rarity_level: RARITY_LEVEL,
+                new_threshold: 50,
+            };
+            assert_eq!(
+                execute(deps.as_mut(), mock_env(), mock_info(USER, &vec![]), msg).unwrap_err(),
+                ContractError::NotAdmin {
+                    caller: Addr::unchecked(USER)
+                }
+            )
+        }
+        #[test]
+        fn update_no_existing_rarity_threshold_error() {
+            let mut deps = mock_desmos_dependencies();
+            do_instantiate(deps.as_mut());
+            let msg = ExecuteMsg::UpdateRarityThreshold {
+                rarity_level: 2,
+                new_threshold: 50,
+            };
+            assert_eq!(
+                execute(deps.as_mut(), mock_env(), mock_info(ADMIN, &vec![]), msg).unwrap_err(),
+                ContractError::RarityNotExists { level: 2 }
+            )
+        }
+        #[test]
+        fn update_rarity_threshold_above_next_level_error() {
+            let mut deps = mock_desmos_dependencies();
             do_instantiate(deps.as_mut());
-            let env = mock_env();
-            let info = mock_info(ADMIN, &vec![]);
-            let msg = ExecuteMsg::UpdateAdmin {
-                new_admin: NEW_ADMIN.into(),
+            RARITIES
+                .update(deps.as_mut().storage, |mut rarities| -> StdResult<_> {
+                    let mut rare = rarities[0].clone();
+                    rare.engagement_threshold = ENGAGEMENT_THRESHOLD * 2;
+                    rarities.push(rare);
+                    Ok(rarities)
+                })
+                .unwrap();
+            let msg = ExecuteMsg::UpdateRarityThreshold {
+                rarity_level: RARITY_LEVEL,
+                new_threshold: ENGAGEMENT_THRESHOLD * 2,
             };
-            assert!(execute(deps.as_mut(), env, info, msg).is_ok());
-            let config = CONFIG.load(&deps.storage).unwrap();
-            let expected = ConfigState {
-                admin: Addr::unchecked(NEW_ADMIN),
-                cw721_code_id: CW721_CODE_ID,
-                subspace_id: SUBSPACE_ID,
+            assert_eq!(
+                execute(deps.as_mut(), mock_env(), mock_info(ADMIN, &vec![]), msg).unwrap_err(),
+                ContractError::InvalidRarityThresholds {}
+            )
+        }
+        #[test]
+        fn update_rarity_threshold_properly() {
+            let mut deps = mock_desmos_dependencies();
+            do_instantiate(deps.as_mut());
+            let msg = ExecuteMsg::UpdateRarityThreshold {
+                rarity_level: RARITY_LEVEL,
+                new_threshold: 50,
             };
-            assert_eq!(config, expected)
+            execute(deps.as_mut(), mock_env(), mock_info(ADMIN, &vec![]), msg).unwrap();
+            let rarities = RARITIES.load(&deps.storage).unwrap();
+            assert_eq!(50, rarities[0].engagement_threshold)
         }
     }
-    mod update_rarity_mint_fees {
+    mod update_rarity_engagement_formula {
         use super::*;
+        fn get_formula(metric: EngagementMetric) -> Option<Vec<EngagementWeight>> {
+            Some(vec![EngagementWeight { metric, weight: 2 }])
+        }
         #[test]
-        fn update_rarity_mint_fees_without_permissions_error() {
+        fn update_rarity_engagement_formula_without_permissions_error() {
             let mut deps = mock_desmos_dependencies();
             do_instantiate(deps.as_mut());
-            let env = mock_env();
-            let info = mock_info(USER, &vec![]);
-            let msg = ExecuteMsg::UpdateRarityMintFees {
+            let msg = ExecuteMsg::UpdateRarityEngagementFormula {
                 rarity_level: RARITY_LEVEL,
-                new_fees: coins(50, DENOM),
+                engagement_formula: get_formula(EngagementMetric::Replies),
             };
             assert_eq!(
-                execute(deps.as_mut(), env, info, msg).unwrap_err(),
+                execute(deps.as_mut(), mock_env(), mock_info(USER, &vec![]), msg).unwrap_err(),
                 ContractError::NotAdmin {
                     caller: Addr::unchecked(USER)
                 }
             )
         }
         #[test]
-        fn update_no_existing_rarity_mint_fees_error() {
+        fn update_no_existing_rarity_engagement_formula_error() {
             let mut deps = mock_desmos_dependencies();
             do_instantiate(deps.as_mut());
-            let env = mock_env();
-            let info = mock_info(ADMIN, &vec![]);
-            let msg = ExecuteMsg::UpdateRarityMintFees {
+            let msg = ExecuteMsg::UpdateRarityEngagementFormula {
                 rarity_level: 2,
-                new_fees: coins(50, DENOM),
+                engagement_formula: get_formula(EngagementMetric::Replies),
             };
             assert_eq!(
-                execute(deps.as_mut(), env, info, msg).unwrap_err(),
-                ContractError::RarityNotExists { level: 2 },
+                execute(deps.as_mut(), mock_env(), mock_info(ADMIN, &vec![]), msg).unwrap_err(),
+                ContractError::RarityNotExists { level: 2 }
             )
         }
         #[test]
-        fn update_rarity_mint_fees_same_as_the_current_error() {
+        fn update_rarity_engagement_formula_using_tips_without_tips_contract_error() {
             let mut deps = mock_desmos_dependencies();
             do_instantiate(deps.as_mut());
-            let env = mock_env();
-            let info = mock_info(ADMIN, &vec![]);
-            let msg = ExecuteMsg::UpdateRarityMintFees {
+            let msg = ExecuteMsg::UpdateRarityEngagementFormula {
                 rarity_level: RARITY_LEVEL,
-                new_fees: coins(MINT_FEES, DENOM),
+                engagement_formula: get_formula(EngagementMetric::Tips),
             };
             assert_eq!(
-                execute(deps.as_mut(), env, info, msg).unwrap_err(),
-                ContractError::NewMintFeesEqualToCurrent {},
+                execute(deps.as_mut(), mock_env(), mock_info(ADMIN, &vec![]), msg).unwrap_err(),
+                ContractError::TipsContractNotConfigured {}
             )
         }
         #[test]
-        fn update_rarity_mint_fees_properly() {
+        fn update_rarity_engagement_formula_with_invalid_thresholds_error() {
             let mut deps = mock_desmos_dependencies();
             do_instantiate(deps.as_mut());
-            let env = mock_env();
-            let info = mock_info(ADMIN, &vec![]);
-            let msg = ExecuteMsg::UpdateRarityMintFees {
+            RARITIES
+                .update(deps.as_mut().storage, |mut rarities| -> StdResult<_> {
+                    let mut rare = rarities[0].clone();
+                    rare.engagement_formula = get_formula(EngagementMetric::Replies);
+                    rare.engagement_threshold = ENGAGEMENT_THRESHOLD - 1;
+                    rarities.push(rare);
+                    Ok(rarities)
+                })
+                .unwrap();
+            // The new level shares the formula of the first one with a lower threshold
+            let msg = ExecuteMsg::UpdateRarityEngagementFormula {
+                rarity_level: 1,
+                engagement_formula: None,
+            };
+            assert_eq!(
+                execute(deps.as_mut(), mock_env(), mock_info(ADMIN, &vec![]), msg).unwrap_err(),
+                ContractError::InvalidRarityThresholds {}
+            )
+        }
+        #[test]
+        fn update_rarity_engagement_formula_properly() {
+            let mut deps = mock_desmos_dependencies();
+            do_instantiate(deps.as_mut());
+            let msg = ExecuteMsg::UpdateRarityEngagementFormula {
                 rarity_level: RARITY_LEVEL,
-                new_fees: coins(50, DENOM),
+                engagement_formula: get_formula(EngagementMetric::UniqueReactors),
             };
-            execute(deps.as_mut(), env, info, msg).unwrap();
-            let new_rarities = RARITIES.load(&deps.storage).unwrap();
-            let expected = Rarity {
-                engagement_threshold: 100,
-                mint_fees: coins(50, DENOM),
+            execute(deps.as_mut(), mock_env(), mock_info(ADMIN, &vec![]), msg).unwrap();
+            let rarities = RARITIES.load(&deps.storage).unwrap();
+            assert_eq!(
+                get_formula(EngagementMetric::UniqueReactors),
+                rarities[0].engagement_formula
+            )
+        }
+    }
+    mod update_tips_contract {
+        use super::*;
+        const TIPS_CONTRACT: &str = "tips_contract";
+        #[test]
+        fn update_tips_contract_without_permissions_error() {
+            let mut deps = mock_desmos_dependencies();
+            do_instantiate(deps.as_mut());
+            let msg = ExecuteMsg::UpdateTipsContract {
+                tips_contract: Some(TIPS_CONTRACT.into()),
             };
-            assert_eq!(expected, *new_rarities.get(0).unwrap())
+            assert_eq!(
+                execute(deps.as_mut(), mock_env(), mock_info(USER, &vec![]), msg).unwrap_err(),
+                ContractError::NotAdmin {
+                    caller: Addr::unchecked(USER)
+                }
+            )
+        }
+        #[test]
+        fn remove_tips_contract_used_by_rarity_error() {
+            let mut deps = mock_desmos_dependencies();
+            do_instantiate(deps.as_mut());
+            CONFIG
+                .update(deps.as_mut().storage, |mut config| -> StdResult<_> {
+                    config.tips_contract = Some(Addr::unchecked(TIPS_CONTRACT));
+                    Ok(config)
+                })
+                .unwrap();
+            RARITIES
+                .update(deps.as_mut().storage, |mut rarities| -> StdResult<_> {
+                    rarities[0].engagement_formula = Some(vec![EngagementWeight {
+                        metric: EngagementMetric::Tips,
+                        weight: 1,
+                    }]);
+                    Ok(rarities)
+                })
+                .unwrap();
+            let msg = ExecuteMsg::UpdateTipsContract {
+                tips_contract: None,
+            };
+            assert_eq!(
+                execute(deps.as_mut(), mock_env(), mock_info(ADMIN, &vec![]), msg).unwrap_err(),
+                ContractError::TipsContractNotConfigured {}
+            )
+        }
+        #[test]
+        fn update_tips_contract_properly() {
+            let mut deps = mock_desmos_dependencies();
+            do_instantiate(deps.as_mut());
+            let msg = ExecuteMsg::UpdateTipsContract {
+                tips_contract: Some(TIPS_CONTRACT.into()),
+            };
+            execute(deps.as_mut(), mock_env(), mock_info(ADMIN, &vec![]), msg).unwrap();
+            let config = CONFIG.load(&deps.storage).unwrap();
+            assert_eq!(Some(Addr::unchecked(TIPS_CONTRACT)), config.tips_contract)
+        }
+    }
+    mod deprecate_rarity {
+        use super::*;
+        #[test]
+        fn deprecate_rarity_without_permissions_error() {
+            let mut deps = mock_desmos_dependencies();
+            do_instantiate(deps.as_mut());
+            let msg = ExecuteMsg::DeprecateRarity {
+                rarity_level: RARITY_LEVEL,
+            };
+            assert_eq!(
+                execute(deps.as_mut(), mock_env(), mock_info(USER, &vec![]), msg).unwrap_err(),
+                ContractError::NotAdmin {
+                    caller: Addr::unchecked(USER)
+                }
+            )
+        }
+        #[test]
+        fn deprecate_already_deprecated_rarity_error() {
+            let mut deps = mock_desmos_dependencies();
+            do_instantiate(deps.as_mut());
+            let msg = ExecuteMsg::DeprecateRarity {
+                rarity_level: RARITY_LEVEL,
+            };
+            execute(
+                deps.as_mut(),
+                mock_env(),
+                mock_info(ADMIN, &vec![]),
+                msg.clone(),
+            )
+            .unwrap();
+            assert_eq!(
+                execute(deps.as_mut(), mock_env(), mock_info(ADMIN, &vec![]), msg).unwrap_err(),
+                ContractError::RarityDeprecated {
+                    level: RARITY_LEVEL
+                }
+            )
+        }
+        #[test]
+        fn deprecate_rarity_properly() {
+            let mut deps = mock_desmos_dependencies();
+            do_instantiate(deps.as_mut());
+            let msg = ExecuteMsg::DeprecateRarity {
+                rarity_level: RARITY_LEVEL,
+            };
+            execute(deps.as_mut(), mock_env(), mock_info(ADMIN, &vec![]), msg).unwrap();
+            let rarities = RARITIES.load(&deps.storage).unwrap();
+            assert!(rarities[0].deprecated)
         }
     }
     mod claim_fees {
@@ -1081,6 +2284,7 @@ mod tests {
                         admin: Addr::unchecked(ADMIN),
                         cw721_code_id: 1u64,
                         subspace_id: SUBSPACE_ID,
+                        tips_contract: None,
                     },
                 )
                 .unwrap();
@@ -1095,6 +2299,7 @@ mod tests {
                     cw721_code_id: 1u64.into(),
                     subspace_id: SUBSPACE_ID.into(),
                     cw721_address: Addr::unchecked("cw721_address"),
+                    tips_contract: None,
                 },
                 config
             )
@@ -1107,8 +2312,12 @@ mod tests {
                 .save(
                     deps.as_mut().storage,
                     &vec![Rarity {
+                        name: "common".into(),
                         engagement_threshold: 100,
                         mint_fees: coins(1, DENOM),
+                        uri_template: None,
+                        engagement_formula: None,
+                        deprecated: false,
                     }],
                 )
                 .unwrap();
@@ -1117,12 +2326,339 @@ mod tests {
             assert_eq!(
                 QueryRaritiesResponse {
                     rarities: vec![Rarity {
+                        name: "common".into(),
                         engagement_threshold: 100,
-                        mint_fees: coins(1, DENOM)
+                        mint_fees: coins(1, DENOM),
+                        uri_template: None,
+                        engagement_formula: None,
+                        deprecated: false,
                     }]
                 },
                 rarities_response
             )
         }
+        #[test]
+        fn query_operators() {
+            let mut deps = mock_desmos_dependencies();
+            OPERATORS
+                .save(
+                    deps.as_mut().storage,
+                    (&Addr::unchecked(USER), &Addr::unchecked(ADMIN)),
+                    &true,
+                )
+                .unwrap();
+            let bz = query(
+                deps.as_ref(),
+                mock_env(),
+                QueryMsg::Operators {
+                    author: USER.into(),
+                },
+            )
+            .unwrap();
+            let response: QueryOperatorsResponse = from_binary(&bz).unwrap();
+            assert_eq!(
+                QueryOperatorsResponse {
+                    operators: vec![Addr::unchecked(ADMIN)]
+                },
+                response
+            )
+        }
+        #[test]
+        fn query_gifts_accepted() {
+            let mut deps = mock_desmos_dependencies();
+            GIFTS_ACCEPTED
+                .save(deps.as_mut().storage, &Addr::unchecked(USER), &true)
+                .unwrap();
+            let bz = query(
+                deps.as_ref(),
+                mock_env(),
+                QueryMsg::GiftsAccepted {
+                    author: USER.into(),
+                },
+            )
+            .unwrap();
+            let response: QueryGiftsAcceptedResponse = from_binary(&bz).unwrap();
+            assert_eq!(QueryGiftsAcceptedResponse { accepted: true }, response)
+        }
+        #[test]
+        fn query_post_engagement() {
+            let querier = MockQuerier::<DesmosQuery>::new(&[(MOCK_CONTRACT_ADDR, &[])])
+                .with_custom_handler(|query| match query {
+                    DesmosQuery::Posts(query) => SystemResult::Ok(mock_posts_query_response(query)),
+                    DesmosQuery::Subspaces(query) => {
+                        SystemResult::Ok(mock_subspaces_query_response(query))
+                    }
+                    DesmosQuery::Reactions(query) => match query {
+                        ReactionsQuery::Reactions { user, .. } => {
+                            // The author reacted once to its own post
+                            let total = if user.is_some() {
+                                1
+                            } else {
+                                ENGAGEMENT_THRESHOLD + 1
+                            };
+                            SystemResult::Ok(ContractResult::Ok(
+                                to_binary(&QueryReactionsResponse {
+                                    reactions: vec![],
+                                    pagination: Some(PageResponse {
+                                        next_key: None,
+                                        total: Some(total.into()),
+                                    }),
+                                })
+                                .unwrap(),
+                            ))
+                        }
+                        _ => SystemResult::Err(SystemError::Unknown {}),
+                    },
+                    #[allow(unreachable_patterns)]
+                    _ => SystemResult::Err(SystemError::Unknown {}),
+                });
+            let mut deps = OwnedDeps {
+                storage: MockStorage::default(),
+                querier,
+                api: MockApi::default(),
+                custom_query_type: PhantomData,
+            };
+            do_instantiate(deps.as_mut());
+            RARITIES
+                .update(deps.as_mut().storage, |mut rarities| -> StdResult<_> {
+                    let mut rare = rarities[0].clone();
+                    rare.engagement_threshold = ENGAGEMENT_THRESHOLD * 2;
+                    rare.engagement_formula = Some(vec![EngagementWeight {
+                        metric: EngagementMetric::Reactions,
+                        weight: 3,
+                    }]);
+                    rarities.push(rare);
+                    Ok(rarities)
+                })
+                .unwrap();
+            let bz = query(
+                deps.as_ref(),
+                mock_env(),
+                QueryMsg::PostEngagement {
+                    post_id: POST_ID.into(),
+                },
+            )
+            .unwrap();
+            let response: QueryPostEngagementResponse = from_binary(&bz).unwrap();
+            assert_eq!(
+                QueryPostEngagementResponse {
+                    post_id: POST_ID.into(),
+                    scores: vec![
+                        RarityEngagementScore {
+                            rarity_level: 0,
+                            score: ENGAGEMENT_THRESHOLD as u64,
+                            qualified: true,
+                        },
+                        RarityEngagementScore {
+                            rarity_level: 1,
+                            score: ENGAGEMENT_THRESHOLD as u64 * 3,
+                            qualified: true,
+                        },
+                    ],
+                    qualified_rarity_levels: vec![0, 1],
+                },
+                response
+            )
+        }
+        #[test]
+        fn query_post_engagement_counts_references_inside_scan_window() {
+            let querier = MockQuerier::<DesmosQuery>::new(&[(MOCK_CONTRACT_ADDR, &[])])
+                .with_custom_handler(|query| match query {
+                    DesmosQuery::Posts(PostsQuery::Post {
+                        subspace_id,
+                        post_id,
+                    }) => {
+                        let post_id = post_id.u64();
+                        // The post right after the scored one has been deleted
+                        if post_id == POST_ID + 2 {
+                            return SystemResult::Err(SystemError::InvalidRequest {
+                                error: "post not found".to_string(),
+                                request: Default::default(),
+                            });
+                        }
+                        let mut post =
+                            MockPostsQueries::get_mocked_post(post_id.into(), *subspace_id);
+                        // The author of the scored post replies to it with the post right after the quote
+                        if post_id != POST_ID && post_id != POST_ID + 4 {
+                            post.author = Addr::unchecked(RECEIVER);
+                        }
+                        let ref_type = match post_id {
+                            id if id == POST_ID + 1 => Some(PostReferenceType::Replay),
+                            id if id == POST_ID + 3 => Some(PostReferenceType::Quote),
+                            id if id == POST_ID + 4 => Some(PostReferenceType::Replay),
+                            // Replies outside of the window are not counted
+                            id if id == POST_ID + REFERENCES_SCAN_WINDOW + 1 => {
+                                Some(PostReferenceType::Replay)
+                            }
+                            _ => None,
+                        };
+                        if let Some(ref_type) = ref_type {
+                            post.referenced_posts = vec![PostReference {
+                                ref_type,
+                                post_id: POST_ID.into(),
+                                position: None,
+                            }];
+                        }
+                        SystemResult::Ok(ContractResult::Ok(
+                            to_binary(&QueryPostResponse { post }).unwrap(),
+                        ))
+                    }
+                    DesmosQuery::Subspaces(query) => {
+                        SystemResult::Ok(mock_subspaces_query_response(query))
+                    }
+                    _ => SystemResult::Err(SystemError::Unknown {}),
+                });
+            let mut deps = OwnedDeps {
+                storage: MockStorage::default(),
+                querier,
+                api: MockApi::default(),
+                custom_query_type: PhantomData,
+            };
+            do_instantiate(deps.as_mut());
+            RARITIES
+                .update(deps.as_mut().storage, |mut rarities| -> StdResult<_> {
+                    rarities[0].engagement_threshold = 1;
+                    rarities[0].engagement_formula = Some(vec![EngagementWeight {
+                        metric: EngagementMetric::Replies,
+                        weight: 1,
+                    }]);
+                    let mut rare = rarities[0].clone();
+                    rare.engagement_formula = Some(vec![EngagementWeight {
+                        metric: EngagementMetric::Quotes,
+                        weight: 2,
+                    }]);
+                    rare.engagement_threshold = 3;
+                    rarities.push(rare);
+                    Ok(rarities)
+                })
+                .unwrap();
+            let bz = query(
+                deps.as_ref(),
+                mock_env(),
+                QueryMsg::PostEngagement {
+                    post_id: POST_ID.into(),
+                },
+            )
+            .unwrap();
+            let response: QueryPostEngagementResponse = from_binary(&bz).unwrap();
+            assert_eq!(
+                QueryPostEngagementResponse {
+                    post_id: POST_ID.into(),
+                    scores: vec![
+                        RarityEngagementScore {
+                            rarity_level: 0,
+                            score: 1,
+                            qualified: true,
+                        },
+                        RarityEngagementScore {
+                            rarity_level: 1,
+                            score: 2,
+                            qualified: false,
+                        },
+                    ],
+                    qualified_rarity_levels: vec![0],
+                },
+                response
+            )
+        }
+        #[test]
+        fn query_post_engagement_excludes_author_tips() {
+            const TIPS_CONTRACT: &str = "tips_contract";
+            let mut querier = MockQuerier::<DesmosQuery>::new(&[(MOCK_CONTRACT_ADDR, &[])])
+                .with_custom_handler(|query| match query {
+                    DesmosQuery::Posts(query) => SystemResult::Ok(mock_posts_query_response(query)),
+                    DesmosQuery::Subspaces(query) => {
+                        SystemResult::Ok(mock_subspaces_query_response(query))
+                    }
+                    _ => SystemResult::Err(SystemError::Unknown {}),
+                });
+            querier.update_wasm(|query| match query {
+                WasmQuery::Smart { contract_addr, .. } if contract_addr == TIPS_CONTRACT => {
+                    // The author tipped its own post once
+                    let tips = [USER, RECEIVER, RECEIVER]
+                        .iter()
+                        .map(|sender| Tip {
+                            sender: Addr::unchecked(*sender),
+                            receiver: Addr::unchecked(USER),
+                            amount: coins(1, DENOM),
+                            post_id: Some(POST_ID.into()),
+                            block_height: 1u64.into(),
+                        })
+                        .collect();
+                    SystemResult::Ok(ContractResult::Ok(
+                        to_binary(&TipsResponse { tips }).unwrap(),
+                    ))
+                }
+                _ => SystemResult::Err(SystemError::Unknown {}),
+            });
+            let mut deps = OwnedDeps {
+                storage: MockStorage::default(),
+                querier,
+                api: MockApi::default(),
+                custom_query_type: PhantomData,
+            };
+            do_instantiate(deps.as_mut());
+            CONFIG
+                .update(deps.as_mut().storage, |mut config| -> StdResult<_> {
+                    config.tips_contract = Some(Addr::unchecked(TIPS_CONTRACT));
+                    Ok(config)
+                })
+                .unwrap();
+            RARITIES
+                .update(deps.as_mut().storage, |mut rarities| -> StdResult<_> {
+                    rarities[0].engagement_threshold = 2;
+                    rarities[0].engagement_formula = Some(vec![EngagementWeight {
+                        metric: EngagementMetric::Tips,
+                        weight: 1,
+                    }]);
+                    Ok(rarities)
+                })
+                .unwrap();
+            let bz = query(
+                deps.as_ref(),
+                mock_env(),
+                QueryMsg::PostEngagement {
+                    post_id: POST_ID.into(),
+                },
+            )
+            .unwrap();
+            let response: QueryPostEngagementResponse = from_binary(&bz).unwrap();
+            assert_eq!(
+                QueryPostEngagementResponse {
+                    post_id: POST_ID.into(),
+                    scores: vec![RarityEngagementScore {
+                        rarity_level: 0,
+                        score: 2,
+                        qualified: true,
+                    }],
+                    qualified_rarity_levels: vec![0],
+                },
+                response
+            )
+        }
+    }
+    mod migrate {
+        use super::*;
+        #[test]
+        fn migrate_names_stored_rarities_properly() {
+            let mut deps = mock_desmos_dependencies();
+            do_instantiate(deps.as_mut());
+            // Rarity stored before the rarities had a name
+            let legacy_rarity: Rarity = cosmwasm_std::from_slice(
+                br#"{"engagement_threshold":100,"mint_fees":[{"denom":"test","amount":"1"}]}"#,
+            )
+            .unwrap();
+            assert!(legacy_rarity.name.is_empty());
+            RARITIES
+                .update(deps.as_mut().storage, |mut rarities| -> StdResult<_> {
+                    rarities.push(legacy_rarity);
+                    Ok(rarities)
+                })
+                .unwrap();
+            migrate(deps.as_mut(), mock_env(), MigrateMsg {}).unwrap();
+            let rarities = RARITIES.load(&deps.storage).unwrap();
+            assert_eq!("common", rarities[0].name);
+            assert_eq!("level_1", rarities[1].name);
+        }
     }
 }
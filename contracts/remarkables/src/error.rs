@@ -54,9 +54,47 @@ pub enum ContractError {
     #[error("Invalid Cw721 code id")]
     InvalidCw721CodeId {},
 
-    #[error("Minter {minter} is not the post author {author}")]
-    MinterNotPostAuthor { minter: String, author: String },
+    #[error(
+        "Minter {minter} is neither the post author {author} nor an operator approved by them"
+    )]
+    MinterNotAuthorized { minter: String, author: String },
+
+    #[error("Minter {minter} can't provide the remarkables uri of a gift, the rarity's uri template is used")]
+    GiftRemarkablesUriNotAllowed { minter: String },
+
+    #[error("Author {author} doesn't accept gifts")]
+    GiftsNotAccepted { author: String },
+
+    #[error("Author {author} already accepts gifts")]
+    GiftsAlreadyAccepted { author: String },
+
+    #[error("Operator {operator} has already been approved")]
+    OperatorAlreadyApproved { operator: Addr },
+
+    #[error("Operator {operator} has not been approved")]
+    OperatorNotApproved { operator: Addr },
 
     #[error("Token with id {token_id} has already been minted")]
     TokenHasBeenMinted { token_id: String },
+
+    #[error("Invalid rarity name")]
+    InvalidRarityName {},
+
+    #[error("Engagement thresholds of rarities with the same formula must strictly increase with the level")]
+    InvalidRarityThresholds {},
+
+    #[error("Rarity on the level {level} is deprecated")]
+    RarityDeprecated { level: u32 },
+
+    #[error("A deprecated rarity can't be added")]
+    DeprecatedRarityNotAddable {},
+
+    #[error("Remarkables uri not provided and rarity on the level {level} has no uri template")]
+    RemarkablesUriNotProvided { level: u32 },
+
+    #[error("Invalid engagement formula")]
+    InvalidEngagementFormula {},
+
+    #[error("Tips contract not configured")]
+    TipsContractNotConfigured {},
 }
@@ -1,8 +1,12 @@
 #[cfg(test)]
 mod tests {
     use crate::contract::convert_post_id_to_token_id;
-    use crate::msg::{ExecuteMsg, InstantiateMsg, QueryConfigResponse, QueryMsg, Rarity};
+    use crate::msg::{
+        ExecuteMsg, InstantiateMsg, QueryConfigResponse, QueryMsg, QueryOperatorsResponse,
+        QueryRaritiesResponse, Rarity,
+    };
     use crate::test_utils::*;
+    use anyhow::Result as AnyResult;
     use cosmwasm_std::{coins, wasm_execute, Addr, Coin, Empty};
     use cw721::{AllNftInfoResponse, NftInfoResponse, OwnerOfResponse, TokensResponse};
     use cw721_base::{
@@ -10,12 +14,13 @@ mod tests {
         QueryMsg as Cw721QueryMsg,
     };
     use cw721_remarkables::Metadata;
-    use cw_multi_test::{Contract, ContractWrapper, Executor};
+    use cw_multi_test::{AppResponse, Contract, ContractWrapper, Executor};
     use desmos_bindings::{
         mocks::mock_apps::{custom_desmos_app, mock_failing_desmos_app, DesmosApp, DesmosModule},
         msg::DesmosMsg,
         query::DesmosQuery,
     };
+    const REMARKABLES_URI_TEMPLATE: &str = "ipfs://remarkables/common/{post_id}.json";
     fn get_mint_fees() -> Vec<Coin> {
         coins(100, "udsm")
     }
@@ -25,6 +30,10 @@ mod tests {
                 .bank
                 .init_balance(storage, &Addr::unchecked(AUTHOR), get_mint_fees())
                 .unwrap();
+            router
+                .bank
+                .init_balance(storage, &Addr::unchecked(OPERATOR), get_mint_fees())
+                .unwrap();
         })
     }
     fn contract_remarkables() -> Box<dyn Contract<DesmosMsg, DesmosQuery>> {
@@ -53,14 +62,23 @@ mod tests {
             subspace_id: SUBSPACE_ID.into(),
             rarities: vec![
                 Rarity {
+                    name: "common".into(),
                     engagement_threshold: ACCEPTED_ENGAGEMENT_THRESHOLD,
                     mint_fees: get_mint_fees(),
+                    uri_template: Some(REMARKABLES_URI_TEMPLATE.into()),
+                    engagement_formula: None,
+                    deprecated: false,
                 },
                 Rarity {
+                    name: "rare".into(),
                     engagement_threshold: UNACCEPTED_ENGAGEMENT_THRESHOLD,
                     mint_fees: get_mint_fees(),
+                    uri_template: None,
+                    engagement_formula: None,
+                    deprecated: false,
                 },
             ],
+            tips_contract: None,
         }
     }
     fn proper_instantiate<M: DesmosModule>(app: &mut DesmosApp<M>) -> (Addr, (u64, u64)) {
@@ -84,8 +102,9 @@ mod tests {
                 &contract_addr,
                 &ExecuteMsg::Mint {
                     post_id: POST_ID,
-                    remarkables_uri: REMARKABLES_URI.into(),
+                    remarkables_uri: Some(REMARKABLES_URI.into()),
                     rarity_level: ACCEPTED_RARITY_LEVEL,
+                    recipient: None,
                 },
                 get_mint_fees(),
             )
@@ -194,8 +213,9 @@ mod tests {
                         &addr,
                         &ExecuteMsg::Mint {
                             post_id: POST_ID,
-                            remarkables_uri: REMARKABLES_URI.into(),
+                            remarkables_uri: Some(REMARKABLES_URI.into()),
                             rarity_level: ACCEPTED_RARITY_LEVEL,
+                            recipient: None,
                         },
                         get_mint_fees(),
                     )
@@ -240,6 +260,215 @@ mod tests {
             )
         }
     }
+    mod mint_on_behalf {
+        use super::*;
+        fn mint_from_operator<M: DesmosModule>(
+            app: &mut DesmosApp<M>,
+            contract_addr: &Addr,
+            recipient: Option<String>,
+        ) -> AnyResult<AppResponse> {
+            app.execute_contract(
+                Addr::unchecked(OPERATOR),
+                contract_addr.clone(),
+                &ExecuteMsg::Mint {
+                    post_id: POST_ID,
+                    remarkables_uri: None,
+                    rarity_level: ACCEPTED_RARITY_LEVEL,
+                    recipient,
+                },
+                &get_mint_fees(),
+            )
+        }
+        fn accept_gifts<M: DesmosModule>(app: &mut DesmosApp<M>, contract_addr: &Addr) {
+            app.execute_contract(
+                Addr::unchecked(AUTHOR),
+                contract_addr.clone(),
+                &ExecuteMsg::AcceptGifts {},
+                &[],
+            )
+            .unwrap();
+        }
+        fn query_owned_tokens<M: DesmosModule>(
+            app: &DesmosApp<M>,
+            contract_addr: &Addr,
+            owner: &str,
+        ) -> Vec<String> {
+            let response: TokensResponse = app
+                .wrap()
+                .query_wasm_smart(
+                    contract_addr,
+                    &QueryMsg::Tokens {
+                        owner: owner.into(),
+                        start_after: None,
+                        limit: None,
+                    },
+                )
+                .unwrap();
+            response.tokens
+        }
+        #[test]
+        fn gift_mint_not_accepted_error() {
+            let mut app = mock_desmos_app();
+            let (addr, _) = proper_instantiate(&mut app);
+            assert!(mint_from_operator(&mut app, &addr, None).is_err());
+        }
+        #[test]
+        fn gift_mint_properly() {
+            let mut app = mock_desmos_app();
+            let (addr, _) = proper_instantiate(&mut app);
+            accept_gifts(&mut app, &addr);
+            mint_from_operator(&mut app, &addr, None).unwrap();
+            // the gifter paid the fees and the author received the token
+            assert_eq!(
+                get_mint_fees(),
+                app.wrap().query_all_balances(&addr).unwrap()
+            );
+            assert_eq!(
+                get_mint_fees(),
+                app.wrap().query_all_balances(AUTHOR).unwrap()
+            );
+            assert_eq!(
+                vec![convert_post_id_to_token_id(
+                    POST_ID.into(),
+                    ACCEPTED_RARITY_LEVEL
+                )],
+                query_owned_tokens(&app, &addr, AUTHOR)
+            );
+            // the gift uses the uri template of the rarity
+            let response: AllNftInfoResponse<Metadata> = app
+                .wrap()
+                .query_wasm_smart(
+                    &addr,
+                    &QueryMsg::AllNftInfo {
+                        token_id: convert_post_id_to_token_id(
+                            POST_ID.into(),
+                            ACCEPTED_RARITY_LEVEL,
+                        ),
+                        include_expired: None,
+                    },
+                )
+                .unwrap();
+            assert_eq!(
+                Some(REMARKABLES_URI_TEMPLATE.replace("{post_id}", &POST_ID.to_string())),
+                response.info.token_uri
+            );
+        }
+        #[test]
+        fn gift_mint_with_uri_error() {
+            let mut app = mock_desmos_app();
+            let (addr, _) = proper_instantiate(&mut app);
+            accept_gifts(&mut app, &addr);
+            assert!(app
+                .execute_contract(
+                    Addr::unchecked(OPERATOR),
+                    addr,
+                    &ExecuteMsg::Mint {
+                        post_id: POST_ID,
+                        remarkables_uri: Some(REMARKABLES_URI.into()),
+                        rarity_level: ACCEPTED_RARITY_LEVEL,
+                        recipient: None,
+                    },
+                    &get_mint_fees(),
+                )
+                .is_err());
+        }
+        #[test]
+        fn mint_to_recipient_from_not_approved_operator_error() {
+            let mut app = mock_desmos_app();
+            let (addr, _) = proper_instantiate(&mut app);
+            assert!(mint_from_operator(&mut app, &addr, Some(OPERATOR.into())).is_err());
+        }
+        #[test]
+        fn mint_to_recipient_from_approved_operator_properly() {
+            let mut app = mock_desmos_app();
+            let (addr, _) = proper_instantiate(&mut app);
+            app.execute_contract(
+                Addr::unchecked(AUTHOR),
+                addr.clone(),
+                &ExecuteMsg::ApproveOperator {
+                    operator: OPERATOR.into(),
+                },
+                &[],
+            )
+            .unwrap();
+            let operators: QueryOperatorsResponse = app
+                .wrap()
+                .query_wasm_smart(
+                    &addr,
+                    &QueryMsg::Operators {
+                        author: AUTHOR.into(),
+                    },
+                )
+                .unwrap();
+            assert_eq!(vec![Addr::unchecked(OPERATOR)], operators.operators);
+            mint_from_operator(&mut app, &addr, Some(OPERATOR.into())).unwrap();
+            let token_id = convert_post_id_to_token_id(POST_ID.into(), ACCEPTED_RARITY_LEVEL);
+            assert_eq!(
+                vec![token_id.clone()],
+                query_owned_tokens(&app, &addr, OPERATOR)
+            );
+            // the author is still recorded in the metadata
+            let response: AllNftInfoResponse<Metadata> = app
+                .wrap()
+                .query_wasm_smart(
+                    &addr,
+                    &QueryMsg::AllNftInfo {
+                        token_id,
+                        include_expired: None,
+                    },
+                )
+                .unwrap();
+            assert_eq!(
+                Some(Addr::unchecked(AUTHOR)),
+                response.info.extension.author
+            );
+        }
+    }
+    mod deprecate_rarity {
+        use super::*;
+        #[test]
+        fn deprecate_rarity_keeps_existing_tokens() {
+            let mut app = mock_desmos_app();
+            let (addr, _) = proper_instantiate(&mut app);
+            mint_remarkables_nft_properly(&mut app, Addr::unchecked(&addr));
+            app.execute_contract(
+                Addr::unchecked(ADMIN),
+                addr.clone(),
+                &ExecuteMsg::DeprecateRarity {
+                    rarity_level: ACCEPTED_RARITY_LEVEL,
+                },
+                &[],
+            )
+            .unwrap();
+
+            // the minted token is still owned by the author
+            let response: TokensResponse = app
+                .wrap()
+                .query_wasm_smart(
+                    &addr,
+                    &QueryMsg::Tokens {
+                        owner: AUTHOR.into(),
+                        start_after: None,
+                        limit: None,
+                    },
+                )
+                .unwrap();
+            assert_eq!(
+                vec![convert_post_id_to_token_id(
+                    POST_ID.into(),
+                    ACCEPTED_RARITY_LEVEL
+                )],
+                response.tokens
+            );
+
+            // the rarity is marked as deprecated
+            let rarities: QueryRaritiesResponse = app
+                .wrap()
+                .query_wasm_smart(&addr, &QueryMsg::Rarities {})
+                .unwrap();
+            assert!(rarities.rarities[ACCEPTED_RARITY_LEVEL as usize].deprecated);
+        }
+    }
     mod claim_fees {
         use super::*;
         #[test]
@@ -361,6 +590,7 @@ mod tests {
                             rarity_level: ACCEPTED_RARITY_LEVEL,
                             subspace_id: SUBSPACE_ID.into(),
                             post_id: POST_ID.into(),
+                            author: Some(Addr::unchecked(AUTHOR)),
                         },
                     }
                 },
@@ -6,6 +6,9 @@ use cw721_base::InstantiateMsg as Cw721InstantiateMsg;
 use cw721_remarkables::Metadata;
 use url::Url;
 
+#[cw_serde]
+pub struct MigrateMsg {}
+
 #[cw_serde]
 pub struct InstantiateMsg {
     /// Address of who will have the right to administer the contract.
@@ -18,6 +21,8 @@ pub struct InstantiateMsg {
     pub subspace_id: Uint64,
     /// List of rarities to initialize with this contract.
     pub rarities: Vec<Rarity>,
+    /// Optional address of the tips contract used to count the tips received by the posts.
+    pub tips_contract: Option<String>,
 }
 
 impl InstantiateMsg {
@@ -33,31 +38,209 @@ impl InstantiateMsg {
         if self.rarities.is_empty() {
             return Err(ContractError::EmptyRarities {});
         }
+        validate_rarities(&self.rarities)?;
+        if self.tips_contract.is_none() && self.rarities.iter().any(Rarity::uses_tips) {
+            return Err(ContractError::TipsContractNotConfigured {});
+        }
         Ok(())
     }
 }
 
+/// Metric used to measure the engagement of a post.
+#[cw_serde]
+pub enum EngagementMetric {
+    /// Amount of reactions to the post, excluding the ones added by the author.
+    Reactions,
+    /// Amount of users that reacted to the post excluding the author,
+    /// among the first [`REACTIONS_SCAN_LIMIT`] reactions to the post.
+    UniqueReactors,
+    /// Amount of posts replying to the post not created by its author,
+    /// among the [`REFERENCES_SCAN_WINDOW`] posts created right after it.
+    Replies,
+    /// Amount of posts quoting the post not created by its author,
+    /// among the [`REFERENCES_SCAN_WINDOW`] posts created right after it.
+    Quotes,
+    /// Amount of tips received by the post from users other than its author.
+    /// Only the tips kept inside the history of the configured tips contract are counted,
+    /// so the value can't exceed its `tips_history_size` and is always 0 if the history is disabled.
+    Tips,
+}
+
+/// Amount of posts created after a post that are scanned to count its replies and quotes.
+pub const REFERENCES_SCAN_WINDOW: u64 = 100;
+
+/// Maximum amount of reactions to a post that are scanned to count its unique reactors.
+pub const REACTIONS_SCAN_LIMIT: usize = 500;
+
+/// Engagement metric with the weight applied to its value inside an engagement formula.
+#[cw_serde]
+pub struct EngagementWeight {
+    pub metric: EngagementMetric,
+    pub weight: u32,
+}
+
+/// Placeholder of the rarity uri template replaced with the id of the post.
+pub const URI_TEMPLATE_POST_ID: &str = "{post_id}";
+
 #[cw_serde]
 pub struct Rarity {
-    /// Threshold of the reactions amount to mint.
+    /// Name of the rarity, the rarities stored before it was introduced are named on migration.
+    #[serde(default)]
+    pub name: String,
+    /// Threshold of the engagement score to mint.
     pub engagement_threshold: u32,
     /// Mint fees associated with the rarity
     pub mint_fees: Vec<Coin>,
+    /// Optional IPFS uri template used when the remarkables uri is not provided to mint,
+    /// the `{post_id}` placeholder is replaced with the id of the post.
+    pub uri_template: Option<String>,
+    /// Optional weighted metrics summed up to compute the engagement score of a post,
+    /// if not provided the score is the amount of reactions excluding the author's ones.
+    pub engagement_formula: Option<Vec<EngagementWeight>>,
+    /// Tells if the rarity can't be minted anymore.
+    #[serde(default)]
+    pub deprecated: bool,
+}
+
+impl Rarity {
+    /// Checks that the data inside the rarity are coherent.
+    pub fn validate(&self) -> Result<(), ContractError> {
+        if self.name.trim().is_empty() {
+            return Err(ContractError::InvalidRarityName {});
+        }
+        if let Some(uri_template) = &self.uri_template {
+            validate_ipfs_uri(uri_template)?;
+        }
+        if let Some(formula) = &self.engagement_formula {
+            validate_engagement_formula(formula)?;
+        }
+        Ok(())
+    }
+
+    /// Returns the formula used to compute the engagement score of a post.
+    pub fn engagement_formula(&self) -> Vec<EngagementWeight> {
+        self.engagement_formula.clone().unwrap_or_else(|| {
+            vec![EngagementWeight {
+                metric: EngagementMetric::Reactions,
+                weight: 1,
+            }]
+        })
+    }
+
+    /// Tells if the rarity computes the engagement score with the same formula as the other one,
+    /// regardless of the order of the metrics.
+    pub fn has_same_formula(&self, other: &Rarity) -> bool {
+        let formula = self.engagement_formula();
+        let other_formula = other.engagement_formula();
+        formula.len() == other_formula.len()
+            && formula.iter().all(|weight| other_formula.contains(weight))
+    }
+
+    /// Tells if the engagement formula counts the tips received by the post.
+    pub fn uses_tips(&self) -> bool {
+        self.engagement_formula()
+            .iter()
+            .any(|weight| weight.metric == EngagementMetric::Tips)
+    }
+
+    /// Returns the remarkables uri of the given post built from the uri template.
+    pub fn render_uri(&self, post_id: u64) -> Option<String> {
+        self.uri_template
+            .as_ref()
+            .map(|template| template.replace(URI_TEMPLATE_POST_ID, &post_id.to_string()))
+    }
+}
+
+/// Checks that each rarity is valid and that the engagement thresholds of the rarities
+/// sharing the same engagement formula strictly increase with the level.
+/// Thresholds of rarities with different formulas are not comparable, so they are not checked.
+pub fn validate_rarities(rarities: &[Rarity]) -> Result<(), ContractError> {
+    rarities.iter().try_for_each(|rarity| rarity.validate())?;
+    for (level, rarity) in rarities.iter().enumerate() {
+        let previous = rarities[..level]
+            .iter()
+            .rev()
+            .find(|previous| previous.has_same_formula(rarity));
+        if let Some(previous) = previous {
+            if previous.engagement_threshold >= rarity.engagement_threshold {
+                return Err(ContractError::InvalidRarityThresholds {});
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Checks that the engagement formula is not empty and that each metric appears once with a positive weight.
+fn validate_engagement_formula(formula: &[EngagementWeight]) -> Result<(), ContractError> {
+    if formula.is_empty() || formula.iter().any(|weight| weight.weight == 0) {
+        return Err(ContractError::InvalidEngagementFormula {});
+    }
+    for (index, weight) in formula.iter().enumerate() {
+        if formula[..index]
+            .iter()
+            .any(|previous| previous.metric == weight.metric)
+        {
+            return Err(ContractError::InvalidEngagementFormula {});
+        }
+    }
+    Ok(())
+}
+
+/// Checks that the given uri is a valid IPFS url.
+fn validate_ipfs_uri(uri: &str) -> Result<(), ContractError> {
+    let uri = Url::parse(uri).map_err(|_err| ContractError::InvalidRemarkablesUri {})?;
+    if uri.scheme() != "ipfs" {
+        return Err(ContractError::InvalidRemarkablesUri {});
+    }
+    Ok(())
 }
 
 #[cw_serde]
 pub enum ExecuteMsg {
-    /// Message allowing the user to mint a Remarkables for a specific post owned by the user.
+    /// Message allowing the user to mint a Remarkables for a specific post.
+    /// If `remarkables_uri` is not provided, the uri is built from the rarity's uri template.
+    /// Only the post author and the operators approved by them can set a `recipient` other than the author,
+    /// anyone else pays the mint fees to gift the Remarkables to the author, and can't provide
+    /// `remarkables_uri` since gifts always use the rarity's uri template.
+    /// Gifts are allowed only if the author accepts them, see [`ExecuteMsg::AcceptGifts`].
     Mint {
         post_id: Uint64,
-        remarkables_uri: String,
+        remarkables_uri: Option<String>,
         rarity_level: u32,
+        recipient: Option<String>,
     },
+    /// Message allowing a post author to approve an operator minting Remarkables on their behalf.
+    ApproveOperator { operator: String },
+    /// Message allowing a post author to revoke an operator previously approved.
+    RevokeOperator { operator: String },
+    /// Message allowing a post author to accept the Remarkables gifted by anyone for their posts.
+    AcceptGifts {},
+    /// Message allowing a post author to stop accepting gifts previously accepted.
+    RejectGifts {},
     /// Message allowing the contract administrator to update the mint fees of the given rarity level.
     UpdateRarityMintFees {
         rarity_level: u32,
         new_fees: Vec<Coin>,
     },
+    /// Message allowing the contract administrator to add a new rarity as the next level.
+    AddRarity { rarity: Rarity },
+    /// Message allowing the contract administrator to update the engagement threshold of the given rarity level.
+    UpdateRarityThreshold {
+        rarity_level: u32,
+        new_threshold: u32,
+    },
+    /// Message allowing the contract administrator to update, or remove, the engagement formula
+    /// of the given rarity level.
+    UpdateRarityEngagementFormula {
+        rarity_level: u32,
+        engagement_formula: Option<Vec<EngagementWeight>>,
+    },
+    /// Message allowing the contract administrator to set, or remove, the tips contract
+    /// used to count the tips received by the posts.
+    UpdateTipsContract { tips_contract: Option<String> },
+    /// Message allowing the contract administrator to deprecate the given rarity level,
+    /// preventing new mints while keeping the existing tokens valid.
+    DeprecateRarity { rarity_level: u32 },
     /// Message allowing the contract's admin to transfer the admin rights to another user.
     UpdateAdmin { new_admin: String },
     /// Message allowing the contract's admin to claim fees in this contract.
@@ -78,14 +261,22 @@ impl ExecuteMsg {
                     return Err(ContractError::InvalidPostId {});
                 }
                 // Check that the remarkable uri is a valid IPFS url
-                let uri = Url::parse(remarkables_uri)
-                    .map_err(|_err| ContractError::InvalidRemarkablesUri {})?;
-                if uri.scheme() != "ipfs" {
-                    return Err(ContractError::InvalidRemarkablesUri {});
+                if let Some(remarkables_uri) = remarkables_uri {
+                    validate_ipfs_uri(remarkables_uri)?;
                 }
 
                 Ok(())
             }
+            ExecuteMsg::AddRarity { rarity } => {
+                if rarity.deprecated {
+                    return Err(ContractError::DeprecatedRarityNotAddable {});
+                }
+                rarity.validate()
+            }
+            ExecuteMsg::UpdateRarityEngagementFormula {
+                engagement_formula: Some(formula),
+                ..
+            } => validate_engagement_formula(formula),
             _ => Ok(()),
         }
     }
@@ -100,6 +291,16 @@ pub enum QueryMsg {
     // Returns all the rarities info as a [`QueryRaritiesResponse`].
     #[returns(QueryRaritiesResponse)]
     Rarities {},
+    /// Returns the engagement score of a post for each rarity and the rarities that the post
+    /// qualifies for as a [`QueryPostEngagementResponse`].
+    #[returns(QueryPostEngagementResponse)]
+    PostEngagement { post_id: Uint64 },
+    /// Returns the operators approved by the given author as a [`QueryOperatorsResponse`].
+    #[returns(QueryOperatorsResponse)]
+    Operators { author: String },
+    /// Returns if the given author accepts gifts as a [`QueryGiftsAcceptedResponse`].
+    #[returns(QueryGiftsAcceptedResponse)]
+    GiftsAccepted { author: String },
     /// Returns the nft info with approvals from cw721 contract as a [`AllNftInfoResponse`].
     #[returns(AllNftInfoResponse<Metadata>)]
     AllNftInfo {
@@ -126,6 +327,8 @@ pub struct QueryConfigResponse {
     pub cw721_address: Addr,
     /// Id of the subspace to operate.
     pub subspace_id: Uint64,
+    /// Address of the tips contract used to count the tips received by the posts.
+    pub tips_contract: Option<Addr>,
 }
 
 /// Response to [`QueryMsg::Rarities`].
@@ -135,6 +338,42 @@ pub struct QueryRaritiesResponse {
     pub rarities: Vec<Rarity>,
 }
 
+/// Response to [`QueryMsg::PostEngagement`].
+#[cw_serde]
+pub struct QueryPostEngagementResponse {
+    /// Id of the post.
+    pub post_id: Uint64,
+    /// Engagement score of the post computed with the formula of each rarity.
+    pub scores: Vec<RarityEngagementScore>,
+    /// Levels of the rarities that the post currently qualifies for.
+    pub qualified_rarity_levels: Vec<u32>,
+}
+
+/// Response to [`QueryMsg::Operators`].
+#[cw_serde]
+pub struct QueryOperatorsResponse {
+    /// Addresses of the operators allowed to mint Remarkables on behalf of the author.
+    pub operators: Vec<Addr>,
+}
+
+/// Response to [`QueryMsg::GiftsAccepted`].
+#[cw_serde]
+pub struct QueryGiftsAcceptedResponse {
+    /// Tells if anyone can gift Remarkables to the author.
+    pub accepted: bool,
+}
+
+/// Engagement score of a post computed with the formula of a rarity.
+#[cw_serde]
+pub struct RarityEngagementScore {
+    /// Level of the rarity.
+    pub rarity_level: u32,
+    /// Engagement score of the post.
+    pub score: u64,
+    /// Tells if the score reaches the rarity's threshold and the rarity is not deprecated.
+    pub qualified: bool,
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -152,6 +391,7 @@ mod tests {
                 },
                 subspace_id: 0u64.into(),
                 rarities: vec![],
+                tips_contract: None,
             };
             assert_eq!(
                 ContractError::InvalidSubspaceId {},
@@ -170,6 +410,7 @@ mod tests {
                 },
                 subspace_id: 1u64.into(),
                 rarities: vec![],
+                tips_contract: None,
             };
             assert_eq!(
                 ContractError::InvalidCw721CodeId {},
@@ -188,10 +429,128 @@ mod tests {
                 },
                 subspace_id: 1u64.into(),
                 rarities: vec![],
+                tips_contract: None,
             };
             assert_eq!(ContractError::EmptyRarities {}, msg.validate().unwrap_err())
         }
         #[test]
+        fn instantiate_msg_with_not_increasing_thresholds_error() {
+            let rarity = Rarity {
+                name: "common".into(),
+                engagement_threshold: 100,
+                mint_fees: vec![],
+                uri_template: None,
+                engagement_formula: None,
+                deprecated: false,
+            };
+            let msg = InstantiateMsg {
+                admin: "admin".into(),
+                cw721_code_id: 1u64.into(),
+                cw721_instantiate_msg: Cw721InstantiateMsg {
+                    name: "".to_string(),
+                    minter: "".to_string(),
+                    symbol: "".to_string(),
+                },
+                subspace_id: 1u64.into(),
+                rarities: vec![rarity.clone(), rarity],
+                tips_contract: None,
+            };
+            assert_eq!(
+                ContractError::InvalidRarityThresholds {},
+                msg.validate().unwrap_err()
+            )
+        }
+        #[test]
+        fn instantiate_msg_with_not_increasing_thresholds_of_same_formula_error() {
+            let common = Rarity {
+                name: "common".into(),
+                engagement_threshold: 100,
+                mint_fees: vec![],
+                uri_template: None,
+                engagement_formula: Some(vec![
+                    EngagementWeight {
+                        metric: EngagementMetric::Reactions,
+                        weight: 1,
+                    },
+                    EngagementWeight {
+                        metric: EngagementMetric::Replies,
+                        weight: 2,
+                    },
+                ]),
+                deprecated: false,
+            };
+            let rare = Rarity {
+                name: "rare".into(),
+                engagement_threshold: 10,
+                engagement_formula: Some(vec![EngagementWeight {
+                    metric: EngagementMetric::Tips,
+                    weight: 1,
+                }]),
+                ..common.clone()
+            };
+            let mut epic = Rarity {
+                name: "epic".into(),
+                engagement_threshold: 50,
+                ..common.clone()
+            };
+            // Same formula as the common rarity with the metrics in another order
+            epic.engagement_formula.as_mut().unwrap().reverse();
+            let msg = InstantiateMsg {
+                admin: "admin".into(),
+                cw721_code_id: 1u64.into(),
+                cw721_instantiate_msg: Cw721InstantiateMsg {
+                    name: "".to_string(),
+                    minter: "".to_string(),
+                    symbol: "".to_string(),
+                },
+                subspace_id: 1u64.into(),
+                rarities: vec![common, rare, epic],
+                tips_contract: Some("tips".into()),
+            };
+            assert_eq!(
+                ContractError::InvalidRarityThresholds {},
+                msg.validate().unwrap_err()
+            )
+        }
+        #[test]
+        fn instantiate_msg_with_lower_threshold_of_other_formula_no_error() {
+            let common = Rarity {
+                name: "common".into(),
+                engagement_threshold: 100,
+                mint_fees: vec![],
+                uri_template: None,
+                engagement_formula: None,
+                deprecated: false,
+            };
+            let rare = Rarity {
+                name: "rare".into(),
+                engagement_threshold: 10,
+                engagement_formula: Some(vec![EngagementWeight {
+                    metric: EngagementMetric::Replies,
+                    weight: 1,
+                }]),
+                ..common.clone()
+            };
+            let epic = Rarity {
+                name: "epic".into(),
+                engagement_threshold: 200,
+                ..common.clone()
+            };
+            let msg = InstantiateMsg {
+                admin: "admin".into(),
+                cw721_code_id: 1u64.into(),
+                cw721_instantiate_msg: Cw721InstantiateMsg {
+                    name: "".to_string(),
+                    minter: "".to_string(),
+                    symbol: "".to_string(),
+                },
+                subspace_id: 1u64.into(),
+                rarities: vec![common, rare, epic],
+                tips_contract: None,
+            };
+            msg.validate().unwrap()
+        }
+        #[test]
         fn valid_instantiate_msg_no_error() {
             let msg = InstantiateMsg {
                 admin: "admin".into(),
@@ -203,12 +562,46 @@ mod tests {
                 },
                 subspace_id: 1u64.into(),
                 rarities: vec![Rarity {
+                    name: "common".into(),
                     engagement_threshold: 100,
                     mint_fees: vec![],
+                    uri_template: None,
+                    engagement_formula: None,
+                    deprecated: false,
                 }],
+                tips_contract: None,
             };
             msg.validate().unwrap()
         }
+        #[test]
+        fn instantiate_msg_using_tips_without_tips_contract_error() {
+            let msg = InstantiateMsg {
+                admin: "admin".into(),
+                cw721_code_id: 1u64.into(),
+                cw721_instantiate_msg: Cw721InstantiateMsg {
+                    name: "".to_string(),
+                    minter: "".to_string(),
+                    symbol: "".to_string(),
+                },
+                subspace_id: 1u64.into(),
+                rarities: vec![Rarity {
+                    name: "common".into(),
+                    engagement_threshold: 100,
+                    mint_fees: vec![],
+                    uri_template: None,
+                    engagement_formula: Some(vec![EngagementWeight {
+                        metric: EngagementMetric::Tips,
+                        weight: 1,
+                    }]),
+                    deprecated: false,
+                }],
+                tips_contract: None,
+            };
+            assert_eq!(
+                ContractError::TipsContractNotConfigured {},
+                msg.validate().unwrap_err()
+            )
+        }
     }
     mod execute_msg {
         use super::*;
@@ -217,7 +610,8 @@ mod tests {
             let msg = ExecuteMsg::Mint {
                 post_id: 1u64.into(),
                 rarity_level: 1,
-                remarkables_uri: "".into(),
+                remarkables_uri: Some("".into()),
+                recipient: None,
             };
             assert_eq!(
                 msg.validate().unwrap_err(),
@@ -229,7 +623,8 @@ mod tests {
             let msg = ExecuteMsg::Mint {
                 post_id: 1u64.into(),
                 rarity_level: 1,
-                remarkables_uri: "https://remarkables.com".into(),
+                remarkables_uri: Some("https://remarkables.com".into()),
+                recipient: None,
             };
             assert_eq!(
                 msg.validate().unwrap_err(),
@@ -241,7 +636,8 @@ mod tests {
             let msg = ExecuteMsg::Mint {
                 post_id: 0u64.into(),
                 rarity_level: 1,
-                remarkables_uri: "https://remarkables.com".into(),
+                remarkables_uri: Some("https://remarkables.com".into()),
+                recipient: None,
             };
             assert_eq!(msg.validate().unwrap_err(), ContractError::InvalidPostId {})
         }
@@ -250,11 +646,129 @@ mod tests {
             let msg = ExecuteMsg::Mint {
                 post_id: 1u64.into(),
                 rarity_level: 1,
-                remarkables_uri: "ipfs://remarkables.com".into(),
+                remarkables_uri: Some("ipfs://remarkables.com".into()),
+                recipient: None,
             };
             msg.validate().unwrap()
         }
         #[test]
+        fn add_rarity_msg_with_deprecated_rarity_error() {
+            let msg = ExecuteMsg::AddRarity {
+                rarity: Rarity {
+                    name: "rare".into(),
+                    engagement_threshold: 100,
+                    mint_fees: vec![],
+                    uri_template: None,
+                    engagement_formula: None,
+                    deprecated: true,
+                },
+            };
+            assert_eq!(
+                msg.validate().unwrap_err(),
+                ContractError::DeprecatedRarityNotAddable {}
+            )
+        }
+        #[test]
+        fn add_rarity_msg_with_empty_name_error() {
+            let msg = ExecuteMsg::AddRarity {
+                rarity: Rarity {
+                    name: " ".into(),
+                    engagement_threshold: 100,
+                    mint_fees: vec![],
+                    uri_template: None,
+                    engagement_formula: None,
+                    deprecated: false,
+                },
+            };
+            assert_eq!(
+                msg.validate().unwrap_err(),
+                ContractError::InvalidRarityName {}
+            )
+        }
+        #[test]
+        fn add_rarity_msg_with_invalid_uri_template_error() {
+            let msg = ExecuteMsg::AddRarity {
+                rarity: Rarity {
+                    name: "rare".into(),
+                    engagement_threshold: 100,
+                    mint_fees: vec![],
+                    uri_template: Some("https://remarkables.com/{post_id}".into()),
+                    engagement_formula: None,
+                    deprecated: false,
+                },
+            };
+            assert_eq!(
+                msg.validate().unwrap_err(),
+                ContractError::InvalidRemarkablesUri {}
+            )
+        }
+        #[test]
+        fn add_rarity_msg_with_zero_weight_engagement_formula_error() {
+            let msg = ExecuteMsg::AddRarity {
+                rarity: Rarity {
+                    name: "rare".into(),
+                    engagement_threshold: 100,
+                    mint_fees: vec![],
+                    uri_template: None,
+                    engagement_formula: Some(vec![EngagementWeight {
+                        metric: EngagementMetric::Replies,
+                        weight: 0,
+                    }]),
+                    deprecated: false,
+                },
+            };
+            assert_eq!(
+                msg.validate().unwrap_err(),
+                ContractError::InvalidEngagementFormula {}
+            )
+        }
+        #[test]
+        fn update_rarity_engagement_formula_msg_with_duplicated_metric_error() {
+            let msg = ExecuteMsg::UpdateRarityEngagementFormula {
+                rarity_level: 0,
+                engagement_formula: Some(vec![
+                    EngagementWeight {
+                        metric: EngagementMetric::Quotes,
+                        weight: 1,
+                    },
+                    EngagementWeight {
+                        metric: EngagementMetric::Quotes,
+                        weight: 2,
+                    },
+                ]),
+            };
+            assert_eq!(
+                msg.validate().unwrap_err(),
+                ContractError::InvalidEngagementFormula {}
+            )
+        }
+        #[test]
+        fn update_rarity_engagement_formula_msg_with_empty_formula_error() {
+            let msg = ExecuteMsg::UpdateRarityEngagementFormula {
+                rarity_level: 0,
+                engagement_formula: Some(vec![]),
+            };
+            assert_eq!(
+                msg.validate().unwrap_err(),
+                ContractError::InvalidEngagementFormula {}
+            )
+        }
+        #[test]
+        fn render_uri_template_properly() {
+            let rarity = Rarity {
+                name: "rare".into(),
+                engagement_threshold: 100,
+                mint_fees: vec![],
+                uri_template: Some("ipfs://remarkables/{post_id}.json".into()),
+                engagement_formula: None,
+                deprecated: false,
+            };
+            assert_eq!(
+                Some("ipfs://remarkables/42.json".to_string()),
+                rarity.render_uri(42)
+            )
+        }
+        #[test]
         fn other_msgs_no_error() {
             let msg = ExecuteMsg::UpdateAdmin {
                 new_admin: "new_admin".into(),
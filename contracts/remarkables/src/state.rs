@@ -12,9 +12,12 @@ pub struct ConfigState {
     pub admin: Addr,
     pub subspace_id: u64,
     pub cw721_code_id: u64,
+    pub tips_contract: Option<Addr>,
 }
 
 pub const RARITIES: Item<RaritiesState> = Item::new("rarities");
 pub const CONFIG: Item<ConfigState> = Item::new("config");
 pub const CW721_ADDRESS: Item<Addr> = Item::new("cw721_address");
 pub const MINTED_TOKEN: Map<String, bool> = Map::new("minted_token");
+pub const OPERATORS: Map<(&Addr, &Addr), bool> = Map::new("operators");
+pub const GIFTS_ACCEPTED: Map<&Addr, bool> = Map::new("gifts_accepted");
@@ -76,6 +76,7 @@ pub const SUBSPACE_ID: Uint64 = Uint64::new(1);
 pub const POST_ID: Uint64 = Uint64::new(1);
 pub const REMARKABLES_URI: &str = "ipfs://remarkables.com";
 pub const AUTHOR: &str = "desmos1nwp8gxrnmrsrzjdhvk47vvmthzxjtphgxp5ftc";
+pub const OPERATOR: &str = "operator";
 pub const ACCEPTED_RARITY_LEVEL: u32 = 0;
 pub const ACCEPTED_ENGAGEMENT_THRESHOLD: u32 = 10;
 pub const UNACCEPTED_ENGAGEMENT_THRESHOLD: u32 = 100;
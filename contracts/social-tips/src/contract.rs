@@ -1,22 +1,29 @@
 use crate::error::ContractError;
 use crate::msg::{
-    ExecuteMsg, InstantiateMsg, QueryConfigResponse, QueryMsg, QueryPendingTipsResponse,
-    QueryUnclaimedSentTipsResponse,
+    ApplicationHandle, ExecuteMsg, HandleOwner, InstantiateMsg, PendingTipInfo, PendingTipKey,
+    QueryClaimHookResponse, QueryConfigResponse, QueryMsg, QueryPendingTipsResponse,
+    QueryResolveHandleResponse, QueryTotalPendingAmountResponse, QueryUnclaimedSentTipsResponse,
+    ReceiveMsg, TipClaimedHookMsg, TipHookMsg,
+};
+use crate::state::{pending_tips, Config, PendingTip, CLAIM_HOOKS, CONFIG};
+use crate::utils::{
+    serialize_coins, serialize_cw20_coins, sum_coins_sorted, sum_cw20_coins_sorted, transfer_msgs,
 };
-use crate::state::{pending_tips, Config, PendingTip, CONFIG};
-use crate::utils::{serialize_coins, sum_coins_sorted};
 #[cfg(not(feature = "library"))]
 use cosmwasm_std::entry_point;
 use cosmwasm_std::{
-    to_binary, Addr, BankMsg, Binary, Coin, Deps, DepsMut, Env, MessageInfo, Order, Response,
-    StdResult, Storage, Uint64,
+    from_binary, to_binary, Addr, Binary, Coin, Deps, DepsMut, Env, MessageInfo, Order, Reply,
+    Response, StdError, StdResult, Storage, SubMsg, SubMsgResult, Uint64, WasmMsg,
 };
 use cw2::set_contract_version;
+use cw20::{Cw20CoinVerified, Cw20ReceiveMsg};
+use cw_storage_plus::Bound;
 use desmos_bindings::msg::DesmosMsg;
 use desmos_bindings::profiles::models_app_links::ApplicationLinkState;
 use desmos_bindings::profiles::querier::ProfilesQuerier;
 use desmos_bindings::query::DesmosQuery;
 use desmos_bindings::types::PageRequest;
+use std::cmp::Ordering;
 use std::ops::Deref;
 
 // version info for migration info
@@ -28,10 +35,17 @@ const ATTRIBUTE_ACTION: &str = "action";
 const ATTRIBUTE_TIP_COLLECTED: &str = "tip_collected";
 const ATTRIBUTE_TIP_CLAIMER: &str = "tip_claimer";
 const ATTRIBUTE_TIP_AMOUNT: &str = "tip_amount";
+const ATTRIBUTE_CW20_TIP_AMOUNT: &str = "cw20_tip_amount";
 const ATTRIBUTE_REMOVED_TIP_AMOUNT: &str = "removed_tip_amount";
+const ATTRIBUTE_REMOVED_CW20_TIP_AMOUNT: &str = "removed_cw20_tip_amount";
+const ATTRIBUTE_NEW_ALLOWED_DENOMS: &str = "new_allowed_denoms";
+const ATTRIBUTE_NEW_ALLOWED_CW20_TOKENS: &str = "new_allowed_cw20_tokens";
+const ATTRIBUTE_CLAIM_HOOK: &str = "claim_hook";
+const ATTRIBUTE_ERROR: &str = "error";
 const ATTRIBUTE_NEW_MAX_PENDING_TIPS_VALUE: &str = "new_max_pending_tips_value";
 const ATTRIBUTE_NEW_MAX_SENT_PENDING_TIPS_VALUE: &str = "new_max_sent_pending_tips_value";
 const ATTRIBUTE_NEW_ADMIN: &str = "new_admin";
+const ATTRIBUTE_NEW_CLAIM_HOOK_GAS_LIMIT_VALUE: &str = "new_claim_hook_gas_limit_value";
 const ACTION_INSTANTIATE: &str = "instantiate";
 const ACTION_SEND_TIPS: &str = "send_tips";
 const ACTION_UPDATE_ADMIN: &str = "update_admin";
@@ -39,6 +53,18 @@ const ACTION_CLAIM_PENDING_TIPS: &str = "claim_pending_tips";
 const ACTION_UPDATE_MAX_PENDING_TIPS: &str = "update_max_pending_tips";
 const ACTION_UPDATE_MAX_SENT_PENDING_TIPS: &str = "update_max_sent_pending_tips";
 const ACTION_REMOVE_PENDING_TIP: &str = "remove_pending_tip";
+const ACTION_UPDATE_ALLOWED_DENOMS: &str = "update_allowed_denoms";
+const ACTION_UPDATE_ALLOWED_CW20_TOKENS: &str = "update_allowed_cw20_tokens";
+const ACTION_REGISTER_CLAIM_HOOK: &str = "register_claim_hook";
+const ACTION_UNREGISTER_CLAIM_HOOK: &str = "unregister_claim_hook";
+const ACTION_CLAIM_HOOK_FAILED: &str = "claim_hook_failed";
+const ACTION_UPDATE_CLAIM_HOOK_GAS_LIMIT: &str = "update_claim_hook_gas_limit";
+// reply ids
+const CLAIM_HOOK_REPLY_ID: u64 = 1;
+// pagination consts
+const APPLICATION_LINKS_PAGE_SIZE: u64 = 10;
+const DEFAULT_QUERY_LIMIT: u32 = 10;
+const MAX_QUERY_LIMIT: u32 = 30;
 
 #[cfg_attr(not(feature = "library"), entry_point)]
 pub fn instantiate(
@@ -62,6 +88,9 @@ pub fn instantiate(
             admin,
             max_pending_tips: msg.max_pending_tips,
             max_sent_pending_tips: msg.max_sent_pending_tips,
+            allowed_denoms: msg.allowed_denoms,
+            allowed_cw20_tokens: validate_addresses(deps.as_ref(), &msg.allowed_cw20_tokens)?,
+            claim_hook_gas_limit: msg.claim_hook_gas_limit,
         },
     )?;
 
@@ -82,7 +111,16 @@ pub fn execute(
             application,
             handle,
             owner_index,
-        } => send_tip(deps, env, info, application, handle, owner_index),
+            expected_owner,
+        } => send_tip(
+            deps,
+            env,
+            info,
+            application,
+            handle,
+            owner_index,
+            expected_owner,
+        ),
         ExecuteMsg::ClaimTips {} => claim_tips(deps, info),
         ExecuteMsg::UpdateAdmin { new_admin } => update_admin(deps, info, new_admin),
         ExecuteMsg::UpdateMaxPendingTips { value } => update_max_pending_tips(deps, info, value),
@@ -93,6 +131,16 @@ pub fn execute(
             application,
             handle,
         } => remove_pending_tip(deps, info, application, handle),
+        ExecuteMsg::UpdateAllowedDenoms { denoms } => update_allowed_denoms(deps, info, denoms),
+        ExecuteMsg::UpdateAllowedCw20Tokens { tokens } => {
+            update_allowed_cw20_tokens(deps, info, tokens)
+        }
+        ExecuteMsg::UpdateClaimHookGasLimit { value } => {
+            update_claim_hook_gas_limit(deps, info, value)
+        }
+        ExecuteMsg::RegisterClaimHook { contract } => register_claim_hook(deps, info, contract),
+        ExecuteMsg::UnregisterClaimHook {} => unregister_claim_hook(deps, info),
+        ExecuteMsg::Receive(cw20_msg) => receive_cw20(deps, env, info, cw20_msg),
     }
 }
 
@@ -105,6 +153,54 @@ fn check_admin(store: &dyn Storage, sender: &Addr) -> Result<(), ContractError>
     }
 }
 
+/// Converts a list of addresses into a list of [`Addr`] ensuring that they are valid.
+fn validate_addresses(deps: Deps<DesmosQuery>, addresses: &[String]) -> StdResult<Vec<Addr>> {
+    addresses
+        .iter()
+        .map(|address| deps.api.addr_validate(address))
+        .collect()
+}
+
+/// Builds the message that notifies the hook registered from the tip sender,
+/// returns `None` if the sender hasn't registered any hook.
+/// The message is dispatched with `reply_on_error` and capped to the configured
+/// `claim_hook_gas_limit`, so that the hook errors (including running out of gas)
+/// are ignored instead of reverting the claim or the removal of the tip.
+fn claim_hook_msg(
+    storage: &dyn Storage,
+    application: &str,
+    handle: &str,
+    tip: &PendingTip,
+    claimer: Option<Addr>,
+) -> StdResult<Option<SubMsg<DesmosMsg>>> {
+    let hook = match CLAIM_HOOKS.may_load(storage, tip.sender.clone())? {
+        Some(hook) => hook,
+        None => return Ok(None),
+    };
+
+    let gas_limit = CONFIG.load(storage)?.claim_hook_gas_limit;
+    let msg = TipHookMsg::TipClaimedHook(TipClaimedHookMsg {
+        application: application.to_string(),
+        handle: handle.to_string(),
+        sender: tip.sender.clone(),
+        claimer,
+        amount: tip.amount.clone(),
+        cw20_amount: tip.cw20_amount.clone(),
+    });
+
+    Ok(Some(
+        SubMsg::reply_on_error(
+            WasmMsg::Execute {
+                contract_addr: hook.to_string(),
+                msg: to_binary(&msg)?,
+                funds: vec![],
+            },
+            CLAIM_HOOK_REPLY_ID,
+        )
+        .with_gas_limit(gas_limit),
+    ))
+}
+
 pub fn send_tip(
     deps: DepsMut<DesmosQuery>,
     env: Env,
@@ -112,15 +208,90 @@ pub fn send_tip(
     application: String,
     handle: String,
     owner_index: Option<Uint64>,
+    expected_owner: Option<String>,
 ) -> Result<Response<DesmosMsg>, ContractError> {
-    let querier = ProfilesQuerier::new(deps.querier.deref());
-    let sender = info.sender;
     let funds = sum_coins_sorted(info.funds)?;
 
     if funds.is_empty() {
         return Err(ContractError::EmptyTipAmount {});
     }
 
+    // Ensure that all the sent coins can be used to tip, an empty allowlist allows any denom.
+    let config = CONFIG.load(deps.storage)?;
+    if let Some(coin) = funds.iter().find(|coin| {
+        !config.allowed_denoms.is_empty() && !config.allowed_denoms.contains(&coin.denom)
+    }) {
+        return Err(ContractError::DenomNotAllowed {
+            denom: coin.denom.clone(),
+        });
+    }
+
+    let tip = PendingTip {
+        sender: info.sender,
+        amount: funds,
+        cw20_amount: vec![],
+        block_height: env.block.height,
+    };
+
+    send_or_collect_tip(deps, tip, application, handle, owner_index, expected_owner)
+}
+
+fn receive_cw20(
+    deps: DepsMut<DesmosQuery>,
+    env: Env,
+    info: MessageInfo,
+    cw20_msg: Cw20ReceiveMsg,
+) -> Result<Response<DesmosMsg>, ContractError> {
+    // The message sender is the CW20 contract of the sent tokens.
+    let config = CONFIG.load(deps.storage)?;
+    if !config.allowed_cw20_tokens.contains(&info.sender) {
+        return Err(ContractError::Cw20TokenNotAllowed {
+            token: info.sender.to_string(),
+        });
+    }
+
+    if cw20_msg.amount.is_zero() {
+        return Err(ContractError::EmptyTipAmount {});
+    }
+
+    let msg: ReceiveMsg = from_binary(&cw20_msg.msg)?;
+    msg.validate()?;
+
+    let tip = PendingTip {
+        sender: deps.api.addr_validate(&cw20_msg.sender)?,
+        amount: vec![],
+        cw20_amount: vec![Cw20CoinVerified {
+            address: info.sender,
+            amount: cw20_msg.amount,
+        }],
+        block_height: env.block.height,
+    };
+
+    match msg {
+        ReceiveMsg::SendTip {
+            application,
+            handle,
+            owner_index,
+            expected_owner,
+        } => send_or_collect_tip(deps, tip, application, handle, owner_index, expected_owner),
+    }
+}
+
+/// Sends a tip to the user that has linked the provided application handle
+/// or, if the handle is not linked to any profile, stores it as a pending tip.
+fn send_or_collect_tip(
+    deps: DepsMut<DesmosQuery>,
+    tip: PendingTip,
+    application: String,
+    handle: String,
+    owner_index: Option<Uint64>,
+    expected_owner: Option<String>,
+) -> Result<Response<DesmosMsg>, ContractError> {
+    let querier = ProfilesQuerier::new(deps.querier.deref());
+    let expected_owner = expected_owner
+        .map(|owner| deps.api.addr_validate(&owner))
+        .transpose()?;
+
     // Query users that have that application linked to their accounts.
     let response = querier.query_application_link_owners(
         Some(application.clone()),
@@ -134,28 +305,47 @@ pub fn send_tip(
         }),
     )?;
 
-    let serialized_coins = serialize_coins(&funds);
+    let serialized_coins = serialize_coins(&tip.amount);
+    let serialized_cw20_coins = serialize_cw20_coins(&tip.cw20_amount);
 
     return if !response.owners.is_empty() {
         let owner = response.owners.first().unwrap().user.to_string();
 
+        // Ensure that the tip goes to the owner that the sender was expecting.
+        if let Some(expected) = expected_owner {
+            if expected.as_str() != owner {
+                return Err(ContractError::UnexpectedHandleOwner {
+                    application,
+                    handle,
+                    expected: expected.to_string(),
+                    owner,
+                });
+            }
+        }
+
         Ok(Response::new()
             .add_attribute(ATTRIBUTE_ACTION, ACTION_SEND_TIPS)
             .add_attribute(ATTRIBUTE_TIP_COLLECTED, "false")
             .add_attribute(ATTRIBUTE_TIP_CLAIMER, &owner)
             .add_attribute(ATTRIBUTE_TIP_AMOUNT, serialized_coins)
-            .add_message(BankMsg::Send {
-                amount: funds,
-                to_address: owner,
-            }))
+            .add_attribute(ATTRIBUTE_CW20_TIP_AMOUNT, serialized_cw20_coins)
+            .add_messages(transfer_msgs(&owner, tip.amount, tip.cw20_amount)?))
     } else {
+        // The sender expected the handle to be already linked to a profile.
+        if expected_owner.is_some() {
+            return Err(ContractError::HandleNotLinked {
+                application,
+                handle,
+            });
+        }
+
         let config = CONFIG.load(deps.storage)?;
         let tips = pending_tips();
 
         let user_sent_pending_tips_count = tips
             .idx
             .sender
-            .prefix(sender.clone())
+            .prefix(tip.sender.clone())
             .range_raw(deps.storage, None, None, Order::Ascending)
             .count();
 
@@ -177,31 +367,24 @@ pub fn send_tip(
             });
         }
 
-        let key = (application, handle, sender.clone());
+        let key = (application, handle, tip.sender.clone());
         let replaced = tips.may_load(deps.storage, key.clone())?;
 
-        tips.replace(
-            deps.storage,
-            key,
-            Some(&PendingTip {
-                sender,
-                amount: funds,
-                block_height: env.block.height,
-            }),
-            replaced.as_ref(),
-        )?;
+        tips.replace(deps.storage, key, Some(&tip), replaced.as_ref())?;
 
         let mut response = Response::new()
             .add_attribute(ATTRIBUTE_ACTION, ACTION_SEND_TIPS)
             .add_attribute(ATTRIBUTE_TIP_COLLECTED, "true")
-            .add_attribute(ATTRIBUTE_TIP_AMOUNT, serialized_coins);
+            .add_attribute(ATTRIBUTE_TIP_AMOUNT, serialized_coins)
+            .add_attribute(ATTRIBUTE_CW20_TIP_AMOUNT, serialized_cw20_coins);
 
         // Send back the funds of the replaced tip.
         if let Some(replaced_tip) = replaced {
-            response = response.add_message(BankMsg::Send {
-                amount: replaced_tip.amount,
-                to_address: replaced_tip.sender.to_string(),
-            });
+            response = response.add_messages(transfer_msgs(
+                replaced_tip.sender.as_str(),
+                replaced_tip.amount,
+                replaced_tip.cw20_amount,
+            )?);
         }
 
         Ok(response)
@@ -213,12 +396,17 @@ pub fn claim_tips(
     info: MessageInfo,
 ) -> Result<Response<DesmosMsg>, ContractError> {
     let mut coins = Vec::<Coin>::new();
+    let mut cw20_coins = Vec::<Cw20CoinVerified>::new();
+    let mut hook_msgs = Vec::<SubMsg<DesmosMsg>>::new();
     let querier = ProfilesQuerier::new(deps.querier.deref());
     let pending_tips_map = pending_tips();
 
-    for app_link_result in
-        querier.iterate_application_links(Some(info.sender.clone()), None, None, 10)
-    {
+    for app_link_result in querier.iterate_application_links(
+        Some(info.sender.clone()),
+        None,
+        None,
+        APPLICATION_LINKS_PAGE_SIZE,
+    ) {
         let app_link = app_link_result?;
         if app_link.state == ApplicationLinkState::VerificationSuccess {
             let mut pending_tips = pending_tips_map
@@ -230,10 +418,21 @@ pub fn claim_tips(
                 .collect::<StdResult<Vec<_>>>()?;
 
             for (sender, mut pending_tip) in pending_tips.drain(0..) {
+                if let Some(hook_msg) = claim_hook_msg(
+                    deps.storage,
+                    &app_link.data.application,
+                    &app_link.data.username,
+                    &pending_tip,
+                    Some(info.sender.clone()),
+                )? {
+                    hook_msgs.push(hook_msg);
+                }
+
                 pending_tip
                     .amount
                     .drain(0..)
                     .for_each(|coin| coins.push(coin));
+                cw20_coins.append(&mut pending_tip.cw20_amount);
                 pending_tips_map.remove(
                     deps.storage,
                     (
@@ -247,8 +446,9 @@ pub fn claim_tips(
     }
 
     let merged_coins = sum_coins_sorted(coins)?;
+    let merged_cw20_coins = sum_cw20_coins_sorted(cw20_coins)?;
 
-    if merged_coins.is_empty() {
+    if merged_coins.is_empty() && merged_cw20_coins.is_empty() {
         return Err(ContractError::NoTipsAvailable {
             user: info.sender.to_string(),
         });
@@ -258,10 +458,16 @@ pub fn claim_tips(
         .add_attribute(ATTRIBUTE_ACTION, ACTION_CLAIM_PENDING_TIPS)
         .add_attribute(ATTRIBUTE_TIP_CLAIMER, &info.sender)
         .add_attribute(ATTRIBUTE_TIP_AMOUNT, serialize_coins(&merged_coins))
-        .add_message(BankMsg::Send {
-            amount: merged_coins,
-            to_address: info.sender.to_string(),
-        }))
+        .add_attribute(
+            ATTRIBUTE_CW20_TIP_AMOUNT,
+            serialize_cw20_coins(&merged_cw20_coins),
+        )
+        .add_messages(transfer_msgs(
+            info.sender.as_str(),
+            merged_coins,
+            merged_cw20_coins,
+        )?)
+        .add_submessages(hook_msgs))
 }
 
 fn update_admin(
@@ -316,6 +522,81 @@ fn update_max_sent_pending_tips(
         .add_attribute(ATTRIBUTE_NEW_MAX_SENT_PENDING_TIPS_VALUE, value.to_string()))
 }
 
+fn update_allowed_denoms(
+    deps: DepsMut<DesmosQuery>,
+    info: MessageInfo,
+    denoms: Vec<String>,
+) -> Result<Response<DesmosMsg>, ContractError> {
+    check_admin(deps.storage, &info.sender)?;
+
+    let serialized_denoms = denoms.join(",");
+    CONFIG.update::<_, ContractError>(deps.storage, |mut config| {
+        config.allowed_denoms = denoms;
+        Ok(config)
+    })?;
+
+    Ok(Response::new()
+        .add_attribute(ATTRIBUTE_ACTION, ACTION_UPDATE_ALLOWED_DENOMS)
+        .add_attribute(ATTRIBUTE_NEW_ALLOWED_DENOMS, serialized_denoms))
+}
+
+fn update_allowed_cw20_tokens(
+    deps: DepsMut<DesmosQuery>,
+    info: MessageInfo,
+    tokens: Vec<String>,
+) -> Result<Response<DesmosMsg>, ContractError> {
+    check_admin(deps.storage, &info.sender)?;
+
+    let tokens_addr = validate_addresses(deps.as_ref(), &tokens)?;
+    CONFIG.update::<_, ContractError>(deps.storage, |mut config| {
+        config.allowed_cw20_tokens = tokens_addr;
+        Ok(config)
+    })?;
+
+    Ok(Response::new()
+        .add_attribute(ATTRIBUTE_ACTION, ACTION_UPDATE_ALLOWED_CW20_TOKENS)
+        .add_attribute(ATTRIBUTE_NEW_ALLOWED_CW20_TOKENS, tokens.join(",")))
+}
+
+fn update_claim_hook_gas_limit(
+    deps: DepsMut<DesmosQuery>,
+    info: MessageInfo,
+    value: u64,
+) -> Result<Response<DesmosMsg>, ContractError> {
+    check_admin(deps.storage, &info.sender)?;
+
+    CONFIG.update::<_, ContractError>(deps.storage, |mut config| {
+        config.claim_hook_gas_limit = value;
+        Ok(config)
+    })?;
+
+    Ok(Response::new()
+        .add_attribute(ATTRIBUTE_ACTION, ACTION_UPDATE_CLAIM_HOOK_GAS_LIMIT)
+        .add_attribute(ATTRIBUTE_NEW_CLAIM_HOOK_GAS_LIMIT_VALUE, value.to_string()))
+}
+
+fn register_claim_hook(
+    deps: DepsMut<DesmosQuery>,
+    info: MessageInfo,
+    contract: String,
+) -> Result<Response<DesmosMsg>, ContractError> {
+    let contract_addr = deps.api.addr_validate(&contract)?;
+    CLAIM_HOOKS.save(deps.storage, info.sender, &contract_addr)?;
+
+    Ok(Response::new()
+        .add_attribute(ATTRIBUTE_ACTION, ACTION_REGISTER_CLAIM_HOOK)
+        .add_attribute(ATTRIBUTE_CLAIM_HOOK, contract_addr))
+}
+
+fn unregister_claim_hook(
+    deps: DepsMut<DesmosQuery>,
+    info: MessageInfo,
+) -> Result<Response<DesmosMsg>, ContractError> {
+    CLAIM_HOOKS.remove(deps.storage, info.sender);
+
+    Ok(Response::new().add_attribute(ATTRIBUTE_ACTION, ACTION_UNREGISTER_CLAIM_HOOK))
+}
+
 fn remove_pending_tip(
     deps: DepsMut<DesmosQuery>,
     info: MessageInfo,
@@ -329,6 +610,7 @@ fn remove_pending_tip(
     if let Some(to_remove_tip) = pending_tip_option {
         let refund_address = key.2.to_string();
         pending_tips_map.replace(deps.storage, key, None, Some(&to_remove_tip))?;
+        let hook_msg = claim_hook_msg(deps.storage, &application, &handle, &to_remove_tip, None)?;
 
         Ok(Response::new()
             .add_attribute(ATTRIBUTE_ACTION, ACTION_REMOVE_PENDING_TIP)
@@ -336,10 +618,16 @@ fn remove_pending_tip(
                 ATTRIBUTE_REMOVED_TIP_AMOUNT,
                 serialize_coins(&to_remove_tip.amount),
             )
-            .add_message(BankMsg::Send {
-                amount: to_remove_tip.amount,
-                to_address: refund_address,
-            }))
+            .add_attribute(
+                ATTRIBUTE_REMOVED_CW20_TIP_AMOUNT,
+                serialize_cw20_coins(&to_remove_tip.cw20_amount),
+            )
+            .add_messages(transfer_msgs(
+                &refund_address,
+                to_remove_tip.amount,
+                to_remove_tip.cw20_amount,
+            )?)
+            .add_submessages(hook_msg))
     } else {
         Err(ContractError::NoPendingTip {
             application,
@@ -348,43 +636,154 @@ fn remove_pending_tip(
     }
 }
 
+// Reply callback triggered when a claim hook fails
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn reply(
+    _deps: DepsMut<DesmosQuery>,
+    _env: Env,
+    msg: Reply,
+) -> Result<Response<DesmosMsg>, ContractError> {
+    if msg.id != CLAIM_HOOK_REPLY_ID {
+        return Err(ContractError::InvalidReplyID {});
+    }
+
+    // Ignore the hook error so that the claim is not reverted.
+    let error = match msg.result {
+        SubMsgResult::Err(error) => error,
+        SubMsgResult::Ok(_) => String::new(),
+    };
+
+    Ok(Response::new()
+        .add_attribute(ATTRIBUTE_ACTION, ACTION_CLAIM_HOOK_FAILED)
+        .add_attribute(ATTRIBUTE_ERROR, error))
+}
+
 #[cfg_attr(not(feature = "library"), entry_point)]
 pub fn query(deps: Deps<DesmosQuery>, _env: Env, msg: QueryMsg) -> StdResult<Binary> {
     match msg {
-        QueryMsg::UserPendingTips { user } => to_binary(&query_user_pending_tips(deps, user)?),
-        QueryMsg::UnclaimedSentTips { user } => to_binary(&query_unclaimed_sent_tips(deps, user)?),
+        QueryMsg::UserPendingTips {
+            user,
+            start_after,
+            limit,
+        } => to_binary(&query_user_pending_tips(deps, user, start_after, limit)?),
+        QueryMsg::UnclaimedSentTips {
+            user,
+            start_after,
+            limit,
+        } => to_binary(&query_unclaimed_sent_tips(deps, user, start_after, limit)?),
+        QueryMsg::PendingTipsByHandle {
+            application,
+            handle,
+            start_after,
+            limit,
+        } => to_binary(&query_pending_tips_by_handle(
+            deps,
+            application,
+            handle,
+            start_after,
+            limit,
+        )?),
+        QueryMsg::TotalPendingAmount {
+            application,
+            handle,
+        } => to_binary(&query_total_pending_amount(deps, application, handle)?),
+        QueryMsg::ResolveHandle {
+            application,
+            handle,
+        } => to_binary(&query_resolve_handle(deps, application, handle)?),
+        QueryMsg::ClaimHook { user } => to_binary(&query_claim_hook(deps, user)?),
         QueryMsg::Config {} => to_binary(&query_config(deps)?),
     }
 }
 
+/// Returns the number of items that a paginated query can return.
+/// * `limit` - Limit requested from the user.
+fn query_limit(limit: Option<u32>) -> usize {
+    limit.unwrap_or(DEFAULT_QUERY_LIMIT).min(MAX_QUERY_LIMIT) as usize
+}
+
+/// Returns at most `limit` pending tips sent to an application handle
+/// starting after the provided sender.
+fn pending_tips_of_handle(
+    deps: Deps<DesmosQuery>,
+    application: &str,
+    handle: &str,
+    start_after: Option<Addr>,
+    limit: usize,
+) -> StdResult<Vec<PendingTipInfo>> {
+    pending_tips()
+        .prefix((application.to_string(), handle.to_string()))
+        .range(
+            deps.storage,
+            start_after.map(Bound::exclusive),
+            None,
+            Order::Ascending,
+        )
+        .take(limit)
+        .map(|item| {
+            item.map(|(_, pending_tip)| {
+                PendingTipInfo::new(application.to_string(), handle.to_string(), pending_tip)
+            })
+        })
+        .collect()
+}
+
 fn query_user_pending_tips(
     deps: Deps<DesmosQuery>,
     user: String,
+    start_after: Option<PendingTipKey>,
+    limit: Option<u32>,
 ) -> StdResult<QueryPendingTipsResponse> {
     let user_addr = deps.api.addr_validate(&user)?;
+    let limit = query_limit(limit);
+    let start_after = start_after
+        .map(|key| -> StdResult<_> {
+            Ok((
+                key.application,
+                key.handle,
+                deps.api.addr_validate(&key.sender)?,
+            ))
+        })
+        .transpose()?;
     let querier = ProfilesQuerier::new(deps.querier.deref());
-    let mut tips = Vec::<PendingTip>::new();
 
-    for app_link_result in querier.iterate_application_links(Some(user_addr), None, None, 10) {
+    // Collect the verified handles of the user, sorted so that the pages are stable.
+    let mut handles = Vec::<(String, String)>::new();
+    for app_link_result in
+        querier.iterate_application_links(Some(user_addr), None, None, APPLICATION_LINKS_PAGE_SIZE)
+    {
         let app_link = app_link_result?;
         if app_link.state == ApplicationLinkState::VerificationSuccess {
-            let key_prefix = (app_link.data.application, app_link.data.username);
-            pending_tips()
-                .prefix(key_prefix)
-                .range(deps.storage, None, None, Order::Ascending)
-                .try_for_each(|item| {
-                    if let Ok((sender, pending_tip)) = item {
-                        tips.push(PendingTip {
-                            sender,
-                            amount: pending_tip.amount,
-                            block_height: pending_tip.block_height,
-                        });
-                        Ok(())
-                    } else {
-                        Err(item.unwrap_err())
-                    }
-                })?;
+            handles.push((app_link.data.application, app_link.data.username));
+        }
+    }
+    handles.sort();
+
+    let mut tips = Vec::<PendingTipInfo>::new();
+    for (application, handle) in handles {
+        if tips.len() >= limit {
+            break;
         }
+
+        // Skip the handles that precede the one of the provided key.
+        let sender_start_after = match &start_after {
+            Some((start_application, start_handle, sender)) => {
+                match (&application, &handle).cmp(&(start_application, start_handle)) {
+                    Ordering::Less => continue,
+                    Ordering::Equal => Some(sender.clone()),
+                    Ordering::Greater => None,
+                }
+            }
+            None => None,
+        };
+
+        tips.extend(pending_tips_of_handle(
+            deps,
+            &application,
+            &handle,
+            sender_start_after,
+            limit - tips.len(),
+        )?);
     }
 
     Ok(QueryPendingTipsResponse { tips })
@@ -393,20 +792,119 @@ fn query_user_pending_tips(
 fn query_unclaimed_sent_tips(
     deps: Deps<DesmosQuery>,
     sender: String,
+    start_after: Option<ApplicationHandle>,
+    limit: Option<u32>,
 ) -> StdResult<QueryUnclaimedSentTipsResponse> {
     let sender = deps.api.addr_validate(&sender)?;
+    let limit = query_limit(limit);
+    let start_after =
+        start_after.map(|key| Bound::exclusive((key.application, key.handle, sender.clone())));
 
     let tips = pending_tips()
         .idx
         .sender
         .prefix(sender)
-        .range(deps.storage, None, None, Order::Ascending)
-        .map(|item| item.map(|item| item.1))
+        .range(deps.storage, start_after, None, Order::Ascending)
+        .take(limit)
+        .map(|item| {
+            item.map(|((application, handle, _), pending_tip)| {
+                PendingTipInfo::new(application, handle, pending_tip)
+            })
+        })
         .collect::<StdResult<_>>()?;
 
     Ok(QueryUnclaimedSentTipsResponse { tips })
 }
 
+fn query_pending_tips_by_handle(
+    deps: Deps<DesmosQuery>,
+    application: String,
+    handle: String,
+    start_after: Option<String>,
+    limit: Option<u32>,
+) -> StdResult<QueryPendingTipsResponse> {
+    let start_after = start_after
+        .map(|sender| deps.api.addr_validate(&sender))
+        .transpose()?;
+
+    let tips =
+        pending_tips_of_handle(deps, &application, &handle, start_after, query_limit(limit))?;
+
+    Ok(QueryPendingTipsResponse { tips })
+}
+
+fn query_total_pending_amount(
+    deps: Deps<DesmosQuery>,
+    application: String,
+    handle: String,
+) -> StdResult<QueryTotalPendingAmountResponse> {
+    let mut coins = Vec::<Coin>::new();
+    let mut cw20_coins = Vec::<Cw20CoinVerified>::new();
+    for item in pending_tips().prefix((application, handle)).range(
+        deps.storage,
+        None,
+        None,
+        Order::Ascending,
+    ) {
+        let (_, pending_tip) = item?;
+        coins.extend(pending_tip.amount);
+        cw20_coins.extend(pending_tip.cw20_amount);
+    }
+
+    let amount =
+        sum_coins_sorted(coins).map_err(|error| StdError::generic_err(error.to_string()))?;
+    let cw20_amount = sum_cw20_coins_sorted(cw20_coins)
+        .map_err(|error| StdError::generic_err(error.to_string()))?;
+
+    Ok(QueryTotalPendingAmountResponse {
+        amount,
+        cw20_amount,
+    })
+}
+
+fn query_resolve_handle(
+    deps: Deps<DesmosQuery>,
+    application: String,
+    handle: String,
+) -> StdResult<QueryResolveHandleResponse> {
+    let querier = ProfilesQuerier::new(deps.querier.deref());
+    let mut owners = Vec::<HandleOwner>::new();
+
+    for owner_result in querier.iterate_application_link_owners(
+        Some(application.clone()),
+        Some(handle.clone()),
+        APPLICATION_LINKS_PAGE_SIZE,
+    ) {
+        let owner = owner_result?;
+
+        // Load the link to get its state and creation time.
+        for app_link_result in querier.iterate_application_links(
+            Some(owner.user.clone()),
+            Some(application.clone()),
+            Some(handle.clone()),
+            APPLICATION_LINKS_PAGE_SIZE,
+        ) {
+            let app_link = app_link_result?;
+            if app_link.state == ApplicationLinkState::VerificationSuccess {
+                owners.push(HandleOwner {
+                    user: app_link.user,
+                    creation_time: app_link.creation_time,
+                });
+            }
+        }
+    }
+
+    Ok(QueryResolveHandleResponse { owners })
+}
+
+fn query_claim_hook(deps: Deps<DesmosQuery>, user: String) -> StdResult<QueryClaimHookResponse> {
+    let user_addr = deps.api.addr_validate(&user)?;
+
+    Ok(QueryClaimHookResponse {
+        contract: CLAIM_HOOKS.may_load(deps.storage, user_addr)?,
+    })
+}
+
 fn query_config(deps: Deps<DesmosQuery>) -> StdResult<QueryConfigResponse> {
     let config = CONFIG.load(deps.storage)?;
 
@@ -414,6 +912,9 @@ fn query_config(deps: Deps<DesmosQuery>) -> StdResult<QueryConfigResponse> {
         admin: config.admin,
         max_pending_tips: config.max_pending_tips,
         max_sent_pending_tips: config.max_sent_pending_tips,
+        allowed_denoms: config.allowed_denoms,
+        allowed_cw20_tokens: config.allowed_cw20_tokens,
+        claim_hook_gas_limit: config.claim_hook_gas_limit,
     })
 }
 
@@ -421,19 +922,22 @@ fn query_config(deps: Deps<DesmosQuery>) -> StdResult<QueryConfigResponse> {
 mod tests {
     use super::*;
     use crate::msg::{
-        ExecuteMsg, InstantiateMsg, QueryConfigResponse, QueryMsg, QueryPendingTipsResponse,
-        QueryUnclaimedSentTipsResponse,
+        ApplicationHandle, ExecuteMsg, HandleOwner, InstantiateMsg, PendingTipInfo,
+        QueryClaimHookResponse, QueryConfigResponse, QueryMsg, QueryPendingTipsResponse,
+        QueryResolveHandleResponse, QueryTotalPendingAmountResponse,
+        QueryUnclaimedSentTipsResponse, ReceiveMsg, TipClaimedHookMsg, TipHookMsg,
     };
     use crate::state::{
-        pending_tips, PendingTip, CONFIG, MAX_CONFIGURABLE_PENDING_TIPS,
-        MAX_CONFIGURABLE_SENT_PENDING_TIPS,
+        pending_tips, Config, PendingTip, CONFIG, DEFAULT_CLAIM_HOOK_GAS_LIMIT,
+        MAX_CONFIGURABLE_PENDING_TIPS, MAX_CONFIGURABLE_SENT_PENDING_TIPS,
     };
     use crate::ContractError;
     use cosmwasm_std::testing::{mock_env, mock_info};
     use cosmwasm_std::{
-        from_binary, to_binary, Addr, BankMsg, Coin, ContractResult, DepsMut, Order, Response,
-        StdResult, SubMsg, Uint64,
+        from_binary, from_slice, to_binary, Addr, BankMsg, Coin, ContractResult, DepsMut, Order,
+        Response, StdResult, SubMsg, Uint128, Uint64, WasmMsg,
     };
+    use cw20::{Cw20CoinVerified, Cw20ExecuteMsg, Cw20ReceiveMsg};
     use desmos_bindings::mocks::mock_queriers::{
         mock_desmos_dependencies, mock_desmos_dependencies_with_custom_querier, MockDesmosQuerier,
     };
@@ -452,6 +956,9 @@ mod tests {
     const ADMIN: &str = "admin";
     const SENDER: &str = "user1";
     const CLAIMER: &str = "user2";
+    const CW20_TOKEN: &str = "cw20_token";
+    const HOOK_CONTRACT: &str = "hook_contract";
+    const CLAIM_HOOK_GAS_LIMIT: u64 = 100_000;
 
     fn init_contract(
         deps: DepsMut<DesmosQuery>,
@@ -468,6 +975,9 @@ mod tests {
                 admin: None,
                 max_pending_tips,
                 max_sent_pending_tips,
+                allowed_denoms: vec!["udsm".to_string(), "uatom".to_string()],
+                allowed_cw20_tokens: vec![CW20_TOKEN.to_string()],
+                claim_hook_gas_limit: CLAIM_HOOK_GAS_LIMIT,
             },
         )
     }
@@ -487,6 +997,33 @@ mod tests {
         })
     }
 
+    fn mock_app_link(
+        user: &str,
+        state: ApplicationLinkState,
+        creation_time: &str,
+    ) -> ApplicationLink {
+        ApplicationLink {
+            user: Addr::unchecked(user),
+            data: Data {
+                username: "handle".to_string(),
+                application: "application".to_string(),
+            },
+            state,
+            oracle_request: OracleRequest {
+                id: Uint64::new(0),
+                oracle_script_id: Uint64::new(0),
+                call_data: CallData {
+                    application: "".to_string(),
+                    call_data: "".to_string(),
+                },
+                client_id: "".to_string(),
+            },
+            result: None,
+            creation_time: creation_time.to_string(),
+            expiration_time: "".to_string(),
+        }
+    }
+
     fn get_pending_tips(
         deps: DepsMut<DesmosQuery>,
         application: &str,
@@ -570,6 +1107,7 @@ mod tests {
                 application: "".to_string(),
                 handle: "user".to_string(),
                 owner_index: None,
+                expected_owner: None,
             },
         )
         .unwrap_err();
@@ -593,6 +1131,7 @@ mod tests {
                 application: "application".to_string(),
                 handle: "".to_string(),
                 owner_index: None,
+                expected_owner: None,
             },
         )
         .unwrap_err();
@@ -616,6 +1155,7 @@ mod tests {
                 application: "application".to_string(),
                 handle: "handle".to_string(),
                 owner_index: None,
+                expected_owner: None,
             },
         )
         .unwrap_err();
@@ -664,6 +1204,7 @@ mod tests {
                 application: "application".to_string(),
                 handle: "handle".to_string(),
                 owner_index: Some(Uint64::new(OWNER_INDEX)),
+                expected_owner: None,
             },
         )
         .unwrap();
@@ -694,6 +1235,7 @@ mod tests {
                 application: "application".to_string(),
                 handle: "handle".to_string(),
                 owner_index: None,
+                expected_owner: None,
             },
         )
         .unwrap();
@@ -704,6 +1246,7 @@ mod tests {
             vec![PendingTip {
                 sender: Addr::unchecked(SENDER),
                 amount: vec![Coin::new(10_000, "udsm")],
+                cw20_amount: vec![],
                 block_height: 12345
             }],
             pending_tips
@@ -726,6 +1269,7 @@ mod tests {
                     application: "application".to_string(),
                     handle: "handle".to_string(),
                     owner_index: None,
+                    expected_owner: None,
                 },
             )
             .unwrap();
@@ -739,6 +1283,7 @@ mod tests {
                 application: "application".to_string(),
                 handle: "handle".to_string(),
                 owner_index: None,
+                expected_owner: None,
             },
         )
         .unwrap_err();
@@ -768,6 +1313,7 @@ mod tests {
                     application: "application".to_string(),
                     handle: format!("handle{}", i),
                     owner_index: None,
+                    expected_owner: None,
                 },
             )
             .unwrap();
@@ -781,6 +1327,7 @@ mod tests {
                 application: "application".to_string(),
                 handle: "handle3".to_string(),
                 owner_index: None,
+                expected_owner: None,
             },
         )
         .unwrap_err();
@@ -804,6 +1351,7 @@ mod tests {
                 application: "application".to_string(),
                 handle: "handle".to_string(),
                 owner_index: None,
+                expected_owner: None,
             },
         )
         .unwrap();
@@ -816,6 +1364,7 @@ mod tests {
                 application: "application".to_string(),
                 handle: "handle".to_string(),
                 owner_index: None,
+                expected_owner: None,
             },
         )
         .unwrap();
@@ -833,6 +1382,7 @@ mod tests {
             vec![PendingTip {
                 sender: Addr::unchecked(SENDER),
                 amount: vec![Coin::new(20_000, "udsm")],
+                cw20_amount: vec![],
                 block_height: 12345,
             }],
             pending_tips
@@ -872,6 +1422,7 @@ mod tests {
                 application: "application".to_string(),
                 handle: "handle".to_string(),
                 owner_index: None,
+                expected_owner: None,
             },
         )
         .unwrap();
@@ -886,96 +1437,635 @@ mod tests {
     }
 
     #[test]
-    fn claim_no_pending_tips_error() {
-        let mut deps = mock_desmos_dependencies();
-        let env = mock_env();
-        let info = mock_info(CLAIMER, &[]);
-
-        init_contract(deps.as_mut(), 10, 10).unwrap();
-
-        let error = execute(deps.as_mut(), env, info, ExecuteMsg::ClaimTips {}).unwrap_err();
-
-        assert_eq!(
-            error,
-            ContractError::NoTipsAvailable {
-                user: CLAIMER.to_string()
+    fn tip_to_unexpected_owner_error() {
+        let querier = MockDesmosQuerier::default().with_custom_profiles_handler(|profiler_query| {
+            match profiler_query {
+                ProfilesQuery::ApplicationLinkOwners { .. } => {
+                    let response = QueryApplicationLinkOwnersResponse {
+                        owners: vec![ApplicationLinkOwnerDetails {
+                            user: Addr::unchecked(CLAIMER),
+                            application: "application".to_string(),
+                            username: "handle".to_string(),
+                        }],
+                        pagination: None,
+                    };
+                    to_binary(&response).into()
+                }
+                _ => mock_profiles_query_response(profiler_query),
             }
-        )
-    }
+        });
 
-    #[test]
-    fn claim_pending_tip_properly() {
-        let querier = querier_with_no_app_links();
         let mut deps = mock_desmos_dependencies_with_custom_querier(querier);
-        let env = mock_env();
-        let info = mock_info(SENDER, &[Coin::new(10_000, "udsm")]);
-
         init_contract(deps.as_mut(), 10, 10).unwrap();
 
-        execute(
+        let error = execute(
             deps.as_mut(),
-            env,
-            info,
+            mock_env(),
+            mock_info(SENDER, &[Coin::new(10_000, "udsm")]),
             ExecuteMsg::SendTip {
                 application: "application".to_string(),
-                handle: "handler".to_string(),
+                handle: "handle".to_string(),
                 owner_index: None,
+                expected_owner: Some("user3".to_string()),
             },
         )
-        .unwrap();
+        .unwrap_err();
+
+        assert_eq!(
+            ContractError::UnexpectedHandleOwner {
+                application: "application".to_string(),
+                handle: "handle".to_string(),
+                expected: "user3".to_string(),
+                owner: CLAIMER.to_string(),
+            },
+            error
+        );
+
+        let response = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info(SENDER, &[Coin::new(10_000, "udsm")]),
+            ExecuteMsg::SendTip {
+                application: "application".to_string(),
+                handle: "handle".to_string(),
+                owner_index: None,
+                expected_owner: Some(CLAIMER.to_string()),
+            },
+        )
+        .unwrap();
+
+        assert_eq!(
+            vec![SubMsg::new(BankMsg::Send {
+                amount: vec![Coin::new(10_000, "udsm")],
+                to_address: CLAIMER.to_string()
+            })],
+            response.messages
+        );
+    }
+
+    #[test]
+    fn tip_expected_owner_to_not_linked_handle_error() {
+        let querier = querier_with_no_app_links();
+        let mut deps = mock_desmos_dependencies_with_custom_querier(querier);
+
+        init_contract(deps.as_mut(), 10, 10).unwrap();
+
+        let error = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info(SENDER, &[Coin::new(10_000, "udsm")]),
+            ExecuteMsg::SendTip {
+                application: "application".to_string(),
+                handle: "handle".to_string(),
+                owner_index: None,
+                expected_owner: Some(CLAIMER.to_string()),
+            },
+        )
+        .unwrap_err();
+
+        assert_eq!(
+            ContractError::HandleNotLinked {
+                application: "application".to_string(),
+                handle: "handle".to_string(),
+            },
+            error
+        );
+
+        // Ensure that the tip has not been collected
+        let pending_tips = get_pending_tips(deps.as_mut(), "application", "handle");
+        assert_eq!(Vec::<PendingTip>::new(), pending_tips);
+    }
+
+    #[test]
+    fn tip_with_not_allowed_denom_error() {
+        let querier = querier_with_no_app_links();
+        let mut deps = mock_desmos_dependencies_with_custom_querier(querier);
+
+        init_contract(deps.as_mut(), 10, 10).unwrap();
+
+        let error = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info(
+                SENDER,
+                &[Coin::new(10_000, "udsm"), Coin::new(10_000, "uosmo")],
+            ),
+            ExecuteMsg::SendTip {
+                application: "application".to_string(),
+                handle: "handle".to_string(),
+                owner_index: None,
+                expected_owner: None,
+            },
+        )
+        .unwrap_err();
+
+        assert_eq!(
+            ContractError::DenomNotAllowed {
+                denom: "uosmo".to_string()
+            },
+            error
+        );
+    }
+
+    #[test]
+    fn tip_with_empty_allowed_denoms_properly() {
+        let querier = querier_with_no_app_links();
+        let mut deps = mock_desmos_dependencies_with_custom_querier(querier);
+
+        init_contract(deps.as_mut(), 10, 10).unwrap();
+        CONFIG
+            .update(deps.as_mut().storage, |mut config| -> StdResult<_> {
+                config.allowed_denoms = vec![];
+                Ok(config)
+            })
+            .unwrap();
+
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info(SENDER, &[Coin::new(10_000, "uosmo")]),
+            ExecuteMsg::SendTip {
+                application: "application".to_string(),
+                handle: "handle".to_string(),
+                owner_index: None,
+                expected_owner: None,
+            },
+        )
+        .unwrap();
+
+        let pending_tips = get_pending_tips(deps.as_mut(), "application", "handle");
+        assert_eq!(vec![Coin::new(10_000, "uosmo")], pending_tips[0].amount);
+    }
+
+    #[test]
+    fn load_config_without_new_fields_properly() {
+        let config: Config =
+            from_slice(br#"{"admin":"admin","max_pending_tips":10,"max_sent_pending_tips":10}"#)
+                .unwrap();
+
+        assert!(config.allowed_denoms.is_empty());
+        assert!(config.allowed_cw20_tokens.is_empty());
+        assert_eq!(DEFAULT_CLAIM_HOOK_GAS_LIMIT, config.claim_hook_gas_limit);
+    }
+
+    #[test]
+    fn tip_with_not_allowed_cw20_token_error() {
+        let querier = querier_with_no_app_links();
+        let mut deps = mock_desmos_dependencies_with_custom_querier(querier);
+
+        init_contract(deps.as_mut(), 10, 10).unwrap();
+
+        let error = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("other_token", &[]),
+            ExecuteMsg::Receive(Cw20ReceiveMsg {
+                sender: SENDER.to_string(),
+                amount: Uint128::new(10_000),
+                msg: to_binary(&ReceiveMsg::SendTip {
+                    application: "application".to_string(),
+                    handle: "handle".to_string(),
+                    owner_index: None,
+                    expected_owner: None,
+                })
+                .unwrap(),
+            }),
+        )
+        .unwrap_err();
+
+        assert_eq!(
+            ContractError::Cw20TokenNotAllowed {
+                token: "other_token".to_string()
+            },
+            error
+        );
+    }
+
+    #[test]
+    fn cw20_tip_sent_properly() {
+        let querier = MockDesmosQuerier::default().with_custom_profiles_handler(|profiler_query| {
+            match profiler_query {
+                ProfilesQuery::ApplicationLinkOwners { .. } => {
+                    let response = QueryApplicationLinkOwnersResponse {
+                        owners: vec![ApplicationLinkOwnerDetails {
+                            user: Addr::unchecked(CLAIMER),
+                            application: "application".to_string(),
+                            username: "handle".to_string(),
+                        }],
+                        pagination: None,
+                    };
+                    to_binary(&response).into()
+                }
+                _ => mock_profiles_query_response(profiler_query),
+            }
+        });
+        let mut deps = mock_desmos_dependencies_with_custom_querier(querier);
+
+        init_contract(deps.as_mut(), 10, 10).unwrap();
+
+        let response = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info(CW20_TOKEN, &[]),
+            ExecuteMsg::Receive(Cw20ReceiveMsg {
+                sender: SENDER.to_string(),
+                amount: Uint128::new(10_000),
+                msg: to_binary(&ReceiveMsg::SendTip {
+                    application: "application".to_string(),
+                    handle: "handle".to_string(),
+                    owner_index: None,
+                    expected_owner: None,
+                })
+                .unwrap(),
+            }),
+        )
+        .unwrap();
+
+        assert_eq!(
+            vec![SubMsg::<DesmosMsg>::new(WasmMsg::Execute {
+                contract_addr: CW20_TOKEN.to_string(),
+                msg: to_binary(&Cw20ExecuteMsg::Transfer {
+                    recipient: CLAIMER.to_string(),
+                    amount: Uint128::new(10_000),
+                })
+                .unwrap(),
+                funds: vec![],
+            })],
+            response.messages
+        );
+    }
+
+    #[test]
+    fn cw20_tip_collected_and_claimed_properly() {
+        let querier = querier_with_no_app_links();
+        let mut deps = mock_desmos_dependencies_with_custom_querier(querier);
+
+        init_contract(deps.as_mut(), 10, 10).unwrap();
+
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info(CW20_TOKEN, &[]),
+            ExecuteMsg::Receive(Cw20ReceiveMsg {
+                sender: SENDER.to_string(),
+                amount: Uint128::new(10_000),
+                msg: to_binary(&ReceiveMsg::SendTip {
+                    application: "application".to_string(),
+                    handle: "handle".to_string(),
+                    owner_index: None,
+                    expected_owner: None,
+                })
+                .unwrap(),
+            }),
+        )
+        .unwrap();
+
+        let pending_tips = get_pending_tips(deps.as_mut(), "application", "handle");
+        assert_eq!(
+            vec![PendingTip {
+                sender: Addr::unchecked(SENDER),
+                amount: vec![],
+                cw20_amount: vec![Cw20CoinVerified {
+                    address: Addr::unchecked(CW20_TOKEN),
+                    amount: Uint128::new(10_000),
+                }],
+                block_height: 12345
+            }],
+            pending_tips
+        );
+
+        // Mock CLAIMER app link
+        deps.querier =
+            MockDesmosQuerier::default().with_custom_profiles_handler(|profiler_query| {
+                match profiler_query {
+                    ProfilesQuery::ApplicationLinks { .. } => {
+                        let response = QueryApplicationLinksResponse {
+                            links: vec![mock_app_link(
+                                CLAIMER,
+                                ApplicationLinkState::VerificationSuccess,
+                                "",
+                            )],
+                            pagination: None,
+                        };
+                        to_binary(&response).into()
+                    }
+                    _ => mock_profiles_query_response(profiler_query),
+                }
+            });
+
+        let response = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info(CLAIMER, &[]),
+            ExecuteMsg::ClaimTips {},
+        )
+        .unwrap();
+
+        assert_eq!(
+            vec![SubMsg::<DesmosMsg>::new(WasmMsg::Execute {
+                contract_addr: CW20_TOKEN.to_string(),
+                msg: to_binary(&Cw20ExecuteMsg::Transfer {
+                    recipient: CLAIMER.to_string(),
+                    amount: Uint128::new(10_000),
+                })
+                .unwrap(),
+                funds: vec![],
+            })],
+            response.messages
+        );
+
+        // Ensure that the claimed tips have been deleted from the contract state
+        let pending_tips = get_pending_tips(deps.as_mut(), "application", "handle");
+        assert_eq!(Vec::<PendingTip>::new(), pending_tips);
+    }
+
+    #[test]
+    fn claim_no_pending_tips_error() {
+        let mut deps = mock_desmos_dependencies();
+        let env = mock_env();
+        let info = mock_info(CLAIMER, &[]);
+
+        init_contract(deps.as_mut(), 10, 10).unwrap();
+
+        let error = execute(deps.as_mut(), env, info, ExecuteMsg::ClaimTips {}).unwrap_err();
+
+        assert_eq!(
+            error,
+            ContractError::NoTipsAvailable {
+                user: CLAIMER.to_string()
+            }
+        )
+    }
+
+    #[test]
+    fn claim_pending_tip_properly() {
+        let querier = querier_with_no_app_links();
+        let mut deps = mock_desmos_dependencies_with_custom_querier(querier);
+        let env = mock_env();
+        let info = mock_info(SENDER, &[Coin::new(10_000, "udsm")]);
+
+        init_contract(deps.as_mut(), 10, 10).unwrap();
+
+        execute(
+            deps.as_mut(),
+            env,
+            info,
+            ExecuteMsg::SendTip {
+                application: "application".to_string(),
+                handle: "handler".to_string(),
+                owner_index: None,
+                expected_owner: None,
+            },
+        )
+        .unwrap();
+
+        // Mock USER_2 app link
+        deps.querier =
+            MockDesmosQuerier::default().with_custom_profiles_handler(|profiler_query| {
+                match profiler_query {
+                    ProfilesQuery::ApplicationLinks { .. } => {
+                        let response = QueryApplicationLinksResponse {
+                            links: vec![ApplicationLink {
+                                user: Addr::unchecked(CLAIMER),
+                                data: Data {
+                                    username: "handler".to_string(),
+                                    application: "application".to_string(),
+                                },
+                                state: ApplicationLinkState::VerificationSuccess,
+                                oracle_request: OracleRequest {
+                                    id: Uint64::new(0),
+                                    oracle_script_id: Uint64::new(0),
+                                    call_data: CallData {
+                                        application: "".to_string(),
+                                        call_data: "".to_string(),
+                                    },
+                                    client_id: "".to_string(),
+                                },
+                                result: None,
+                                creation_time: "".to_string(),
+                                expiration_time: "".to_string(),
+                            }],
+                            pagination: None,
+                        };
+                        to_binary(&response).into()
+                    }
+                    _ => mock_profiles_query_response(profiler_query),
+                }
+            });
+        let env = mock_env();
+        let info = mock_info(CLAIMER, &[Coin::new(10_000, "udsm")]);
+
+        let response = execute(deps.as_mut(), env, info, ExecuteMsg::ClaimTips {}).unwrap();
+        assert_eq!(
+            &SubMsg::<DesmosMsg>::new(BankMsg::Send {
+                to_address: CLAIMER.to_string(),
+                amount: vec![Coin::new(10_000, "udsm")],
+            }),
+            response.messages.first().unwrap()
+        );
+
+        // Ensure that the claimed tips have been deleted from the contract state
+        let pending_tips = get_pending_tips(deps.as_mut(), "application", "handle");
+        assert_eq!(Vec::<PendingTip>::new(), pending_tips);
+    }
+
+    #[test]
+    fn update_admin_from_non_admin_error() {
+        let mut deps = mock_desmos_dependencies();
+
+        init_contract(deps.as_mut(), 10, 10).unwrap();
+
+        let error = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info(SENDER, &[]),
+            ExecuteMsg::UpdateAdmin {
+                new_admin: SENDER.to_string(),
+            },
+        )
+        .unwrap_err();
+
+        assert_eq!(ContractError::Unauthorized {}, error)
+    }
+
+    #[test]
+    fn update_admin_properly() {
+        let mut deps = mock_desmos_dependencies();
+
+        init_contract(deps.as_mut(), 10, 10).unwrap();
+
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info(ADMIN, &[]),
+            ExecuteMsg::UpdateAdmin {
+                new_admin: SENDER.to_string(),
+            },
+        )
+        .unwrap();
+    }
+
+    #[test]
+    fn update_max_pending_tip_from_non_admin_error() {
+        let mut deps = mock_desmos_dependencies();
+
+        init_contract(deps.as_mut(), 10, 10).unwrap();
+
+        let error = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info(SENDER, &[]),
+            ExecuteMsg::UpdateMaxPendingTips { value: 2 },
+        )
+        .unwrap_err();
+
+        assert_eq!(ContractError::Unauthorized {}, error)
+    }
+
+    #[test]
+    fn update_max_pending_tip_with_invalid_value_error() {
+        let mut deps = mock_desmos_dependencies();
+
+        init_contract(deps.as_mut(), 10, 10).unwrap();
+
+        let error = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info(ADMIN, &[]),
+            ExecuteMsg::UpdateMaxPendingTips { value: 0 },
+        )
+        .unwrap_err();
+
+        assert_eq!(
+            ContractError::InvalidMaxPendingTipsValue {
+                value: 0,
+                max: MAX_CONFIGURABLE_PENDING_TIPS
+            },
+            error
+        );
+
+        let error = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info(ADMIN, &[]),
+            ExecuteMsg::UpdateMaxPendingTips {
+                value: MAX_CONFIGURABLE_PENDING_TIPS + 1,
+            },
+        )
+        .unwrap_err();
+
+        assert_eq!(
+            ContractError::InvalidMaxPendingTipsValue {
+                value: MAX_CONFIGURABLE_PENDING_TIPS + 1,
+                max: MAX_CONFIGURABLE_PENDING_TIPS
+            },
+            error
+        );
+    }
+
+    #[test]
+    fn update_max_pending_tip_properly() {
+        let mut deps = mock_desmos_dependencies();
+
+        init_contract(deps.as_mut(), 10, 10).unwrap();
+
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info(ADMIN, &[]),
+            ExecuteMsg::UpdateMaxPendingTips { value: 5 },
+        )
+        .unwrap();
+
+        assert_eq!(
+            5,
+            CONFIG.load(deps.as_mut().storage).unwrap().max_pending_tips
+        )
+    }
+
+    #[test]
+    fn update_max_sent_pending_tip_from_non_admin_error() {
+        let mut deps = mock_desmos_dependencies();
+
+        init_contract(deps.as_mut(), 10, 10).unwrap();
 
-        // Mock USER_2 app link
-        deps.querier =
-            MockDesmosQuerier::default().with_custom_profiles_handler(|profiler_query| {
-                match profiler_query {
-                    ProfilesQuery::ApplicationLinks { .. } => {
-                        let response = QueryApplicationLinksResponse {
-                            links: vec![ApplicationLink {
-                                user: Addr::unchecked(CLAIMER),
-                                data: Data {
-                                    username: "handler".to_string(),
-                                    application: "application".to_string(),
-                                },
-                                state: ApplicationLinkState::VerificationSuccess,
-                                oracle_request: OracleRequest {
-                                    id: Uint64::new(0),
-                                    oracle_script_id: Uint64::new(0),
-                                    call_data: CallData {
-                                        application: "".to_string(),
-                                        call_data: "".to_string(),
-                                    },
-                                    client_id: "".to_string(),
-                                },
-                                result: None,
-                                creation_time: "".to_string(),
-                                expiration_time: "".to_string(),
-                            }],
-                            pagination: None,
-                        };
-                        to_binary(&response).into()
-                    }
-                    _ => mock_profiles_query_response(profiler_query),
-                }
-            });
-        let env = mock_env();
-        let info = mock_info(CLAIMER, &[Coin::new(10_000, "udsm")]);
+        let error = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info(SENDER, &[]),
+            ExecuteMsg::UpdateMaxSentPendingTips { value: 2 },
+        )
+        .unwrap_err();
+
+        assert_eq!(ContractError::Unauthorized {}, error)
+    }
+
+    #[test]
+    fn update_max_sent_pending_tip_with_invalid_value_error() {
+        let mut deps = mock_desmos_dependencies();
+
+        init_contract(deps.as_mut(), 10, 10).unwrap();
+
+        let error = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info(ADMIN, &[]),
+            ExecuteMsg::UpdateMaxSentPendingTips { value: 0 },
+        )
+        .unwrap_err();
 
-        let response = execute(deps.as_mut(), env, info, ExecuteMsg::ClaimTips {}).unwrap();
         assert_eq!(
-            &SubMsg::<DesmosMsg>::new(BankMsg::Send {
-                to_address: CLAIMER.to_string(),
-                amount: vec![Coin::new(10_000, "udsm")],
-            }),
-            response.messages.first().unwrap()
+            ContractError::InvalidMaxSentPendingTipsValue {
+                value: 0,
+                max: MAX_CONFIGURABLE_SENT_PENDING_TIPS
+            },
+            error
         );
 
-        // Ensure that the claimed tips have been deleted from the contract state
-        let pending_tips = get_pending_tips(deps.as_mut(), "application", "handle");
-        assert_eq!(Vec::<PendingTip>::new(), pending_tips);
+        let error = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info(ADMIN, &[]),
+            ExecuteMsg::UpdateMaxSentPendingTips {
+                value: MAX_CONFIGURABLE_SENT_PENDING_TIPS + 1,
+            },
+        )
+        .unwrap_err();
+
+        assert_eq!(
+            ContractError::InvalidMaxSentPendingTipsValue {
+                value: MAX_CONFIGURABLE_SENT_PENDING_TIPS + 1,
+                max: MAX_CONFIGURABLE_SENT_PENDING_TIPS
+            },
+            error
+        );
     }
 
     #[test]
-    fn update_admin_from_non_admin_error() {
+    fn update_max_sent_pending_tip_properly() {
+        let mut deps = mock_desmos_dependencies();
+
+        init_contract(deps.as_mut(), 10, 10).unwrap();
+
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info(ADMIN, &[]),
+            ExecuteMsg::UpdateMaxSentPendingTips { value: 5 },
+        )
+        .unwrap();
+
+        assert_eq!(
+            5,
+            CONFIG
+                .load(deps.as_mut().storage)
+                .unwrap()
+                .max_sent_pending_tips
+        )
+    }
+
+    #[test]
+    fn update_allowed_denoms_from_non_admin_error() {
         let mut deps = mock_desmos_dependencies();
 
         init_contract(deps.as_mut(), 10, 10).unwrap();
@@ -984,8 +2074,8 @@ mod tests {
             deps.as_mut(),
             mock_env(),
             mock_info(SENDER, &[]),
-            ExecuteMsg::UpdateAdmin {
-                new_admin: SENDER.to_string(),
+            ExecuteMsg::UpdateAllowedDenoms {
+                denoms: vec!["uosmo".to_string()],
             },
         )
         .unwrap_err();
@@ -994,24 +2084,30 @@ mod tests {
     }
 
     #[test]
-    fn update_admin_properly() {
+    fn update_allowed_denoms_properly() {
         let mut deps = mock_desmos_dependencies();
 
         init_contract(deps.as_mut(), 10, 10).unwrap();
 
+        let ibc_denom = "ibc/27394FB092D2ECCD56123C74F36E4C1F926001CEADA9CA97EA622B25F41E5EB2";
         execute(
             deps.as_mut(),
             mock_env(),
             mock_info(ADMIN, &[]),
-            ExecuteMsg::UpdateAdmin {
-                new_admin: SENDER.to_string(),
+            ExecuteMsg::UpdateAllowedDenoms {
+                denoms: vec!["udsm".to_string(), ibc_denom.to_string()],
             },
         )
         .unwrap();
+
+        assert_eq!(
+            vec!["udsm".to_string(), ibc_denom.to_string()],
+            CONFIG.load(deps.as_mut().storage).unwrap().allowed_denoms
+        )
     }
 
     #[test]
-    fn update_max_pending_tip_from_non_admin_error() {
+    fn update_allowed_cw20_tokens_from_non_admin_error() {
         let mut deps = mock_desmos_dependencies();
 
         init_contract(deps.as_mut(), 10, 10).unwrap();
@@ -1020,7 +2116,9 @@ mod tests {
             deps.as_mut(),
             mock_env(),
             mock_info(SENDER, &[]),
-            ExecuteMsg::UpdateMaxPendingTips { value: 2 },
+            ExecuteMsg::UpdateAllowedCw20Tokens {
+                tokens: vec!["other_token".to_string()],
+            },
         )
         .unwrap_err();
 
@@ -1028,145 +2126,324 @@ mod tests {
     }
 
     #[test]
-    fn update_max_pending_tip_with_invalid_value_error() {
+    fn update_allowed_cw20_tokens_properly() {
         let mut deps = mock_desmos_dependencies();
 
         init_contract(deps.as_mut(), 10, 10).unwrap();
 
-        let error = execute(
+        execute(
             deps.as_mut(),
             mock_env(),
             mock_info(ADMIN, &[]),
-            ExecuteMsg::UpdateMaxPendingTips { value: 0 },
+            ExecuteMsg::UpdateAllowedCw20Tokens {
+                tokens: vec!["other_token".to_string()],
+            },
         )
-        .unwrap_err();
+        .unwrap();
 
         assert_eq!(
-            ContractError::InvalidMaxPendingTipsValue {
-                value: 0,
-                max: MAX_CONFIGURABLE_PENDING_TIPS
-            },
-            error
-        );
+            vec![Addr::unchecked("other_token")],
+            CONFIG
+                .load(deps.as_mut().storage)
+                .unwrap()
+                .allowed_cw20_tokens
+        )
+    }
+
+    #[test]
+    fn update_claim_hook_gas_limit_from_non_admin_error() {
+        let mut deps = mock_desmos_dependencies();
+
+        init_contract(deps.as_mut(), 10, 10).unwrap();
 
         let error = execute(
             deps.as_mut(),
             mock_env(),
-            mock_info(ADMIN, &[]),
-            ExecuteMsg::UpdateMaxPendingTips {
-                value: MAX_CONFIGURABLE_PENDING_TIPS + 1,
-            },
+            mock_info(SENDER, &[]),
+            ExecuteMsg::UpdateClaimHookGasLimit { value: 200_000 },
         )
         .unwrap_err();
 
+        assert_eq!(ContractError::Unauthorized {}, error)
+    }
+
+    #[test]
+    fn update_claim_hook_gas_limit_properly() {
+        let mut deps = mock_desmos_dependencies();
+
+        init_contract(deps.as_mut(), 10, 10).unwrap();
+
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info(ADMIN, &[]),
+            ExecuteMsg::UpdateClaimHookGasLimit { value: 200_000 },
+        )
+        .unwrap();
+
         assert_eq!(
-            ContractError::InvalidMaxPendingTipsValue {
-                value: MAX_CONFIGURABLE_PENDING_TIPS + 1,
-                max: MAX_CONFIGURABLE_PENDING_TIPS
+            200_000,
+            CONFIG
+                .load(deps.as_mut().storage)
+                .unwrap()
+                .claim_hook_gas_limit
+        )
+    }
+
+    #[test]
+    fn register_and_unregister_claim_hook_properly() {
+        let mut deps = mock_desmos_dependencies();
+
+        init_contract(deps.as_mut(), 10, 10).unwrap();
+
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info(SENDER, &[]),
+            ExecuteMsg::RegisterClaimHook {
+                contract: HOOK_CONTRACT.to_string(),
             },
-            error
+        )
+        .unwrap();
+
+        let response = query(
+            deps.as_ref(),
+            mock_env(),
+            QueryMsg::ClaimHook {
+                user: SENDER.to_string(),
+            },
+        )
+        .unwrap();
+        let response: QueryClaimHookResponse = from_binary(&response).unwrap();
+        assert_eq!(Some(Addr::unchecked(HOOK_CONTRACT)), response.contract);
+
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info(SENDER, &[]),
+            ExecuteMsg::UnregisterClaimHook {},
+        )
+        .unwrap();
+
+        let response = query(
+            deps.as_ref(),
+            mock_env(),
+            QueryMsg::ClaimHook {
+                user: SENDER.to_string(),
+            },
+        )
+        .unwrap();
+        let response: QueryClaimHookResponse = from_binary(&response).unwrap();
+        assert_eq!(None, response.contract);
+    }
+
+    #[test]
+    fn claim_notifies_claim_hook_properly() {
+        let querier = querier_with_no_app_links();
+        let mut deps = mock_desmos_dependencies_with_custom_querier(querier);
+
+        init_contract(deps.as_mut(), 10, 10).unwrap();
+
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info(SENDER, &[]),
+            ExecuteMsg::RegisterClaimHook {
+                contract: HOOK_CONTRACT.to_string(),
+            },
+        )
+        .unwrap();
+
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info(SENDER, &[Coin::new(10_000, "udsm")]),
+            ExecuteMsg::SendTip {
+                application: "application".to_string(),
+                handle: "handle".to_string(),
+                owner_index: None,
+                expected_owner: None,
+            },
+        )
+        .unwrap();
+
+        // Mock CLAIMER app link
+        deps.querier =
+            MockDesmosQuerier::default().with_custom_profiles_handler(|profiler_query| {
+                match profiler_query {
+                    ProfilesQuery::ApplicationLinks { .. } => {
+                        let response = QueryApplicationLinksResponse {
+                            links: vec![mock_app_link(
+                                CLAIMER,
+                                ApplicationLinkState::VerificationSuccess,
+                                "",
+                            )],
+                            pagination: None,
+                        };
+                        to_binary(&response).into()
+                    }
+                    _ => mock_profiles_query_response(profiler_query),
+                }
+            });
+
+        let response = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info(CLAIMER, &[]),
+            ExecuteMsg::ClaimTips {},
+        )
+        .unwrap();
+
+        assert_eq!(
+            vec![
+                SubMsg::new(BankMsg::Send {
+                    amount: vec![Coin::new(10_000, "udsm")],
+                    to_address: CLAIMER.to_string()
+                }),
+                SubMsg::reply_on_error(
+                    WasmMsg::Execute {
+                        contract_addr: HOOK_CONTRACT.to_string(),
+                        msg: to_binary(&TipHookMsg::TipClaimedHook(TipClaimedHookMsg {
+                            application: "application".to_string(),
+                            handle: "handle".to_string(),
+                            sender: Addr::unchecked(SENDER),
+                            claimer: Some(Addr::unchecked(CLAIMER)),
+                            amount: vec![Coin::new(10_000, "udsm")],
+                            cw20_amount: vec![],
+                        }))
+                        .unwrap(),
+                        funds: vec![],
+                    },
+                    CLAIM_HOOK_REPLY_ID
+                )
+                .with_gas_limit(CLAIM_HOOK_GAS_LIMIT)
+            ],
+            response.messages
         );
     }
 
     #[test]
-    fn update_max_pending_tip_properly() {
-        let mut deps = mock_desmos_dependencies();
+    fn remove_pending_tip_notifies_claim_hook_properly() {
+        let querier = querier_with_no_app_links();
+        let mut deps = mock_desmos_dependencies_with_custom_querier(querier);
 
         init_contract(deps.as_mut(), 10, 10).unwrap();
 
         execute(
             deps.as_mut(),
             mock_env(),
-            mock_info(ADMIN, &[]),
-            ExecuteMsg::UpdateMaxPendingTips { value: 5 },
+            mock_info(SENDER, &[]),
+            ExecuteMsg::RegisterClaimHook {
+                contract: HOOK_CONTRACT.to_string(),
+            },
         )
         .unwrap();
 
-        assert_eq!(
-            5,
-            CONFIG.load(deps.as_mut().storage).unwrap().max_pending_tips
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info(SENDER, &[Coin::new(10_000, "udsm")]),
+            ExecuteMsg::SendTip {
+                application: "application".to_string(),
+                handle: "handle".to_string(),
+                owner_index: None,
+                expected_owner: None,
+            },
         )
-    }
-
-    #[test]
-    fn update_max_sent_pending_tip_from_non_admin_error() {
-        let mut deps = mock_desmos_dependencies();
-
-        init_contract(deps.as_mut(), 10, 10).unwrap();
+        .unwrap();
 
-        let error = execute(
+        let response = execute(
             deps.as_mut(),
             mock_env(),
             mock_info(SENDER, &[]),
-            ExecuteMsg::UpdateMaxSentPendingTips { value: 2 },
+            ExecuteMsg::RemovePendingTip {
+                application: "application".to_string(),
+                handle: "handle".to_string(),
+            },
         )
-        .unwrap_err();
+        .unwrap();
 
-        assert_eq!(ContractError::Unauthorized {}, error)
+        assert_eq!(
+            SubMsg::reply_on_error(
+                WasmMsg::Execute {
+                    contract_addr: HOOK_CONTRACT.to_string(),
+                    msg: to_binary(&TipHookMsg::TipClaimedHook(TipClaimedHookMsg {
+                        application: "application".to_string(),
+                        handle: "handle".to_string(),
+                        sender: Addr::unchecked(SENDER),
+                        claimer: None,
+                        amount: vec![Coin::new(10_000, "udsm")],
+                        cw20_amount: vec![],
+                    }))
+                    .unwrap(),
+                    funds: vec![],
+                },
+                CLAIM_HOOK_REPLY_ID
+            )
+            .with_gas_limit(CLAIM_HOOK_GAS_LIMIT),
+            response.messages[1]
+        );
     }
 
     #[test]
-    fn update_max_sent_pending_tip_with_invalid_value_error() {
+    fn reply_with_invalid_id_error() {
         let mut deps = mock_desmos_dependencies();
 
-        init_contract(deps.as_mut(), 10, 10).unwrap();
-
-        let error = execute(
+        let error = reply(
             deps.as_mut(),
             mock_env(),
-            mock_info(ADMIN, &[]),
-            ExecuteMsg::UpdateMaxSentPendingTips { value: 0 },
+            Reply {
+                id: CLAIM_HOOK_REPLY_ID + 1,
+                result: SubMsgResult::Err("error".to_string()),
+            },
         )
         .unwrap_err();
 
-        assert_eq!(
-            ContractError::InvalidMaxSentPendingTipsValue {
-                value: 0,
-                max: MAX_CONFIGURABLE_SENT_PENDING_TIPS
-            },
-            error
-        );
+        assert_eq!(ContractError::InvalidReplyID {}, error);
+    }
 
-        let error = execute(
+    #[test]
+    fn reply_ignores_claim_hook_error_properly() {
+        let mut deps = mock_desmos_dependencies();
+
+        let response = reply(
             deps.as_mut(),
             mock_env(),
-            mock_info(ADMIN, &[]),
-            ExecuteMsg::UpdateMaxSentPendingTips {
-                value: MAX_CONFIGURABLE_SENT_PENDING_TIPS + 1,
+            Reply {
+                id: CLAIM_HOOK_REPLY_ID,
+                result: SubMsgResult::Err("hook error".to_string()),
             },
         )
-        .unwrap_err();
+        .unwrap();
 
         assert_eq!(
-            ContractError::InvalidMaxSentPendingTipsValue {
-                value: MAX_CONFIGURABLE_SENT_PENDING_TIPS + 1,
-                max: MAX_CONFIGURABLE_SENT_PENDING_TIPS
-            },
-            error
+            Response::<DesmosMsg>::new()
+                .add_attribute(ATTRIBUTE_ACTION, ACTION_CLAIM_HOOK_FAILED)
+                .add_attribute(ATTRIBUTE_ERROR, "hook error"),
+            response
         );
     }
 
     #[test]
-    fn update_max_sent_pending_tip_properly() {
+    fn reply_ignores_claim_hook_out_of_gas_properly() {
         let mut deps = mock_desmos_dependencies();
 
-        init_contract(deps.as_mut(), 10, 10).unwrap();
-
-        execute(
+        let response = reply(
             deps.as_mut(),
             mock_env(),
-            mock_info(ADMIN, &[]),
-            ExecuteMsg::UpdateMaxSentPendingTips { value: 5 },
+            Reply {
+                id: CLAIM_HOOK_REPLY_ID,
+                result: SubMsgResult::Err("out of gas in location: wasm contract".to_string()),
+            },
         )
         .unwrap();
 
         assert_eq!(
-            5,
-            CONFIG
-                .load(deps.as_mut().storage)
-                .unwrap()
-                .max_sent_pending_tips
-        )
+            Response::<DesmosMsg>::new()
+                .add_attribute(ATTRIBUTE_ACTION, ACTION_CLAIM_HOOK_FAILED)
+                .add_attribute(ATTRIBUTE_ERROR, "out of gas in location: wasm contract"),
+            response
+        );
     }
 
     #[test]
@@ -1212,6 +2489,7 @@ mod tests {
                 application: "application".to_string(),
                 handle: "handle".to_string(),
                 owner_index: None,
+                expected_owner: None,
             },
         )
         .unwrap();
@@ -1256,6 +2534,7 @@ mod tests {
                 application: "application".to_string(),
                 handle: "handler".to_string(),
                 owner_index: None,
+                expected_owner: None,
             },
         )
         .unwrap();
@@ -1299,6 +2578,8 @@ mod tests {
             mock_env(),
             QueryMsg::UserPendingTips {
                 user: CLAIMER.to_string(),
+                start_after: None,
+                limit: None,
             },
         )
         .unwrap();
@@ -1306,9 +2587,12 @@ mod tests {
         let response: QueryPendingTipsResponse = from_binary(&response).unwrap();
         assert_eq!(
             response.tips,
-            vec![PendingTip {
+            vec![PendingTipInfo {
+                application: "application".to_string(),
+                handle: "handler".to_string(),
                 sender: Addr::unchecked(SENDER),
                 amount: vec![Coin::new(10_000, "udsm")],
+                cw20_amount: vec![],
                 block_height: 12345
             }]
         )
@@ -1329,6 +2613,7 @@ mod tests {
                 application: "application".to_string(),
                 handle: "handler".to_string(),
                 owner_index: None,
+                expected_owner: None,
             },
         )
         .unwrap();
@@ -1338,6 +2623,8 @@ mod tests {
             mock_env(),
             QueryMsg::UnclaimedSentTips {
                 user: SENDER.to_string(),
+                start_after: None,
+                limit: None,
             },
         )
         .unwrap();
@@ -1345,14 +2632,234 @@ mod tests {
         let response: QueryUnclaimedSentTipsResponse = from_binary(&response).unwrap();
         assert_eq!(
             response.tips,
-            vec![PendingTip {
+            vec![PendingTipInfo {
+                application: "application".to_string(),
+                handle: "handler".to_string(),
+                sender: Addr::unchecked(SENDER),
+                amount: vec![Coin::new(10_000, "udsm")],
+                cw20_amount: vec![],
+                block_height: 12345
+            }]
+        )
+    }
+
+    #[test]
+    fn query_unclaimed_sent_tips_paginated_properly() {
+        let querier = querier_with_no_app_links();
+        let mut deps = mock_desmos_dependencies_with_custom_querier(querier);
+
+        init_contract(deps.as_mut(), 10, 10).unwrap();
+
+        for i in 0..3 {
+            execute(
+                deps.as_mut(),
+                mock_env(),
+                mock_info(SENDER, &[Coin::new(10_000, "udsm")]),
+                ExecuteMsg::SendTip {
+                    application: "application".to_string(),
+                    handle: format!("handle{}", i),
+                    owner_index: None,
+                    expected_owner: None,
+                },
+            )
+            .unwrap();
+        }
+
+        let response = query(
+            deps.as_ref(),
+            mock_env(),
+            QueryMsg::UnclaimedSentTips {
+                user: SENDER.to_string(),
+                start_after: Some(ApplicationHandle {
+                    application: "application".to_string(),
+                    handle: "handle0".to_string(),
+                }),
+                limit: Some(1),
+            },
+        )
+        .unwrap();
+
+        let response: QueryUnclaimedSentTipsResponse = from_binary(&response).unwrap();
+        assert_eq!(
+            response.tips,
+            vec![PendingTipInfo {
+                application: "application".to_string(),
+                handle: "handle1".to_string(),
                 sender: Addr::unchecked(SENDER),
                 amount: vec![Coin::new(10_000, "udsm")],
+                cw20_amount: vec![],
+                block_height: 12345
+            }]
+        )
+    }
+
+    #[test]
+    fn query_pending_tips_by_handle_properly() {
+        let querier = querier_with_no_app_links();
+        let mut deps = mock_desmos_dependencies_with_custom_querier(querier);
+
+        init_contract(deps.as_mut(), 10, 10).unwrap();
+
+        for i in 0..3 {
+            execute(
+                deps.as_mut(),
+                mock_env(),
+                mock_info(&format!("user{}", i), &[Coin::new(10_000, "udsm")]),
+                ExecuteMsg::SendTip {
+                    application: "application".to_string(),
+                    handle: "handle".to_string(),
+                    owner_index: None,
+                    expected_owner: None,
+                },
+            )
+            .unwrap();
+        }
+
+        let response = query(
+            deps.as_ref(),
+            mock_env(),
+            QueryMsg::PendingTipsByHandle {
+                application: "application".to_string(),
+                handle: "handle".to_string(),
+                start_after: Some("user0".to_string()),
+                limit: Some(1),
+            },
+        )
+        .unwrap();
+
+        let response: QueryPendingTipsResponse = from_binary(&response).unwrap();
+        assert_eq!(
+            response.tips,
+            vec![PendingTipInfo {
+                application: "application".to_string(),
+                handle: "handle".to_string(),
+                sender: Addr::unchecked("user1"),
+                amount: vec![Coin::new(10_000, "udsm")],
+                cw20_amount: vec![],
                 block_height: 12345
             }]
         )
     }
 
+    #[test]
+    fn query_total_pending_amount_properly() {
+        let querier = querier_with_no_app_links();
+        let mut deps = mock_desmos_dependencies_with_custom_querier(querier);
+
+        init_contract(deps.as_mut(), 10, 10).unwrap();
+
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info(SENDER, &[Coin::new(10_000, "udsm")]),
+            ExecuteMsg::SendTip {
+                application: "application".to_string(),
+                handle: "handle".to_string(),
+                owner_index: None,
+                expected_owner: None,
+            },
+        )
+        .unwrap();
+
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info(
+                CLAIMER,
+                &[Coin::new(5_000, "udsm"), Coin::new(100, "uatom")],
+            ),
+            ExecuteMsg::SendTip {
+                application: "application".to_string(),
+                handle: "handle".to_string(),
+                owner_index: None,
+                expected_owner: None,
+            },
+        )
+        .unwrap();
+
+        let response = query(
+            deps.as_ref(),
+            mock_env(),
+            QueryMsg::TotalPendingAmount {
+                application: "application".to_string(),
+                handle: "handle".to_string(),
+            },
+        )
+        .unwrap();
+
+        let response: QueryTotalPendingAmountResponse = from_binary(&response).unwrap();
+        assert_eq!(
+            response.amount,
+            vec![Coin::new(100, "uatom"), Coin::new(15_000, "udsm")]
+        )
+    }
+
+    #[test]
+    fn query_resolve_handle_properly() {
+        let querier = MockDesmosQuerier::default().with_custom_profiles_handler(|profiler_query| {
+            match profiler_query {
+                ProfilesQuery::ApplicationLinkOwners { .. } => {
+                    let response = QueryApplicationLinkOwnersResponse {
+                        owners: [CLAIMER, SENDER]
+                            .into_iter()
+                            .map(|user| ApplicationLinkOwnerDetails {
+                                user: Addr::unchecked(user),
+                                application: "application".to_string(),
+                                username: "handle".to_string(),
+                            })
+                            .collect(),
+                        pagination: None,
+                    };
+                    to_binary(&response).into()
+                }
+                ProfilesQuery::ApplicationLinks { user, .. } => {
+                    // Only the link of the claimer has been verified
+                    let link = if user.as_ref().map(|user| user.as_str()) == Some(CLAIMER) {
+                        mock_app_link(
+                            CLAIMER,
+                            ApplicationLinkState::VerificationSuccess,
+                            "2022-10-01T00:00:00Z",
+                        )
+                    } else {
+                        mock_app_link(
+                            SENDER,
+                            ApplicationLinkState::VerificationError,
+                            "2022-10-02T00:00:00Z",
+                        )
+                    };
+                    let response = QueryApplicationLinksResponse {
+                        links: vec![link],
+                        pagination: None,
+                    };
+                    to_binary(&response).into()
+                }
+                _ => mock_profiles_query_response(profiler_query),
+            }
+        });
+        let mut deps = mock_desmos_dependencies_with_custom_querier(querier);
+
+        init_contract(deps.as_mut(), 10, 10).unwrap();
+
+        let response = query(
+            deps.as_ref(),
+            mock_env(),
+            QueryMsg::ResolveHandle {
+                application: "application".to_string(),
+                handle: "handle".to_string(),
+            },
+        )
+        .unwrap();
+
+        let response: QueryResolveHandleResponse = from_binary(&response).unwrap();
+        assert_eq!(
+            response.owners,
+            vec![HandleOwner {
+                user: Addr::unchecked(CLAIMER),
+                creation_time: "2022-10-01T00:00:00Z".to_string(),
+            }]
+        )
+    }
+
     #[test]
     fn query_config_properly() {
         let querier = querier_with_no_app_links();
@@ -1368,7 +2875,10 @@ mod tests {
             QueryConfigResponse {
                 admin: Addr::unchecked(ADMIN),
                 max_pending_tips: 5u16,
-                max_sent_pending_tips: 10u16
+                max_sent_pending_tips: 10u16,
+                allowed_denoms: vec!["udsm".to_string(), "uatom".to_string()],
+                allowed_cw20_tokens: vec![Addr::unchecked(CW20_TOKEN)],
+                claim_hook_gas_limit: CLAIM_HOOK_GAS_LIMIT,
             }
         )
     }
@@ -17,6 +17,11 @@ pub enum ContractError {
     )]
     InvalidMaxSentPendingTipsValue { value: u16, max: u16 },
 
+    #[error(
+        "Invalid claim hook gas limit value: {value}, the value must be > 0 and les then {max}"
+    )]
+    InvalidClaimHookGasLimitValue { value: u64, max: u64 },
+
     #[error("The fund field is empty")]
     EmptyTipAmount {},
 
@@ -37,4 +42,27 @@ pub enum ContractError {
 
     #[error("No pending tip for user with handle: {handle} on application: {application}")]
     NoPendingTip { application: String, handle: String },
+
+    #[error("The handle: {handle} on application: {application} is owned by {owner} instead of {expected}")]
+    UnexpectedHandleOwner {
+        application: String,
+        handle: String,
+        expected: String,
+        owner: String,
+    },
+
+    #[error("The handle: {handle} on application: {application} is not linked to any profile")]
+    HandleNotLinked { application: String, handle: String },
+
+    #[error("Invalid denom: {denom}")]
+    InvalidDenom { denom: String },
+
+    #[error("Tips in {denom} are not allowed")]
+    DenomNotAllowed { denom: String },
+
+    #[error("Invalid reply ID")]
+    InvalidReplyID {},
+
+    #[error("Tips with the CW20 token {token} are not allowed")]
+    Cw20TokenNotAllowed { token: String },
 }
@@ -1,13 +1,24 @@
-use crate::state::{PendingTip, MAX_CONFIGURABLE_PENDING_TIPS, MAX_CONFIGURABLE_SENT_PENDING_TIPS};
+use crate::state::{
+    PendingTip, MAX_CONFIGURABLE_CLAIM_HOOK_GAS_LIMIT, MAX_CONFIGURABLE_PENDING_TIPS,
+    MAX_CONFIGURABLE_SENT_PENDING_TIPS,
+};
+use crate::utils::validate_denom;
 use crate::ContractError;
 use cosmwasm_schema::{cw_serde, QueryResponses};
-use cosmwasm_std::{Addr, Uint64};
+use cosmwasm_std::{Addr, Coin, Uint64};
+use cw20::{Cw20CoinVerified, Cw20ReceiveMsg};
 
 #[cw_serde]
 pub struct InstantiateMsg {
     pub admin: Option<String>,
     pub max_pending_tips: u16,
     pub max_sent_pending_tips: u16,
+    /// Native denoms that can be used to send a tip, if empty any denom is allowed.
+    #[serde(default)]
+    pub allowed_denoms: Vec<String>,
+    #[serde(default)]
+    pub allowed_cw20_tokens: Vec<String>,
+    pub claim_hook_gas_limit: u64,
 }
 
 #[cw_serde]
@@ -17,6 +28,7 @@ pub enum ExecuteMsg {
         application: String,
         handle: String,
         owner_index: Option<Uint64>,
+        expected_owner: Option<String>,
     },
     /// Message that allows a user to claim their pending tips.
     ClaimTips {},
@@ -30,6 +42,34 @@ pub enum ExecuteMsg {
     UpdateMaxSentPendingTips { value: u16 },
     /// Message to remove an unclaimed pending tip.
     RemovePendingTip { application: String, handle: String },
+    /// Message that allows the current admin to update the denoms that can be
+    /// used to send a tip, an empty list allows any denom.
+    UpdateAllowedDenoms { denoms: Vec<String> },
+    /// Message that allows the current admin to update the CW20 tokens that can be
+    /// used to send a tip.
+    UpdateAllowedCw20Tokens { tokens: Vec<String> },
+    /// Message that allows the current admin to update the max gas that
+    /// a claim hook can use when notified.
+    UpdateClaimHookGasLimit { value: u64 },
+    /// Message that allows a user to register a contract that will be notified
+    /// when one of their tips is claimed or removed.
+    RegisterClaimHook { contract: String },
+    /// Message that allows a user to remove their registered claim hook.
+    UnregisterClaimHook {},
+    /// Message to send a tip with CW20 tokens, the wrapped message must be a [ReceiveMsg].
+    Receive(Cw20ReceiveMsg),
+}
+
+/// Messages that can be sent to the contract through a CW20 `Send`.
+#[cw_serde]
+pub enum ReceiveMsg {
+    /// Message to send a tip to another user by application handle.
+    SendTip {
+        application: String,
+        handle: String,
+        owner_index: Option<Uint64>,
+        expected_owner: Option<String>,
+    },
 }
 
 #[cw_serde]
@@ -37,25 +77,138 @@ pub enum ExecuteMsg {
 pub enum QueryMsg {
     /// Query the pending tips of a user.
     #[returns(QueryPendingTipsResponse)]
-    UserPendingTips { user: String },
+    UserPendingTips {
+        user: String,
+        start_after: Option<PendingTipKey>,
+        limit: Option<u32>,
+    },
     /// Message to query the unclaimed tips sent from a user.
     #[returns(QueryUnclaimedSentTipsResponse)]
-    UnclaimedSentTips { user: String },
+    UnclaimedSentTips {
+        user: String,
+        start_after: Option<ApplicationHandle>,
+        limit: Option<u32>,
+    },
+    /// Message to query the pending tips sent to an application handle,
+    /// even if it's not linked to any Desmos profile.
+    #[returns(QueryPendingTipsResponse)]
+    PendingTipsByHandle {
+        application: String,
+        handle: String,
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
+    /// Message to query the total amount of the tips that are waiting
+    /// to be claimed from an application handle.
+    #[returns(QueryTotalPendingAmountResponse)]
+    TotalPendingAmount { application: String, handle: String },
+    /// Message to query the Desmos profiles that have verified the ownership
+    /// of an application handle.
+    #[returns(QueryResolveHandleResponse)]
+    ResolveHandle { application: String, handle: String },
+    /// Message to query the contract that is notified when the tips
+    /// sent from a user are claimed or removed.
+    #[returns(QueryClaimHookResponse)]
+    ClaimHook { user: String },
     /// Message to query the contract configurations.
     #[returns(QueryConfigResponse)]
     Config {},
 }
 
-/// Response to [QueryMsg::UserPendingTips].
+/// Messages sent to the contracts registered with [ExecuteMsg::RegisterClaimHook].
+#[cw_serde]
+pub enum TipHookMsg {
+    /// Notifies that a tip has been claimed or removed from its sender.
+    TipClaimedHook(TipClaimedHookMsg),
+}
+
+/// Details of a claimed or removed tip.
+#[cw_serde]
+pub struct TipClaimedHookMsg {
+    pub application: String,
+    pub handle: String,
+    pub sender: Addr,
+    /// User that has claimed the tip, `None` if the tip has been removed from its sender.
+    pub claimer: Option<Addr>,
+    pub amount: Vec<Coin>,
+    pub cw20_amount: Vec<Cw20CoinVerified>,
+}
+
+/// Identifies a centralized application handle.
+#[cw_serde]
+pub struct ApplicationHandle {
+    pub application: String,
+    pub handle: String,
+}
+
+/// Identifies a pending tip, used to paginate the pending tips of a user.
+#[cw_serde]
+pub struct PendingTipKey {
+    pub application: String,
+    pub handle: String,
+    pub sender: String,
+}
+
+/// Pending tip together with the application handle to which it has been sent.
+#[cw_serde]
+pub struct PendingTipInfo {
+    pub application: String,
+    pub handle: String,
+    pub sender: Addr,
+    pub amount: Vec<Coin>,
+    pub cw20_amount: Vec<Cw20CoinVerified>,
+    pub block_height: u64,
+}
+
+impl PendingTipInfo {
+    pub fn new(application: String, handle: String, pending_tip: PendingTip) -> Self {
+        PendingTipInfo {
+            application,
+            handle,
+            sender: pending_tip.sender,
+            amount: pending_tip.amount,
+            cw20_amount: pending_tip.cw20_amount,
+            block_height: pending_tip.block_height,
+        }
+    }
+}
+
+/// Response to [QueryMsg::UserPendingTips] and [QueryMsg::PendingTipsByHandle].
 #[cw_serde]
 pub struct QueryPendingTipsResponse {
-    pub tips: Vec<PendingTip>,
+    pub tips: Vec<PendingTipInfo>,
 }
 
-/// Response to [QueryMsg::UnclaimedTips].
+/// Response to [QueryMsg::UnclaimedSentTips].
 #[cw_serde]
 pub struct QueryUnclaimedSentTipsResponse {
-    pub tips: Vec<PendingTip>,
+    pub tips: Vec<PendingTipInfo>,
+}
+
+/// Response to [QueryMsg::TotalPendingAmount].
+#[cw_serde]
+pub struct QueryTotalPendingAmountResponse {
+    pub amount: Vec<Coin>,
+    pub cw20_amount: Vec<Cw20CoinVerified>,
+}
+
+/// Desmos profile that has linked an application handle.
+#[cw_serde]
+pub struct HandleOwner {
+    pub user: Addr,
+    pub creation_time: String,
+}
+
+/// Response to [QueryMsg::ResolveHandle].
+#[cw_serde]
+pub struct QueryResolveHandleResponse {
+    pub owners: Vec<HandleOwner>,
+}
+
+/// Response to [QueryMsg::ClaimHook].
+#[cw_serde]
+pub struct QueryClaimHookResponse {
+    pub contract: Option<Addr>,
 }
 
 /// Response to [QueryMsg::UnclaimedTips].
@@ -64,6 +217,21 @@ pub struct QueryConfigResponse {
     pub admin: Addr,
     pub max_pending_tips: u16,
     pub max_sent_pending_tips: u16,
+    pub allowed_denoms: Vec<String>,
+    pub allowed_cw20_tokens: Vec<Addr>,
+    pub claim_hook_gas_limit: u64,
+}
+
+/// Checks that the claim hook gas limit is > 0 and not bigger than [MAX_CONFIGURABLE_CLAIM_HOOK_GAS_LIMIT].
+fn validate_claim_hook_gas_limit(value: u64) -> Result<(), ContractError> {
+    if value == 0 || value > MAX_CONFIGURABLE_CLAIM_HOOK_GAS_LIMIT {
+        return Err(ContractError::InvalidClaimHookGasLimitValue {
+            value,
+            max: MAX_CONFIGURABLE_CLAIM_HOOK_GAS_LIMIT,
+        });
+    }
+
+    Ok(())
 }
 
 impl InstantiateMsg {
@@ -84,7 +252,11 @@ impl InstantiateMsg {
             });
         }
 
-        Ok(())
+        for denom in self.allowed_denoms.iter() {
+            validate_denom(denom)?;
+        }
+
+        validate_claim_hook_gas_limit(self.claim_hook_gas_limit)
     }
 }
 
@@ -140,6 +312,40 @@ impl ExecuteMsg {
                     return Err(ContractError::InvalidUserHandle {});
                 }
 
+                Ok(())
+            }
+            ExecuteMsg::UpdateAllowedDenoms { denoms } => {
+                for denom in denoms.iter() {
+                    validate_denom(denom)?;
+                }
+
+                Ok(())
+            }
+            ExecuteMsg::UpdateAllowedCw20Tokens { .. } => Ok(()),
+            ExecuteMsg::UpdateClaimHookGasLimit { value } => validate_claim_hook_gas_limit(*value),
+            ExecuteMsg::RegisterClaimHook { .. } => Ok(()),
+            ExecuteMsg::UnregisterClaimHook {} => Ok(()),
+            ExecuteMsg::Receive(_) => Ok(()),
+        }
+    }
+}
+
+impl ReceiveMsg {
+    pub fn validate(&self) -> Result<(), ContractError> {
+        match self {
+            ReceiveMsg::SendTip {
+                application,
+                handle,
+                ..
+            } => {
+                if application.is_empty() {
+                    return Err(ContractError::InvalidApplication {});
+                }
+
+                if handle.is_empty() {
+                    return Err(ContractError::InvalidUserHandle {});
+                }
+
                 Ok(())
             }
         }
@@ -148,8 +354,11 @@ impl ExecuteMsg {
 
 #[cfg(test)]
 mod tests {
-    use crate::msg::{ExecuteMsg, InstantiateMsg};
-    use crate::state::{MAX_CONFIGURABLE_PENDING_TIPS, MAX_CONFIGURABLE_SENT_PENDING_TIPS};
+    use crate::msg::{ExecuteMsg, InstantiateMsg, ReceiveMsg};
+    use crate::state::{
+        MAX_CONFIGURABLE_CLAIM_HOOK_GAS_LIMIT, MAX_CONFIGURABLE_PENDING_TIPS,
+        MAX_CONFIGURABLE_SENT_PENDING_TIPS,
+    };
     use crate::ContractError;
 
     #[test]
@@ -158,6 +367,9 @@ mod tests {
             max_pending_tips: 0,
             max_sent_pending_tips: 10,
             admin: None,
+            allowed_denoms: vec![],
+            allowed_cw20_tokens: vec![],
+            claim_hook_gas_limit: 100_000,
         }
         .validate()
         .unwrap_err();
@@ -177,6 +389,9 @@ mod tests {
             max_pending_tips: MAX_CONFIGURABLE_PENDING_TIPS + 1,
             max_sent_pending_tips: 10,
             admin: None,
+            allowed_denoms: vec![],
+            allowed_cw20_tokens: vec![],
+            claim_hook_gas_limit: 100_000,
         }
         .validate()
         .unwrap_err();
@@ -196,6 +411,9 @@ mod tests {
             max_pending_tips: 5,
             max_sent_pending_tips: 0,
             admin: None,
+            allowed_denoms: vec![],
+            allowed_cw20_tokens: vec![],
+            claim_hook_gas_limit: 100_000,
         }
         .validate()
         .unwrap_err();
@@ -215,6 +433,9 @@ mod tests {
             max_pending_tips: 10,
             max_sent_pending_tips: MAX_CONFIGURABLE_SENT_PENDING_TIPS + 1,
             admin: None,
+            allowed_denoms: vec![],
+            allowed_cw20_tokens: vec![],
+            claim_hook_gas_limit: 100_000,
         }
         .validate()
         .unwrap_err();
@@ -228,12 +449,52 @@ mod tests {
         );
     }
 
+    #[test]
+    fn instantiate_with_zero_claim_hook_gas_limit_error() {
+        let error = InstantiateMsg {
+            max_pending_tips: 5,
+            max_sent_pending_tips: 5,
+            admin: None,
+            allowed_denoms: vec![],
+            allowed_cw20_tokens: vec![],
+            claim_hook_gas_limit: 0,
+        }
+        .validate()
+        .unwrap_err();
+
+        assert_eq!(
+            ContractError::InvalidClaimHookGasLimitValue {
+                value: 0,
+                max: MAX_CONFIGURABLE_CLAIM_HOOK_GAS_LIMIT
+            },
+            error
+        );
+    }
+
+    #[test]
+    fn update_claim_hook_gas_limit_bigger_than_max_value_error() {
+        let error = ExecuteMsg::UpdateClaimHookGasLimit {
+            value: MAX_CONFIGURABLE_CLAIM_HOOK_GAS_LIMIT + 1,
+        }
+        .validate()
+        .unwrap_err();
+
+        assert_eq!(
+            ContractError::InvalidClaimHookGasLimitValue {
+                value: MAX_CONFIGURABLE_CLAIM_HOOK_GAS_LIMIT + 1,
+                max: MAX_CONFIGURABLE_CLAIM_HOOK_GAS_LIMIT
+            },
+            error
+        );
+    }
+
     #[test]
     fn send_tip_with_empty_application_error() {
         let error = ExecuteMsg::SendTip {
             application: "".to_string(),
             handle: "handle".to_string(),
             owner_index: None,
+            expected_owner: None,
         }
         .validate()
         .unwrap_err();
@@ -247,6 +508,7 @@ mod tests {
             application: "application".to_string(),
             handle: "".to_string(),
             owner_index: None,
+            expected_owner: None,
         }
         .validate()
         .unwrap_err();
@@ -341,4 +603,69 @@ mod tests {
 
         assert_eq!(ContractError::InvalidUserHandle {}, error);
     }
+
+    #[test]
+    fn instantiate_with_invalid_denom_error() {
+        let error = InstantiateMsg {
+            max_pending_tips: 10,
+            max_sent_pending_tips: 10,
+            admin: None,
+            allowed_denoms: vec!["udsm".to_string(), "ibc/invalid".to_string()],
+            allowed_cw20_tokens: vec![],
+            claim_hook_gas_limit: 100_000,
+        }
+        .validate()
+        .unwrap_err();
+
+        assert_eq!(
+            ContractError::InvalidDenom {
+                denom: "ibc/invalid".to_string()
+            },
+            error
+        );
+    }
+
+    #[test]
+    fn update_allowed_denoms_with_invalid_denom_error() {
+        let error = ExecuteMsg::UpdateAllowedDenoms {
+            denoms: vec!["".to_string()],
+        }
+        .validate()
+        .unwrap_err();
+
+        assert_eq!(
+            ContractError::InvalidDenom {
+                denom: "".to_string()
+            },
+            error
+        );
+    }
+
+    #[test]
+    fn receive_send_tip_with_empty_application_error() {
+        let error = ReceiveMsg::SendTip {
+            application: "".to_string(),
+            handle: "handle".to_string(),
+            owner_index: None,
+            expected_owner: None,
+        }
+        .validate()
+        .unwrap_err();
+
+        assert_eq!(ContractError::InvalidApplication {}, error);
+    }
+
+    #[test]
+    fn receive_send_tip_with_empty_handle_error() {
+        let error = ReceiveMsg::SendTip {
+            application: "application".to_string(),
+            handle: "".to_string(),
+            owner_index: None,
+            expected_owner: None,
+        }
+        .validate()
+        .unwrap_err();
+
+        assert_eq!(ContractError::InvalidUserHandle {}, error);
+    }
 }
@@ -1,11 +1,14 @@
 use cosmwasm_schema::cw_serde;
 use cosmwasm_std::{Addr, Coin};
-use cw_storage_plus::{index_list, IndexedMap, Item, MultiIndex};
+use cw20::Cw20CoinVerified;
+use cw_storage_plus::{index_list, IndexedMap, Item, Map, MultiIndex};
 
 #[cw_serde]
 pub struct PendingTip {
     pub sender: Addr,
     pub amount: Vec<Coin>,
+    #[serde(default)]
+    pub cw20_amount: Vec<Cw20CoinVerified>,
     pub block_height: u64,
 }
 
@@ -19,11 +22,27 @@ pub struct Config {
     pub admin: Addr,
     pub max_pending_tips: u16,
     pub max_sent_pending_tips: u16,
+    /// Native denoms that can be used to send a tip, if empty any denom is allowed.
+    #[serde(default)]
+    pub allowed_denoms: Vec<String>,
+    #[serde(default)]
+    pub allowed_cw20_tokens: Vec<Addr>,
+    #[serde(default = "default_claim_hook_gas_limit")]
+    pub claim_hook_gas_limit: u64,
+}
+
+/// Gas limit of the claim hooks used by the configs stored before it was configurable.
+fn default_claim_hook_gas_limit() -> u64 {
+    DEFAULT_CLAIM_HOOK_GAS_LIMIT
 }
 
 pub const MAX_CONFIGURABLE_PENDING_TIPS: u16 = 20u16;
 pub const MAX_CONFIGURABLE_SENT_PENDING_TIPS: u16 = 20u16;
+pub const MAX_CONFIGURABLE_CLAIM_HOOK_GAS_LIMIT: u64 = 1_000_000u64;
+pub const DEFAULT_CLAIM_HOOK_GAS_LIMIT: u64 = 200_000u64;
 pub const CONFIG: Item<Config> = Item::new("config");
+/// Contracts that will be notified when the tips sent from a user are claimed or removed.
+pub const CLAIM_HOOKS: Map<Addr, Addr> = Map::new("claim_hooks");
 
 pub fn pending_tips<'a>(
 ) -> IndexedMap<'a, (String, String, Addr), PendingTip, PendingTipsIndexes<'a>> {
@@ -1,7 +1,14 @@
 use crate::ContractError;
-use cosmwasm_std::{Coin, OverflowError, OverflowOperation, StdError};
+use cosmwasm_std::{
+    to_binary, Addr, BankMsg, Coin, CosmosMsg, OverflowError, OverflowOperation, StdError,
+    StdResult, Uint128, WasmMsg,
+};
+use cw20::{Cw20CoinVerified, Cw20ExecuteMsg};
 use std::collections::btree_map::BTreeMap;
 
+/// Length of the hash contained in an IBC denom.
+const IBC_DENOM_HASH_LENGTH: usize = 64;
+
 /// Iterates over the coins vector and merges the coins having the same `denom`
 /// and return them sorted by denom.
 /// * `coins` - Vector of coins to merge.
@@ -38,6 +45,26 @@ pub fn sum_coins_sorted(coins: Vec<Coin>) -> Result<Vec<Coin>, ContractError> {
     Ok(coins)
 }
 
+/// Iterates over the CW20 coins vector and merges the coins having the same token
+/// address and return them sorted by address.
+/// * `coins` - Vector of CW20 coins to merge.
+pub fn sum_cw20_coins_sorted(
+    coins: Vec<Cw20CoinVerified>,
+) -> Result<Vec<Cw20CoinVerified>, ContractError> {
+    let mut map: BTreeMap<Addr, Uint128> = BTreeMap::new();
+    for coin in coins {
+        let amount = map.entry(coin.address).or_default();
+        *amount = amount
+            .checked_add(coin.amount)
+            .map_err(StdError::overflow)?;
+    }
+
+    Ok(map
+        .into_iter()
+        .map(|(address, amount)| Cw20CoinVerified { address, amount })
+        .collect())
+}
+
 /// Serialize a slice of [`Coin`] into where each coin is separated by a "," (comma).
 /// * `coins` - Coins slice to serialize.
 pub fn serialize_coins(coins: &[Coin]) -> String {
@@ -48,11 +75,93 @@ pub fn serialize_coins(coins: &[Coin]) -> String {
         .join(",")
 }
 
+/// Serialize a slice of [`Cw20CoinVerified`] where each coin is separated by a "," (comma)
+/// and is represented as `<amount><token address>`.
+/// * `coins` - CW20 coins slice to serialize.
+pub fn serialize_cw20_coins(coins: &[Cw20CoinVerified]) -> String {
+    coins
+        .iter()
+        .map(|coin| format!("{}{}", coin.amount, coin.address))
+        .collect::<Vec<_>>()
+        .join(",")
+}
+
+/// Builds the messages required to transfer native and CW20 coins to a recipient.
+/// * `recipient` - Address of the user that will receive the coins.
+/// * `amount` - Native coins to transfer.
+/// * `cw20_amount` - CW20 coins to transfer.
+pub fn transfer_msgs<T>(
+    recipient: &str,
+    amount: Vec<Coin>,
+    cw20_amount: Vec<Cw20CoinVerified>,
+) -> StdResult<Vec<CosmosMsg<T>>> {
+    let mut messages = Vec::with_capacity(cw20_amount.len() + 1);
+
+    if !amount.is_empty() {
+        messages.push(
+            BankMsg::Send {
+                to_address: recipient.to_string(),
+                amount,
+            }
+            .into(),
+        );
+    }
+
+    for coin in cw20_amount {
+        messages.push(
+            WasmMsg::Execute {
+                contract_addr: coin.address.to_string(),
+                msg: to_binary(&Cw20ExecuteMsg::Transfer {
+                    recipient: recipient.to_string(),
+                    amount: coin.amount,
+                })?,
+                funds: vec![],
+            }
+            .into(),
+        );
+    }
+
+    Ok(messages)
+}
+
+/// Checks if the provided denom is a valid native or IBC denom.
+/// * `denom` - Denom to check.
+pub fn validate_denom(denom: &str) -> Result<(), ContractError> {
+    let invalid_denom = || ContractError::InvalidDenom {
+        denom: denom.to_string(),
+    };
+
+    // IBC denoms are represented as ibc/<hex encoded hash of the trace>.
+    if let Some(hash) = denom.strip_prefix("ibc/") {
+        if hash.len() != IBC_DENOM_HASH_LENGTH || !hash.chars().all(|c| c.is_ascii_hexdigit()) {
+            return Err(invalid_denom());
+        }
+        return Ok(());
+    }
+
+    // Follows the Cosmos SDK denom regex: [a-zA-Z][a-zA-Z0-9/:._-]{2,127}.
+    let mut chars = denom.chars();
+    let valid_first_char = chars.next().map_or(false, |c| c.is_ascii_alphabetic());
+    let valid_chars = chars.all(|c| c.is_ascii_alphanumeric() || "/:._-".contains(c));
+    if !valid_first_char || !valid_chars || denom.len() < 3 || denom.len() > 128 {
+        return Err(invalid_denom());
+    }
+
+    Ok(())
+}
+
 #[cfg(test)]
 mod tests {
     use crate::error::ContractError;
-    use crate::utils::{serialize_coins, sum_coins_sorted};
-    use cosmwasm_std::{Coin, OverflowError, OverflowOperation, StdError};
+    use crate::utils::{
+        serialize_coins, serialize_cw20_coins, sum_coins_sorted, sum_cw20_coins_sorted,
+        transfer_msgs, validate_denom,
+    };
+    use cosmwasm_std::{
+        to_binary, Addr, BankMsg, Coin, CosmosMsg, Empty, OverflowError, OverflowOperation,
+        StdError, Uint128, WasmMsg,
+    };
+    use cw20::{Cw20CoinVerified, Cw20ExecuteMsg};
 
     #[test]
     fn test_coin_merge_duplicates_properly() {
@@ -138,4 +247,108 @@ mod tests {
             serialize_coins(&[Coin::new(100, "uatom"), Coin::new(100, "udsm")])
         )
     }
+
+    #[test]
+    fn test_cw20_coin_merge_duplicates_properly() {
+        let merged = sum_cw20_coins_sorted(vec![
+            Cw20CoinVerified {
+                address: Addr::unchecked("token2"),
+                amount: Uint128::new(100),
+            },
+            Cw20CoinVerified {
+                address: Addr::unchecked("token1"),
+                amount: Uint128::new(200),
+            },
+            Cw20CoinVerified {
+                address: Addr::unchecked("token2"),
+                amount: Uint128::new(300),
+            },
+        ])
+        .unwrap();
+
+        assert_eq!(
+            vec![
+                Cw20CoinVerified {
+                    address: Addr::unchecked("token1"),
+                    amount: Uint128::new(200),
+                },
+                Cw20CoinVerified {
+                    address: Addr::unchecked("token2"),
+                    amount: Uint128::new(400),
+                }
+            ],
+            merged
+        )
+    }
+
+    #[test]
+    fn serialize_cw20_coins_properly() {
+        assert_eq!(
+            "100token1,200token2",
+            serialize_cw20_coins(&[
+                Cw20CoinVerified {
+                    address: Addr::unchecked("token1"),
+                    amount: Uint128::new(100),
+                },
+                Cw20CoinVerified {
+                    address: Addr::unchecked("token2"),
+                    amount: Uint128::new(200),
+                }
+            ])
+        )
+    }
+
+    #[test]
+    fn transfer_msgs_properly() {
+        let messages = transfer_msgs::<Empty>(
+            "recipient",
+            vec![Coin::new(100, "udsm")],
+            vec![Cw20CoinVerified {
+                address: Addr::unchecked("token"),
+                amount: Uint128::new(200),
+            }],
+        )
+        .unwrap();
+
+        assert_eq!(
+            vec![
+                CosmosMsg::Bank(BankMsg::Send {
+                    to_address: "recipient".to_string(),
+                    amount: vec![Coin::new(100, "udsm")],
+                }),
+                CosmosMsg::Wasm(WasmMsg::Execute {
+                    contract_addr: "token".to_string(),
+                    msg: to_binary(&Cw20ExecuteMsg::Transfer {
+                        recipient: "recipient".to_string(),
+                        amount: Uint128::new(200),
+                    })
+                    .unwrap(),
+                    funds: vec![],
+                })
+            ],
+            messages
+        )
+    }
+
+    #[test]
+    fn validate_denom_properly() {
+        assert!(validate_denom("udsm").is_ok());
+        assert!(validate_denom("factory/desmos1.../utoken").is_ok());
+        assert!(validate_denom(
+            "ibc/27394FB092D2ECCD56123C74F36E4C1F926001CEADA9CA97EA622B25F41E5EB2"
+        )
+        .is_ok());
+    }
+
+    #[test]
+    fn validate_invalid_denom_error() {
+        for denom in ["", "ud", "1udsm", "udsm!", "ibc/27394FB092", "ibc/invalid"] {
+            assert_eq!(
+                ContractError::InvalidDenom {
+                    denom: denom.to_string()
+                },
+                validate_denom(denom).unwrap_err()
+            );
+        }
+    }
 }